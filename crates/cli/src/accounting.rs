@@ -0,0 +1,104 @@
+// Accounting imports: sales and marketing expense from the ledger, for a fully-loaded CAC that
+// counts salaries, tools, agencies, and events alongside ad spend. Each ledger maps its profit
+// and loss report into `ExpenseLine`s; the expense accounts whose names match `--account` are
+// summed and divided by the customers acquired over the same period, given as `--acquisitions`
+// or counted from closed-won deals in a CRM.
+
+use clap::{Args, ValueEnum};
+
+use crate::evaluation::money;
+use crate::history::date_from_days;
+use crate::hubspot::HubSpotArgs;
+use crate::import::{Imported, PeriodArgs};
+use crate::locale;
+use crate::precision;
+use crate::salesforce::SalesforceArgs;
+
+/// Expense accounts counted as sales and marketing when no `--account` is given.
+const DEFAULT_ACCOUNTS: [&str; 6] = ["advertising", "marketing", "promotion", "sales", "commission", "sponsorship"];
+
+/// One expense account's total over the period.
+#[derive(Debug, Clone)]
+pub struct ExpenseLine {
+    pub account: String,
+    pub amount: f64,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum Crm {
+    Hubspot,
+    Salesforce,
+}
+
+/// Options shared by every ledger.
+#[derive(Args, Debug, Clone)]
+pub struct AccountingArgs {
+    /// The profit and loss period to total expenses over
+    #[command(flatten)]
+    pub period: PeriodArgs,
+
+    /// Expense account to count as sales and marketing, matched by name; repeat for each (default: advertising, marketing, promotion, sales, commission, sponsorship)
+    #[arg(long = "account")]
+    pub accounts: Vec<String>,
+
+    /// Customers acquired over the period
+    #[arg(long, value_parser = locale::number)]
+    pub acquisitions: Option<f64>,
+
+    /// Count acquisitions as deals won in this CRM over the period, with its environment-variable credentials
+    #[arg(long, value_enum, conflicts_with = "acquisitions")]
+    pub crm: Option<Crm>,
+}
+
+impl AccountingArgs {
+    fn counted(&self, account: &str) -> bool {
+        let account = account.to_lowercase();
+        if self.accounts.is_empty() {
+            DEFAULT_ACCOUNTS.iter().any(|a| account.contains(a))
+        } else {
+            self.accounts.iter().any(|a| account.contains(&a.to_lowercase()))
+        }
+    }
+
+    /// Customers acquired over the period, and where the count came from.
+    fn acquisitions(&self) -> Result<(f64, String), String> {
+        let (crm, deals) = match (self.acquisitions, self.crm) {
+            (Some(n), _) => return Ok((n, "--acquisitions".to_string())),
+            (None, Some(Crm::Hubspot)) => ("HubSpot", HubSpotArgs::acquisitions_in(&self.period)?),
+            (None, Some(Crm::Salesforce)) => ("Salesforce", SalesforceArgs::acquisitions_in(&self.period)?),
+            (None, None) => return Err("provide --acquisitions or --crm to count the customers this spend acquired".to_string()),
+        };
+        Ok((deals.len() as f64, format!("deals won in {}", crm)))
+    }
+}
+
+/// Prints the expense accounts counted and left out, and returns the fully-loaded CAC.
+pub fn report(ledger: &str, lines: &[ExpenseLine], args: &AccountingArgs) -> Result<Imported, String> {
+    let (since, until) = args.period.days()?;
+    let (counted, other): (Vec<&ExpenseLine>, Vec<&ExpenseLine>) = lines.iter().filter(|l| l.amount != 0.0).partition(|l| args.counted(&l.account));
+    if counted.is_empty() {
+        return Err(format!("no {} expense accounts match {}; pass --account with the names of your sales and marketing accounts", ledger, if args.accounts.is_empty() { "the defaults" } else { "--account" }));
+    }
+    let spend: f64 = counted.iter().map(|l| l.amount).sum();
+    let (acquired, source) = args.acquisitions()?;
+    if acquired <= 0.0 {
+        return Err(format!("no customers acquired ({}) to divide {} of spend by", source, money(spend)));
+    }
+    let cac = spend / acquired;
+
+    println!("\n=== {} import ===\n", ledger);
+    println!("Sales and marketing expense from {} to {}:", date_from_days(since), date_from_days(until));
+    for l in &counted {
+        println!("  {:<36}{:>12}", l.account.chars().take(36).collect::<String>(), money(l.amount));
+    }
+    println!("  {:<36}{:>12}", "Total", money(spend));
+    if !other.is_empty() {
+        let names: Vec<&str> = other.iter().map(|l| l.account.as_str()).collect();
+        println!("\nLeft out as not sales and marketing: {}.", names.join(", "));
+        println!("Repeat --account with every account to count if this split is wrong.");
+    }
+    println!("\nFully-loaded CAC = {} ÷ {} customers ({}) = {}.", money(spend), precision::fixed(acquired, 0), source, money(cac));
+    println!("It counts everything spent to win customers, so expect it above an ads-only CAC.");
+
+    Ok(Imported { cac: Some(cac), ..Imported::default() })
+}
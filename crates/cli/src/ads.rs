@@ -0,0 +1,264 @@
+// Ad platform imports: spend and conversions per campaign, from a platform's API or an exported
+// report, turned into CAC. Blended CAC is total spend over total conversions, or over
+// `--conversions` when acquisitions are counted somewhere more trustworthy (a CRM, the billing
+// system); platform and campaign CAC divide their spend by their own conversions, scaled to add up
+// to `--conversions` when it's given. Each platform (or campaign) becomes a `--channel` for the
+// per-channel analysis, and `import ads` runs several platforms as one import.
+
+use std::collections::BTreeMap;
+
+use clap::Args;
+
+use crate::channel::Channel;
+use crate::evaluation::money;
+use crate::google_ads::GoogleAdsArgs;
+use crate::import::{Imported, Importer, OutputArgs, PeriodArgs};
+use crate::locale;
+use crate::meta_ads::MetaAdsArgs;
+use crate::precision;
+
+/// Several ad platforms at once, for blended CAC across them; API credentials come from each
+/// platform's environment variables.
+#[derive(Args, Debug)]
+pub struct AdsArgs {
+    /// Google Ads campaign report (CSV)
+    #[arg(long)]
+    pub google_csv: Option<String>,
+
+    /// Google Ads customer ID to query through the API (GOOGLE_ADS_DEVELOPER_TOKEN, GOOGLE_ADS_ACCESS_TOKEN)
+    #[arg(long)]
+    pub google_customer_id: Option<String>,
+
+    /// Meta Ads campaign report exported from Ads Manager (CSV)
+    #[arg(long)]
+    pub meta_csv: Option<String>,
+
+    /// Meta ad account ID to query through the API (META_ACCESS_TOKEN)
+    #[arg(long)]
+    pub meta_account_id: Option<String>,
+
+    /// Meta action type counted as a conversion
+    #[arg(long, default_value = "purchase")]
+    pub meta_action_type: String,
+
+    #[command(flatten)]
+    pub spend: SpendArgs,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+/// Period and conversion options shared by every ad platform.
+#[derive(Args, Debug, Clone)]
+pub struct SpendArgs {
+    /// Customers acquired over the period, counted outside the platform (e.g. closed deals); replaces its conversions
+    #[arg(long, value_parser = locale::number)]
+    pub conversions: Option<f64>,
+
+    /// Save each campaign as its own channel instead of one channel for the whole platform
+    #[arg(long, default_value_t = false)]
+    pub per_campaign: bool,
+
+    /// Spend to fetch through the API (reports cover whatever period they were exported for)
+    #[command(flatten)]
+    pub period: PeriodArgs,
+}
+
+/// One campaign's spend over the period; `conversions` is None when the source doesn't report them.
+#[derive(Debug, Clone)]
+pub struct Campaign {
+    pub name: String,
+    pub cost: f64,
+    pub conversions: Option<f64>,
+}
+
+/// Adds `campaign` to `campaigns`, merging rows for the same campaign (one per day or ad set).
+pub fn add(campaigns: &mut BTreeMap<String, Campaign>, campaign: Campaign) {
+    campaigns
+        .entry(campaign.name.clone())
+        .and_modify(|c| {
+            c.cost += campaign.cost;
+            c.conversions = match (c.conversions, campaign.conversions) {
+                (None, None) => None,
+                (a, b) => Some(a.unwrap_or(0.0) + b.unwrap_or(0.0)),
+            };
+        })
+        .or_insert(campaign);
+}
+
+/// Splits one CSV line, honoring double quotes (`"1,234.50"`).
+fn fields(line: &str) -> Vec<String> {
+    let mut out = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                out.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => out.push(String::new()),
+            _ => out.last_mut().unwrap().push(ch),
+        }
+    }
+    out.iter().map(|f| f.trim().to_string()).collect()
+}
+
+/// A report number with currency symbols, codes, and thousands separators stripped; `--` and blanks are None.
+fn number(s: &str) -> Option<f64> {
+    let cleaned: String = s.chars().filter(|c| c.is_ascii_digit() || *c == '.' || *c == '-').collect();
+    if cleaned.trim_matches('-').is_empty() { None } else { cleaned.parse().ok() }
+}
+
+/// Index of the first header matching one of `names`, exactly or with a suffix like ` (USD)`.
+fn column(header: &[String], names: &[&str]) -> Option<usize> {
+    header.iter().position(|h| {
+        let h = h.to_lowercase();
+        names.iter().any(|n| h == *n || h.starts_with(&format!("{} (", n)))
+    })
+}
+
+/// Campaigns in an exported report. The header is the first line with a campaign and a cost
+/// column, so title and date-range lines above it are skipped, as are blank and total rows.
+pub fn read_report(path: &str, names: &[&str], costs: &[&str], conversions: &[&str]) -> Result<Vec<Campaign>, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path, e))?;
+    let text = text.trim_start_matches('\u{feff}');
+    let mut lines = text.lines().enumerate();
+    let (name_col, cost_col, conv_col) = loop {
+        let Some((_, line)) = lines.next() else {
+            return Err(format!("{}: no header row with `{}` and `{}` columns", path, names[0], costs[0]));
+        };
+        let header = fields(line);
+        if let (Some(n), Some(c)) = (column(&header, names), column(&header, costs)) {
+            break (n, c, column(&header, conversions));
+        }
+    };
+    let mut campaigns = BTreeMap::new();
+    for (i, line) in lines {
+        let row = fields(line);
+        let name = row.get(name_col).map(String::as_str).unwrap_or("");
+        if name.is_empty() || row.first().is_some_and(|f| f.to_lowercase().starts_with("total")) {
+            continue;
+        }
+        let cost = row.get(cost_col).and_then(|c| number(c)).ok_or_else(|| format!("{} line {}: no cost for `{}`", path, i + 1, name))?;
+        let conversions = conv_col.map(|k| row.get(k).and_then(|c| number(c)).unwrap_or(0.0));
+        add(&mut campaigns, Campaign { name: name.to_string(), cost, conversions });
+    }
+    Ok(campaigns.into_values().collect())
+}
+
+/// `Brand — Search (US)` → `brand-search-us`, safe as a `--channel` name.
+pub fn slug(name: &str) -> String {
+    let lower = name.to_lowercase();
+    let words: Vec<&str> = lower.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()).collect();
+    if words.is_empty() { "campaign".to_string() } else { words.join("-") }
+}
+
+/// One platform's campaigns over the period, and where they were read from.
+pub struct Platform {
+    pub name: &'static str,
+    pub channel: String,
+    pub source: String,
+    pub campaigns: Vec<Campaign>,
+}
+
+impl Platform {
+    fn spend(&self) -> f64 {
+        self.campaigns.iter().map(|c| c.cost).sum()
+    }
+
+    fn conversions(&self) -> Option<f64> {
+        self.campaigns.iter().map(|c| c.conversions).sum()
+    }
+}
+
+fn row(name: &str, spend: f64, conversions: Option<f64>, cac: Option<f64>) {
+    let name: String = name.chars().take(32).collect();
+    let conversions = conversions.map(|v| precision::fixed(v, 1)).unwrap_or_else(|| "-".to_string());
+    println!("  {:<32}  {:>12}  {:>11}  {:>10}", name, money(spend), conversions, cac.map(money).unwrap_or_else(|| "-".to_string()));
+}
+
+/// Prints spend, conversions, and CAC per campaign and platform, and returns the blended CAC with
+/// one channel per platform (or per campaign with `--per-campaign`).
+pub fn report(title: &str, platforms: &[Platform], args: &SpendArgs) -> Result<Imported, String> {
+    if let Some(p) = platforms.iter().find(|p| p.campaigns.is_empty()) {
+        return Err(format!("no {} campaigns with spend in the period", p.name));
+    }
+    let spend: f64 = platforms.iter().map(Platform::spend).sum();
+    let reported: Option<f64> = platforms.iter().map(Platform::conversions).sum();
+    let acquired = args.conversions.or(reported).ok_or_else(|| "a report has no conversions column; pass --conversions".to_string())?;
+    if acquired <= 0.0 {
+        return Err(format!("no conversions to divide {} of spend by; pass --conversions", money(spend)));
+    }
+    let blended = spend / acquired;
+    // Platform conversions are scaled to add up to --conversions, keeping each one's share.
+    let scale = match (args.conversions, reported) {
+        (None, Some(_)) => Some(1.0),
+        (Some(given), Some(r)) if r > 0.0 => Some(given / r),
+        _ => None,
+    };
+    let cac = |cost: f64, conversions: Option<f64>| scale.zip(conversions).map(|(k, v)| k * v).filter(|v| *v > 0.0).map(|v| cost / v);
+
+    println!("\n=== {} import ===", title);
+    for p in platforms {
+        println!("\n{} spend by campaign ({}):", p.name, p.source);
+        println!("  {:<32}  {:>12}  {:>11}  {:>10}", "Campaign", "Spend", "Conversions", "CAC");
+        for c in &p.campaigns {
+            row(&c.name, c.cost, c.conversions, cac(c.cost, c.conversions));
+        }
+    }
+    if platforms.len() > 1 {
+        println!("\nBy platform:");
+        for p in platforms {
+            row(p.name, p.spend(), p.conversions(), cac(p.spend(), p.conversions()));
+        }
+    }
+    println!("\nBlended CAC = {} spend ÷ {} {} = {}.", money(spend), precision::fixed(acquired, 1), if args.conversions.is_some() { "customers (--conversions)" } else { "conversions" }, money(blended));
+    match scale {
+        _ if args.conversions.is_none() => println!("Platform conversions are each platform's own attribution; pass --conversions with customers actually won for a truer CAC."),
+        Some(k) => println!("Campaign and platform CAC count {} customers per platform conversion, so they add up to --conversions.", precision::fixed(k, 2)),
+        None => println!("Without platform conversions to split --conversions by, only the blended CAC is known."),
+    }
+
+    let channels: Vec<Channel> = match scale {
+        Some(_) if args.per_campaign => platforms
+            .iter()
+            .flat_map(|p| p.campaigns.iter().filter_map(|c| cac(c.cost, c.conversions).map(|v| Channel { name: format!("{}-{}", p.channel, slug(&c.name)), cac: v, capacity: None })))
+            .collect(),
+        Some(_) => platforms.iter().filter_map(|p| cac(p.spend(), p.conversions()).map(|v| Channel { name: p.channel.clone(), cac: v, capacity: None })).collect(),
+        None => {
+            let name = if let [p] = platforms { p.channel.clone() } else { "paid-ads".to_string() };
+            vec![Channel { name, cac: blended, capacity: None }]
+        }
+    };
+    let priced = if args.per_campaign { platforms.iter().map(|p| p.campaigns.len()).sum() } else { platforms.len() };
+    if scale.is_some() && channels.len() < priced {
+        println!("Spend without conversions has no CAC of its own and isn't saved as a channel.");
+    }
+    Ok(Imported { cac: Some(blended), channels, ..Imported::default() })
+}
+
+impl Importer for AdsArgs {
+    fn name(&self) -> &'static str {
+        "Paid ads"
+    }
+
+    fn output(&self) -> &OutputArgs {
+        &self.output
+    }
+
+    fn import(&self) -> Result<Imported, String> {
+        let mut platforms = Vec::new();
+        if self.google_csv.is_some() || self.google_customer_id.is_some() {
+            platforms.push(GoogleAdsArgs::platform_of(self.google_csv.clone(), self.google_customer_id.clone(), &self.spend)?);
+        }
+        if self.meta_csv.is_some() || self.meta_account_id.is_some() {
+            platforms.push(MetaAdsArgs::platform_of(self.meta_csv.clone(), self.meta_account_id.clone(), &self.meta_action_type, &self.spend)?);
+        }
+        if platforms.is_empty() {
+            return Err("provide a report or account for at least one platform (--google-csv, --meta-csv, ...)".to_string());
+        }
+        report(self.name(), &platforms, &self.spend)
+    }
+}
@@ -0,0 +1,86 @@
+// Airtable: every evaluation appended as a record to the base and table named in the config
+// file, so ops teams tracking experiments in Airtable get results without copying them over.
+//
+//   [airtable]
+//   base = "appXXXXXXXXXXXXXX"
+//   table = "Experiments"
+//
+// The token comes from AIRTABLE_TOKEN (a personal access token with data.records:write). The
+// table needs the fields in `record`; Tags is a multiple select, and new tags are added to it as
+// they appear. Runs are pushed while the section is there, unless `--no-airtable` is given.
+
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::clock;
+use crate::import;
+use crate::report::EvaluationResult;
+use crate::sweep::verdict_label;
+
+/// Airtable takes at most this many records per request.
+const BATCH: usize = 10;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AirtableConfig {
+    /// Base ID, `app…`.
+    pub base: String,
+    /// Table name or ID, `tbl…`.
+    pub table: String,
+    #[serde(default = "default_api_url")]
+    pub api_url: String,
+}
+
+fn default_api_url() -> String {
+    "https://api.airtable.com".to_string()
+}
+
+struct Target {
+    config: AirtableConfig,
+    tags: Vec<String>,
+}
+
+static TARGET: OnceLock<Option<Target>> = OnceLock::new();
+
+/// Sets where evaluations go for this run; None (no config, or `--no-airtable`) pushes nothing.
+pub fn install(config: Option<AirtableConfig>, tags: Vec<String>) {
+    TARGET.set(config.map(|config| Target { config, tags })).ok().expect("airtable installed once, at startup");
+}
+
+/// One evaluation as Airtable fields.
+pub fn record(name: &str, r: &EvaluationResult) -> Value {
+    let i = &r.inputs;
+    let tags = TARGET.get().and_then(Option::as_ref).map(|t| t.tags.clone()).unwrap_or_default();
+    json!({ "fields": {
+        "Name": name,
+        "Date": clock::today(),
+        "CAC": i.cac,
+        "CFA": i.cfa,
+        "LTGP": i.ltgp,
+        "Early GP rate": i.early_gp_rate,
+        "Period": i.period,
+        "LTGP:CAC": r.ratio,
+        "Net outlay": r.net_outlay,
+        "Quadrant": r.quadrant,
+        "Verdict": verdict_label(&r.verdict),
+        "Severity": r.severity.level,
+        "Payback days": r.payback_days,
+        "Tags": tags,
+    }})
+}
+
+/// Appends `records` to the configured table; Ok(None) when no table is configured.
+pub fn push(records: Vec<Value>) -> Result<Option<String>, String> {
+    let Some(target) = TARGET.get().and_then(Option::as_ref) else { return Ok(None) };
+    let token = std::env::var("AIRTABLE_TOKEN").map_err(|_| "set AIRTABLE_TOKEN to push results to Airtable, or pass --no-airtable".to_string())?;
+    let c = &target.config;
+    let url = format!("{}/v0/{}/{}", c.api_url.trim_end_matches('/'), import::path_segment(&c.base), import::path_segment(&c.table));
+    let bearer = format!("Bearer {}", token);
+    for chunk in records.chunks(BATCH) {
+        let body = json!({ "records": chunk, "typecast": true });
+        let _: Value = import::post(&url, &[("Authorization", &bearer)], &body.to_string())?;
+    }
+    Ok(Some(c.table.clone()))
+}
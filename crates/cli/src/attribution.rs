@@ -0,0 +1,96 @@
+// "What changed" attribution: splits the change in a metric between two scenarios into
+// per-input contributions. Inputs are swapped from A to B one at a time, and each input's
+// effect is averaged over every swap order (Shapley values), so the contributions always
+// add up to the total change and do not depend on an arbitrary ordering.
+
+use crate::evaluation::{self, money, ratio_text, Evaluation, Field, Inputs};
+use crate::layout::wrapln;
+use crate::precision;
+
+fn changed(field: Field, a: &Inputs, b: &Inputs) -> bool {
+    field.get(a) != field.get(b) || (field == Field::EarlyGpRate && a.period != b.period)
+}
+
+/// Copies one input (and, for early GP, its period unit) from `from` into `to`.
+fn substitute(field: Field, to: &mut Inputs, from: &Inputs) {
+    field.set(to, field.get(from));
+    if field == Field::EarlyGpRate { to.period = from.period; }
+}
+
+#[derive(Debug, Clone)]
+pub struct Contribution {
+    pub input: Field,
+    pub delta: f64,
+    pub from: f64,
+    pub to: f64,
+}
+
+fn permutations(items: &[Field]) -> Vec<Vec<Field>> {
+    if items.len() <= 1 { return vec![items.to_vec()]; }
+    let mut out = Vec::new();
+    for i in 0..items.len() {
+        let mut rest = items.to_vec();
+        let first = rest.remove(i);
+        for mut tail in permutations(&rest) {
+            tail.insert(0, first);
+            out.push(tail);
+        }
+    }
+    out
+}
+
+/// Per-input contributions to `metric(b) − metric(a)`; None if the metric is undefined along any path.
+pub fn attribute(a: &Inputs, b: &Inputs, metric: impl Fn(&Evaluation) -> Option<f64>) -> Option<Vec<Contribution>> {
+    let changed: Vec<Field> = Field::ALL.iter().copied().filter(|field| changed(*field, a, b)).collect();
+    let orders = permutations(&changed);
+    let mut totals = vec![0.0; changed.len()];
+    for order in &orders {
+        let mut current = a.clone();
+        let mut before = metric(&evaluation::evaluate(&current))?;
+        for input in order {
+            substitute(*input, &mut current, b);
+            let after = metric(&evaluation::evaluate(&current))?;
+            let idx = changed.iter().position(|c| c == input).unwrap();
+            totals[idx] += after - before;
+            before = after;
+        }
+    }
+    let n = orders.len() as f64;
+    Some(changed.iter().zip(totals).map(|(input, total)| Contribution { input: *input, delta: total / n, from: input.get(a), to: input.get(b) }).collect())
+}
+
+fn describe(c: &Contribution) -> String {
+    let direction = if c.to > c.from { "higher" } else if c.to < c.from { "lower" } else { "re-unitized" };
+    format!("{} {}", direction, c.input.name())
+}
+
+/// "ratio improved 0.90 from lower CAC, but lost 0.40 from lower LTGP."
+fn sentence(metric: &str, contributions: &[Contribution], higher_is_better: bool, fmt: impl Fn(f64) -> String) -> String {
+    let significant: Vec<&Contribution> = contributions.iter().filter(|c| c.delta.abs() > 1e-9).collect();
+    if significant.is_empty() { return format!("{} did not change.", metric); }
+    let (good, bad): (Vec<&Contribution>, Vec<&Contribution>) = significant.iter().partition(|c| (c.delta > 0.0) == higher_is_better);
+    let verb = |improved: bool| if improved { "improved" } else { "worsened" };
+    let list = |items: &[&Contribution]| items.iter().map(|c| format!("{} from {}", fmt(c.delta.abs()), describe(c))).collect::<Vec<_>>().join(" and ");
+    match (good.is_empty(), bad.is_empty()) {
+        (false, true) => format!("{} {} {}.", metric, verb(true), list(&good)),
+        (true, false) => format!("{} {} {}.", metric, verb(false), list(&bad)),
+        _ => format!("{} {} {}, but {} {}.", metric, verb(true), list(&good), if higher_is_better { "lost" } else { "gave back" }, list(&bad)),
+    }
+}
+
+pub fn print_attribution(a: &Inputs, b: &Inputs) {
+    let (ea, eb) = (evaluation::evaluate(a), evaluation::evaluate(b));
+    println!("\nWhat changed (A → B):");
+    if let Some(c) = attribute(a, b, |e| e.ratio.is_finite().then_some(e.ratio)) {
+        wrapln!(" - {}", sentence(&format!("LTGP:CAC ({} → {})", ratio_text(ea.ratio), ratio_text(eb.ratio)), &c, true, |v| precision::fixed(v, 2)));
+    } else {
+        wrapln!(" - LTGP:CAC cannot be attributed because CAC is zero in one of the scenarios.");
+    }
+    if let Some(c) = attribute(a, b, |e| Some(e.net_outlay)) {
+        wrapln!(" - {}", sentence(&format!("Net upfront outlay ({} → {})", money(ea.net_outlay), money(eb.net_outlay)), &c, false, money));
+    }
+    match (attribute(a, b, |e| e.payback_days()), ea.payback_days(), eb.payback_days()) {
+        (Some(c), Some(from), Some(to)) => wrapln!(" - {}", sentence(&format!("Payback ({} → {} days)", precision::fixed(from, 1), precision::fixed(to, 1)), &c, false, |v| format!("{} days", precision::fixed(v, 1)))),
+        _ => wrapln!(" - Payback cannot be attributed because one scenario has no early gross profit."),
+    }
+}
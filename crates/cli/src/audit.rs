@@ -0,0 +1,70 @@
+// Audit log: with `--audit-log PATH` (or `audit_log` in the config file) every evaluation a run
+// makes is appended to PATH as one JSON line saying who ran it, how, on which inputs, and with
+// what result, for teams that have to show where a forecast figure came from. The file is only
+// ever appended to, one complete line per write, and is separate from the `--save-run` history:
+//
+//   {"at": "2026-10-16T23:51:00+00:00", "user": "alice", "host": "build-7", "version": "0.1.0",
+//    "command": ["ltgp_cac_calculator", "batch", "q3.csv"], "name": "acme", "result": {…}}
+//
+// `at` is in the run's `--timezone` (UTC unless set), and `result` is the object `--format json`
+// prints, inputs included. Values of flags that carry credentials (`--api-key`, `--access-token`,
+// …) are written as `<redacted>`.
+
+use std::io::Write;
+use std::sync::OnceLock;
+
+use serde_json::{json, Value};
+
+use crate::clock;
+use crate::report::{self, EvaluationResult};
+
+static PATH: OnceLock<Option<String>> = OnceLock::new();
+
+/// Flag names containing any of these take a credential.
+const SECRET_FLAGS: [&str; 4] = ["key", "token", "secret", "password"];
+
+/// Sets the log file for the rest of the run; None turns auditing off.
+pub fn install(path: Option<String>) {
+    let _ = PATH.set(path);
+}
+
+/// The command line as run, with credential values replaced.
+fn command() -> Vec<String> {
+    let is_secret = |flag: &str| flag.starts_with("--") && SECRET_FLAGS.iter().any(|s| flag.contains(s));
+    let mut redact_next = false;
+    std::env::args()
+        .map(|arg| {
+            if std::mem::take(&mut redact_next) {
+                return "<redacted>".to_string();
+            }
+            match arg.split_once('=') {
+                Some((flag, _)) if is_secret(flag) => format!("{}=<redacted>", flag),
+                None if is_secret(&arg) => {
+                    redact_next = true;
+                    arg
+                }
+                _ => arg,
+            }
+        })
+        .collect()
+}
+
+fn entry(name: &str, r: &EvaluationResult) -> Value {
+    json!({
+        "at": clock::rfc3339(),
+        "user": std::env::var("USER").or_else(|_| std::env::var("USERNAME")).ok(),
+        "host": std::env::var("HOSTNAME").or_else(|_| std::env::var("COMPUTERNAME")).ok(),
+        "version": env!("CARGO_PKG_VERSION"),
+        "command": command(),
+        "name": (!name.is_empty()).then_some(name),
+        "result": report::json_value(r),
+    })
+}
+
+/// Appends one line per `(name, result)`; nothing when no log is set.
+pub fn record(results: &[(&str, &EvaluationResult)]) -> Result<(), String> {
+    let Some(path) = PATH.get().and_then(Option::as_ref) else { return Ok(()) };
+    let lines: String = results.iter().map(|(name, r)| format!("{}\n", entry(name, r))).collect();
+    let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path).map_err(|e| format!("could not open audit log {}: {}", path, e))?;
+    file.write_all(lines.as_bytes()).map_err(|e| format!("could not write audit log {}: {}", path, e))
+}
@@ -0,0 +1,454 @@
+// Batch mode: evaluate every customer (or offer) in a CSV file, label each row with its quadrant
+// and verdict, and plot all of them on the CAC-vs-CFA-coverage plane so the shape of the whole
+// base is visible: a terminal density plot, plus an SVG scatter on request. With `--grouped`, rows
+// name a channel or segment and each group's ratio spread is drawn as a box plot.
+//
+//   id,cac,cfa,ltgp,early_gp_rate
+//   acme,500,200,2500,50
+//   globex,1200,0,3000
+//
+//   id,group,cac,cfa,ltgp           (--grouped)
+//   acme,paid-search,500,200,2500
+
+use std::collections::BTreeMap;
+
+use clap::Args;
+
+use crate::airtable;
+use crate::audit;
+use crate::chart_data::{self, ChartData};
+use crate::evaluation::{self, ratio_text, Evaluation, Inputs};
+use crate::layout::icon;
+use crate::locale::{self, Locale};
+use crate::notify;
+use crate::period::Period;
+use crate::precision;
+use crate::report::EvaluationResult;
+use crate::rules::COLORS;
+use crate::scenario;
+use crate::severity::{self, Severity};
+use crate::sheets;
+use crate::sweep::verdict_label;
+use crate::theme;
+use crate::uncertainty::percentile;
+use crate::webhook;
+
+#[derive(Args, Debug)]
+pub struct BatchArgs {
+    /// CSV file with `id,cac,cfa,ltgp[,early_gp_rate]` rows; a header row is skipped
+    #[arg(required_unless_present = "from_sheet")]
+    pub file: Option<String>,
+
+    /// Read the rows from a Google Sheets range instead (URL!A2:F20: name, cac, cfa, ltgp, early_gp_rate, period)
+    #[arg(long, value_parser = sheets::Sheet::parse, conflicts_with_all = ["file", "grouped"])]
+    pub from_sheet: Option<sheets::Sheet>,
+
+    /// Append every row's inputs and results to a Google Sheet (URL!Results)
+    #[arg(long, value_parser = sheets::Sheet::parse)]
+    pub to_sheet: Option<sheets::Sheet>,
+
+    /// Rows carry a group (channel, segment) as their second column: `id,group,cac,cfa,ltgp[,early_gp_rate]`
+    #[arg(long, default_value_t = false)]
+    pub grouped: bool,
+
+    /// Period unit for the early gross profit rate column
+    #[arg(long, value_enum, ignore_case = true, default_value_t = Period::Days)]
+    pub period: Period,
+
+    /// Consider CAC 'low' if CAC < threshold_fraction * LTGP
+    #[arg(long, value_parser = locale::number, default_value_t = 0.10)]
+    pub low_cac_fraction: f64,
+
+    /// Consider CFA 'high' if CFA ≥ threshold_fraction * CAC
+    #[arg(long, value_parser = locale::number, default_value_t = evaluation::DEFAULT_HIGH_CFA_FRACTION)]
+    pub high_cfa_fraction: f64,
+
+    /// Write an SVG scatter of every row on the CAC-vs-CFA-coverage plane to this path
+    #[arg(long)]
+    pub scatter_svg: Option<String>,
+
+    /// Write the per-group LTGP:CAC box plots as an SVG image to this path (with --grouped)
+    #[arg(long, requires = "grouped")]
+    pub box_svg: Option<String>,
+
+    /// Write the plotted points (and box plot statistics) to a CSV file (`chart,series,x,y`)
+    #[arg(long)]
+    pub chart_data: Option<String>,
+}
+
+pub struct Row {
+    pub id: String,
+    pub group: Option<String>,
+    pub inputs: Inputs,
+}
+
+/// Reads `id,cac,cfa,ltgp[,early_gp_rate]` rows, or `id,group,…` when `grouped`; blank lines,
+/// `#` comments, and a header row are skipped.
+pub fn read_rows(path: &str, template: &Inputs, grouped: bool) -> Result<Vec<Row>, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path, e))?;
+    let mut rows = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') { continue; }
+        let mut fields: Vec<&str> = line.split(',').map(|f| f.trim()).collect();
+        let group = (grouped && fields.len() > 1).then(|| fields.remove(1).to_string());
+        let num = |s: &str| Locale::EN.parse(s);
+        let parsed = match fields.as_slice() {
+            [id, cac, cfa, ltgp] => num(cac).zip(num(cfa)).zip(num(ltgp)).map(|((cac, cfa), ltgp)| (id, cac, cfa, ltgp, 0.0)),
+            [id, cac, cfa, ltgp, rate] => num(cac).zip(num(cfa)).zip(num(ltgp)).zip(num(rate)).map(|(((cac, cfa), ltgp), rate)| (id, cac, cfa, ltgp, rate)),
+            _ => None,
+        };
+        match parsed {
+            Some((id, cac, cfa, ltgp, rate)) => {
+                let inputs = scenario::checked(Inputs { cac, cfa, ltgp, early_gp_rate: rate, ..template.clone() }, &format!("{}:{}", path, i + 1))?;
+                tracing::trace!(line = i + 1, id, "row read");
+                rows.push(Row { id: id.to_string(), group, inputs });
+            }
+            None if i == 0 => {
+                tracing::debug!("skipping header line");
+                continue;
+            }
+            None if grouped => return Err(format!("{}:{}: expected `id,group,cac,cfa,ltgp[,early_gp_rate]`", path, i + 1)),
+            None => return Err(format!("{}:{}: expected `id,cac,cfa,ltgp[,early_gp_rate]`", path, i + 1)),
+        }
+    }
+    if rows.is_empty() {
+        return Err(format!("{}: no rows", path));
+    }
+    Ok(rows)
+}
+
+/// Rows from a Google Sheets range; CAC and LTGP are required, the rest default as in the CSV.
+fn sheet_rows(sheet: &sheets::Sheet, template: &Inputs) -> Result<Vec<Row>, String> {
+    let mut rows = Vec::new();
+    for (i, r) in sheets::read(sheet)?.into_iter().enumerate() {
+        let (Some(cac), Some(ltgp)) = (r.cac, r.ltgp) else {
+            return Err(format!("{} row {}: CAC and LTGP are required", sheet.range, i + 1));
+        };
+        let inputs = Inputs {
+            cac: cac.mean,
+            cfa: r.cfa.map(|c| c.mean).unwrap_or(0.0),
+            ltgp: ltgp.mean,
+            early_gp_rate: r.early_gp_rate.unwrap_or(0.0),
+            period: r.period.unwrap_or(template.period),
+            ..template.clone()
+        };
+        let inputs = scenario::checked(inputs, &format!("{} row {}", sheet.range, i + 1))?;
+        rows.push(Row { id: r.name.unwrap_or_else(|| format!("row {}", i + 1)), group: None, inputs });
+    }
+    Ok(rows)
+}
+
+fn coverage(i: &Inputs) -> f64 {
+    if i.cac > 0.0 { i.cfa / i.cac } else { 0.0 }
+}
+
+fn median(mut values: Vec<f64>) -> f64 {
+    values.sort_by(f64::total_cmp);
+    values.get(values.len() / 2).copied().unwrap_or_default()
+}
+
+/// Plot bounds: CAC across, CFA coverage up, with the coverage axis capped at 3× so a few
+/// prepaid outliers don't flatten everyone else (they are drawn on the top edge).
+fn bounds(evals: &[(String, Evaluation)]) -> (f64, f64) {
+    let x_max = evals.iter().map(|(_, e)| e.inputs.cac).fold(0.0, f64::max).max(1.0) * 1.05;
+    let y_max = evals.iter().map(|(_, e)| coverage(&e.inputs)).fold(0.0, f64::max).clamp(1.0, 3.0) * 1.05;
+    (x_max, y_max)
+}
+
+const WIDTH: usize = 56;
+const HEIGHT: usize = 16;
+const DENSITY: [char; 5] = ['·', '∘', 'o', 'O', '@'];
+
+fn density(count: usize) -> char {
+    match count {
+        0 => ' ',
+        1 => DENSITY[0],
+        2..=3 => DENSITY[1],
+        4..=7 => DENSITY[2],
+        8..=15 => DENSITY[3],
+        _ => DENSITY[4],
+    }
+}
+
+fn print_density_plot(evals: &[(String, Evaluation)], low_cac_line: f64, high_cfa_fraction: f64) {
+    let (x_max, y_max) = bounds(evals);
+    let col = |cac: f64| ((cac / x_max) * (WIDTH - 1) as f64).round().clamp(0.0, (WIDTH - 1) as f64) as usize;
+    let row = |cov: f64| HEIGHT - 1 - ((cov / y_max) * (HEIGHT - 1) as f64).round().clamp(0.0, (HEIGHT - 1) as f64) as usize;
+
+    let mut counts = vec![vec![0usize; WIDTH]; HEIGHT];
+    for (_, e) in evals {
+        counts[row(coverage(&e.inputs))][col(e.inputs.cac)] += 1;
+    }
+    let (cac_col, cfa_row) = (col(low_cac_line), row(high_cfa_fraction));
+    println!("\nCustomer density (· 1, ∘ 2–3, o 4–7, O 8–15, @ 16+; | = median low-CAC cut ${}, - = high-CFA cut {}% of CAC):", precision::fixed(low_cac_line, 0), precision::fixed(high_cfa_fraction * 100.0, 0));
+    println!("  CFA ÷ CAC");
+    for (r, cells) in counts.iter().enumerate() {
+        let line: String = cells.iter().enumerate().map(|(c, n)| match (*n, c == cac_col, r == cfa_row) {
+            (0, true, true) => '+',
+            (0, true, false) => '|',
+            (0, false, true) => '-',
+            (n, _, _) => density(n),
+        }).collect();
+        let label = match r {
+            0 => format!("{:>7}%", precision::fixed(y_max * 100.0, 0)),
+            _ if r == HEIGHT - 1 => format!("{:>8}", "0%"),
+            _ => " ".repeat(8),
+        };
+        println!("{} │{}", label, line);
+    }
+    println!("{} └{}", " ".repeat(8), "─".repeat(WIDTH));
+    println!("{}  $0{:>width$}  CAC", " ".repeat(8), precision::fixed(x_max, 0), width = WIDTH - 2);
+}
+
+/// Standalone SVG scatter, one dot per row colored by quadrant, with each row's id on hover.
+fn scatter_svg(evals: &[(String, Evaluation)], quadrants: &[&str], low_cac_line: f64, high_cfa_fraction: f64) -> String {
+    let (x_max, y_max) = bounds(evals);
+    let (width, height, top, left) = (560.0, 340.0, 20.0, 60.0);
+    let x = |cac: f64| left + (cac / x_max).min(1.0) * width;
+    let y = |cov: f64| top + (1.0 - (cov / y_max).min(1.0)) * height;
+    let theme = theme::active();
+    let (axis, guide) = (&theme.axis, &theme.guide);
+    let color = |q: &str| theme.hex(COLORS[quadrants.iter().position(|n| *n == q).unwrap_or(0) % COLORS.len()]);
+
+    let legend_top = top + height + 44.0;
+    let mut out = theme.svg_open(left + width + 20.0, legend_top + quadrants.len() as f64 * 18.0 + 10.0, 11);
+    out.push_str(&format!(r#"<rect x="{left}" y="{top}" width="{width}" height="{height}" fill="none" stroke="{axis}"/>"#));
+    out.push_str(&format!(r#"<line x1="{lx:.1}" x2="{lx:.1}" y1="{top}" y2="{b}" stroke="{guide}" stroke-dasharray="4 3"/>"#, lx = x(low_cac_line), b = top + height));
+    out.push_str(&format!(r#"<line x1="{left}" x2="{r}" y1="{hy:.1}" y2="{hy:.1}" stroke="{guide}" stroke-dasharray="4 3"/>"#, r = left + width, hy = y(high_cfa_fraction)));
+    for (id, e) in evals {
+        out.push_str(&format!(r#"<circle cx="{cx:.1}" cy="{cy:.1}" r="4" fill="{fill}" fill-opacity="0.7"><title>{id}: {q}</title></circle>"#, cx = x(e.inputs.cac), cy = y(coverage(&e.inputs)), fill = color(e.quadrant.name()), q = e.quadrant.name()));
+    }
+    out.push_str(&format!(r#"<text x="{r}" y="{ty}" text-anchor="end">CAC ${x_max:.0}</text>"#, r = left + width, ty = top + height + 16.0));
+    out.push_str(&format!(r#"<text x="{lx}" y="{ty}" text-anchor="end">{pct:.0}%</text><text x="{lx}" y="{b}" text-anchor="end">0%</text>"#, lx = left - 6.0, ty = top + 10.0, pct = y_max * 100.0, b = top + height));
+    out.push_str(&format!(r#"<text x="{left}" y="{ty}">CFA ÷ CAC up, CAC across; dashed lines are the median low-CAC and high-CFA cuts</text>"#, ty = top + height + 30.0));
+    for (n, q) in quadrants.iter().enumerate() {
+        let ly = legend_top + n as f64 * 18.0;
+        out.push_str(&format!(r#"<circle cx="{cx}" cy="{cy}" r="5" fill="{fill}"/><text x="{tx}" y="{ty}">{q}</text>"#, cx = left + 5.0, cy = ly, fill = color(q), tx = left + 16.0, ty = ly + 4.0));
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+/// One group's LTGP:CAC five-number summary; rows with a free customer (CAC of 0) are counted
+/// in `rows` but left out of the spread, and a group with nothing else isn't plotted.
+struct GroupBox<'a> {
+    name: &'a str,
+    rows: usize,
+    min: f64,
+    q1: f64,
+    median: f64,
+    q3: f64,
+    max: f64,
+    mean: f64,
+    below: usize,
+}
+
+impl GroupBox<'_> {
+    fn new(name: &str, mut ratios: Vec<f64>) -> Option<GroupBox<'_>> {
+        let rows = ratios.len();
+        ratios.retain(|r| r.is_finite());
+        if ratios.is_empty() {
+            return None;
+        }
+        ratios.sort_by(f64::total_cmp);
+        let mean = ratios.iter().sum::<f64>() / ratios.len().max(1) as f64;
+        Some(GroupBox {
+            name,
+            rows,
+            min: ratios.first().copied().unwrap_or_default(),
+            q1: percentile(&ratios, 0.25),
+            median: percentile(&ratios, 0.5),
+            q3: percentile(&ratios, 0.75),
+            max: ratios.last().copied().unwrap_or_default(),
+            mean,
+            below: ratios.iter().filter(|r| **r <= RATIO_BAR).count(),
+        })
+    }
+}
+
+/// The box plots mark this ratio; rows at or below it are counted per group.
+const RATIO_BAR: f64 = 3.0;
+const BOX_WIDTH: usize = 50;
+
+fn box_axis_max(boxes: &[GroupBox]) -> f64 {
+    boxes.iter().map(|b| b.max).filter(|m| m.is_finite()).fold(RATIO_BAR, f64::max) * 1.1
+}
+
+fn print_box_plots(boxes: &[GroupBox]) {
+    let top = box_axis_max(boxes);
+    let col = |v: f64| ((v.max(0.0) / top) * (BOX_WIDTH - 1) as f64).round() as usize;
+    let name_width = boxes.iter().map(|b| b.name.chars().count()).max().unwrap_or(0).clamp(5, 20);
+    println!("\nLTGP:CAC by group (whiskers at min and max, box from Q1 to Q3, ┃ median, ┊ 3:1):");
+    for b in boxes {
+        let mut cells = [' '; BOX_WIDTH];
+        cells[col(RATIO_BAR)] = '┊';
+        for cell in &mut cells[col(b.min)..=col(b.max)] {
+            *cell = '─';
+        }
+        for cell in &mut cells[col(b.q1)..=col(b.q3)] {
+            *cell = '▒';
+        }
+        cells[col(b.min)] = '├';
+        cells[col(b.max)] = '┤';
+        cells[col(b.median)] = '┃';
+        let name: String = b.name.chars().take(name_width).collect();
+        println!(" {:<nw$} │{}│ n={:<4} median {}, mean {}, {} at or below 3:1", name, cells.iter().collect::<String>(), b.rows, precision::fixed(b.median, 2), precision::fixed(b.mean, 2), b.below, nw = name_width);
+    }
+    println!(" {:<nw$} 0{:>w$}", "", precision::fixed(top, 1), nw = name_width, w = BOX_WIDTH + 1);
+}
+
+/// Standalone SVG of the box plots on a shared LTGP:CAC axis, with the 3:1 bar dashed.
+fn box_svg(boxes: &[GroupBox]) -> String {
+    let (left, width, row_h, top) = (140.0, 480.0, 36.0, 20.0);
+    let axis_max = box_axis_max(boxes);
+    let x = |v: f64| left + v.max(0.0) / axis_max * width;
+    let bottom = top + boxes.len() as f64 * row_h;
+    let theme = theme::active();
+    let (axis, guide, ink) = (&theme.axis, &theme.guide, &theme.text);
+    let mut out = theme.svg_open(left + width + 30.0, bottom + 40.0, 11);
+    for (n, b) in boxes.iter().enumerate() {
+        let cy = top + n as f64 * row_h + row_h / 2.0;
+        out.push_str(&format!(r#"<text x="{tx}" y="{ty}" text-anchor="end">{name} (n={rows})</text>"#, tx = left - 8.0, ty = cy + 4.0, name = b.name, rows = b.rows));
+        out.push_str(&format!(r#"<line x1="{x1:.1}" x2="{x2:.1}" y1="{cy}" y2="{cy}" stroke="{axis}"/>"#, x1 = x(b.min), x2 = x(b.max)));
+        for v in [b.min, b.max] {
+            out.push_str(&format!(r#"<line x1="{vx:.1}" x2="{vx:.1}" y1="{y1}" y2="{y2}" stroke="{axis}"/>"#, vx = x(v), y1 = cy - 6.0, y2 = cy + 6.0));
+        }
+        let fill = theme.hex(if b.median > RATIO_BAR { "green" } else { "red" });
+        out.push_str(&format!(r#"<rect x="{bx:.1}" y="{by}" width="{bw:.1}" height="{bh}" fill="{fill}" fill-opacity="0.6" stroke="{axis}"><title>Q1 {q1:.2} · median {m:.2} · Q3 {q3:.2} · mean {mean:.2}</title></rect>"#, bx = x(b.q1), by = cy - 10.0, bw = (x(b.q3) - x(b.q1)).max(1.0), bh = 20.0, q1 = b.q1, m = b.median, q3 = b.q3, mean = b.mean));
+        out.push_str(&format!(r#"<line x1="{mx:.1}" x2="{mx:.1}" y1="{y1}" y2="{y2}" stroke="{ink}" stroke-width="2"/>"#, mx = x(b.median), y1 = cy - 10.0, y2 = cy + 10.0));
+    }
+    out.push_str(&format!(r#"<line x1="{bx:.1}" x2="{bx:.1}" y1="{top}" y2="{bottom}" stroke="{guide}" stroke-dasharray="4 3"/><text x="{bx:.1}" y="{ty}" text-anchor="middle">3:1</text>"#, bx = x(RATIO_BAR), ty = bottom + 14.0));
+    out.push_str(&format!(r#"<text x="{left}" y="{ty}">0</text><text x="{r}" y="{ty}" text-anchor="end">{axis_max:.1}</text><text x="{mid}" y="{ly}" text-anchor="middle">LTGP:CAC</text>"#, r = left + width, ty = bottom + 14.0, mid = left + width / 2.0, ly = bottom + 32.0));
+    out.push_str("</svg>\n");
+    out
+}
+
+pub fn run(args: &BatchArgs) {
+    let template = Inputs {
+        cac: 0.0,
+        cfa: 0.0,
+        ltgp: 0.0,
+        early_gp_rate: 0.0,
+        period: args.period,
+        low_cac_fraction: args.low_cac_fraction,
+        high_cfa_fraction: args.high_cfa_fraction,
+        low_cac_max: None,
+        high_cfa_min: None,
+    };
+    let _span = tracing::info_span!("batch", source = args.from_sheet.as_ref().map(|s| s.range.as_str()).or(args.file.as_deref())).entered();
+    let (rows, source) = match (&args.from_sheet, &args.file) {
+        (Some(sheet), _) => (sheet_rows(sheet, &template), sheet.range.clone()),
+        (None, Some(file)) => (read_rows(file, &template, args.grouped), file.clone()),
+        (None, None) => unreachable!("clap requires a file or --from-sheet"),
+    };
+    let rows = rows.unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(2);
+    });
+    tracing::debug!(rows = rows.len(), "rows read");
+    let groups: Vec<Option<String>> = rows.iter().map(|r| r.group.clone()).collect();
+    let evals: Vec<(String, Evaluation)> = rows.into_iter().map(|r| (r.id, evaluation::evaluate(&r.inputs))).collect();
+    for (id, e) in &evals {
+        tracing::debug!(id = %id, ratio = e.ratio, quadrant = e.quadrant.name(), "row evaluated");
+    }
+    tracing::info!(rows = evals.len(), "batch evaluated");
+
+    let results: Vec<(String, EvaluationResult)> = evals.iter().map(|(id, e)| (id.clone(), EvaluationResult::from(e))).collect();
+
+    println!("\n=== Batch: {} rows from {} ===\n", results.len(), source);
+    println!("{:<16}  {:>10}  {:>8}  {:>9}  {:<24}  Verdict", "Id", "CAC", "CFA÷CAC", "LTGP:CAC", "Quadrant");
+    for (id, r) in &results {
+        let severity = r.severity.level;
+        let special = r.degenerate.as_ref().map(|d| format!("  {}{}", icon(Severity::Watch), d.kind.headline())).unwrap_or_default();
+        let covered = precision::fixed(coverage(&r.inputs) * 100.0, 0);
+        println!("{:<16}  {:>10}  {:>7}%  {:>9}  {:<24}  {}{}{}", id, precision::fixed(r.inputs.cac, 2), covered, ratio_text(r.ratio_value()), r.quadrant, icon(severity), severity::paint(severity, verdict_label(&r.verdict)), special);
+    }
+    let warnings: Vec<String> = results.iter().flat_map(|(id, r)| r.warnings.iter().map(move |w| format!(" - {}: {}", id, w))).collect();
+    if !warnings.is_empty() {
+        println!("\n{}Warnings:", icon(Severity::Watch));
+        warnings.iter().for_each(|w| println!("{}", w));
+    }
+
+    let mut by_quadrant: BTreeMap<&str, usize> = BTreeMap::new();
+    for (_, e) in &evals {
+        *by_quadrant.entry(e.quadrant.name()).or_default() += 1;
+    }
+    println!("\nBy quadrant:");
+    for (q, n) in &by_quadrant {
+        println!(" - {:<24} {:>5} ({}%)", q, n, precision::fixed(*n as f64 / evals.len() as f64 * 100.0, 0));
+    }
+
+    // Each row's low-CAC cut scales with its own LTGP, so the plot draws the median row's cut.
+    let low_cac_line = median(evals.iter().map(|(_, e)| e.low_cac_thresh).collect());
+    print_density_plot(&evals, low_cac_line, args.high_cfa_fraction);
+    let mut data = ChartData::new();
+    for (id, e) in &evals {
+        data.push_xy("batch_scatter", id, e.inputs.cac, coverage(&e.inputs));
+    }
+
+    if args.grouped {
+        let mut ratios: BTreeMap<&str, Vec<f64>> = BTreeMap::new();
+        for (group, (_, e)) in groups.iter().zip(&evals) {
+            ratios.entry(group.as_deref().unwrap_or_default()).or_default().push(e.ratio);
+        }
+        let boxes: Vec<GroupBox> = ratios.into_iter().filter_map(|(name, values)| GroupBox::new(name, values)).collect();
+        print_box_plots(&boxes);
+        for b in &boxes {
+            for (stat, v) in [("min", b.min), ("q1", b.q1), ("median", b.median), ("q3", b.q3), ("max", b.max), ("mean", b.mean)] {
+                data.push("box_plot", b.name, stat, v);
+            }
+        }
+        if let Some(path) = &args.box_svg {
+            match std::fs::write(path, box_svg(&boxes)) {
+                Ok(()) => println!("\nBox plots written to {}.", path),
+                Err(e) => {
+                    eprintln!("Error: could not write {}: {}", path, e);
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
+
+    if let Some(path) = &args.scatter_svg {
+        let quadrants: Vec<&str> = by_quadrant.keys().copied().collect();
+        match std::fs::write(path, scatter_svg(&evals, &quadrants, low_cac_line, args.high_cfa_fraction)) {
+            Ok(()) => println!("\nScatter plot written to {}.", path),
+            Err(e) => {
+                eprintln!("Error: could not write {}: {}", path, e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if let Some(sheet) = &args.to_sheet {
+        match sheets::append(sheet, results.iter().map(|(id, r)| sheets::result_row(id, r)).collect()) {
+            Ok(()) => println!("\n{} results appended to {}.", evals.len(), sheet.range),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if let Err(e) = audit::record(&results.iter().map(|(id, r)| (id.as_str(), r)).collect::<Vec<_>>()) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+
+    match airtable::push(results.iter().map(|(id, r)| airtable::record(id, r)).collect()) {
+        Ok(Some(table)) => println!("\n{} results pushed to Airtable ({}).", evals.len(), table),
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+
+    if let Err(e) = notify::batch(&results).and_then(|()| webhook::batch(&results)) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+
+    chart_data::save(&data, args.chart_data.as_deref());
+}
@@ -0,0 +1,95 @@
+// Peer benchmarks: where the user's ratio, payback, and CFA coverage sit against typical ranges
+// for their business model, from a small dataset bundled into the binary or a shared JSON URL.
+//
+// A remote dataset has the same shape as the bundled one:
+//   {"model": [{"name": "saas-smb", "description": "...", "ratio": [1.5, 2.3, 3.2, 4.5, 6.0],
+//               "payback_months": [30, 20, 14, 9, 6], "cfa_coverage": [0, 0.05, 0.1, 0.3, 0.6],
+//               "operating_margin": [-40, -20, -5, 10, 20]}]}
+// `operating_margin` (percent) is optional.
+
+use serde::Deserialize;
+
+use crate::evaluation::{ratio_text, Evaluation};
+use crate::layout::wrapln;
+use crate::precision;
+
+const BUNDLED: &str = include_str!("benchmarks.toml");
+
+/// Percentiles that each benchmark row lists, weakest peer first.
+const PERCENTILES: [f64; 5] = [10.0, 25.0, 50.0, 75.0, 90.0];
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct Benchmark {
+    pub name: String,
+    pub description: String,
+    pub ratio: [f64; 5],
+    pub payback_months: [f64; 5],
+    pub cfa_coverage: [f64; 5],
+    #[serde(default)]
+    pub operating_margin: Option<[f64; 5]>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Dataset {
+    model: Vec<Benchmark>,
+}
+
+pub fn bundled() -> Vec<Benchmark> {
+    toml::from_str::<Dataset>(BUNDLED).expect("bundled benchmarks.toml is valid").model
+}
+
+/// Downloads a benchmark dataset published as JSON, e.g. an organization's internal set.
+pub fn fetch(url: &str) -> Result<Vec<Benchmark>, String> {
+    let body = ureq::get(url).call().and_then(|mut r| r.body_mut().read_to_string()).map_err(|e| format!("could not fetch {}: {}", url, e))?;
+    let dataset: Dataset = serde_json::from_str(&body).map_err(|e| format!("{}: {}", url, e))?;
+    Ok(dataset.model)
+}
+
+/// Looks up a business model by name, listing the known names when it isn't found.
+pub fn find(benchmarks: &[Benchmark], name: &str) -> Result<Benchmark, String> {
+    benchmarks.iter().find(|b| b.name.eq_ignore_ascii_case(name)).cloned().ok_or_else(|| {
+        let names = benchmarks.iter().map(|b| b.name.as_str()).collect::<Vec<_>>().join(", ");
+        format!("unknown benchmark `{}` (available: {})", name, names)
+    })
+}
+
+/// Share of peers `value` beats, interpolated between the listed percentiles and clamped to 10–90.
+/// `points` run weakest to strongest, so this works whether higher or lower is better.
+pub fn percentile(value: f64, points: &[f64; 5]) -> f64 {
+    let better = |a: f64, b: f64| if points[4] >= points[0] { a >= b } else { a <= b };
+    if !better(value, points[0]) { return PERCENTILES[0]; }
+    for k in 1..points.len() {
+        if !better(value, points[k]) {
+            let span = points[k] - points[k - 1];
+            let t = if span != 0.0 { (value - points[k - 1]) / span } else { 1.0 };
+            return PERCENTILES[k - 1] + t * (PERCENTILES[k] - PERCENTILES[k - 1]);
+        }
+    }
+    PERCENTILES[4]
+}
+
+fn describe(p: f64) -> String {
+    if p <= PERCENTILES[0] {
+        "bottom 10% of peers".to_string()
+    } else if p >= PERCENTILES[4] {
+        "top 10% of peers".to_string()
+    } else {
+        format!("better than about {}% of peers", precision::fixed(p, 0))
+    }
+}
+
+pub fn print_benchmark_report(e: &Evaluation, b: &Benchmark, operating_margin: Option<f64>) {
+    let i = &e.inputs;
+    println!("\nBenchmark vs {} ({}):", b.name, b.description);
+    wrapln!(" - LTGP:CAC {} (peer median {}) → {}.", ratio_text(e.ratio), precision::fixed(b.ratio[2], 1), describe(percentile(e.ratio, &b.ratio)));
+    match e.cac_payback_months {
+        Some(m) => wrapln!(" - CAC payback {} months (peer median {}) → {}.", precision::fixed(m, 1), precision::fixed(b.payback_months[2], 1), describe(percentile(m, &b.payback_months))),
+        None => wrapln!(" - CAC payback: not compared; provide --early-gp-rate."),
+    }
+    let coverage = if i.cac > 0.0 { i.cfa / i.cac } else { 0.0 };
+    wrapln!(" - CFA coverage {}% of CAC (peer median {}%) → {}.", precision::fixed(coverage * 100.0, 0), precision::fixed(b.cfa_coverage[2] * 100.0, 0), describe(percentile(coverage, &b.cfa_coverage)));
+    if let (Some(margin), Some(points)) = (operating_margin, &b.operating_margin) {
+        wrapln!(" - Operating margin {}% (peer median {}%) → {}.", precision::fixed(margin, 0), precision::fixed(points[2], 0), describe(percentile(margin, points)));
+    }
+}
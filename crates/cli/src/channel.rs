@@ -0,0 +1,42 @@
+// The channel-mix section of the report, from the saturation math in ltgp_core::channel.
+
+pub use ltgp_core::channel::*;
+
+use crate::evaluation::money;
+use crate::layout::wrapln;
+use crate::precision;
+
+pub fn print_channel_report(channels: &[Channel], ltgp: f64, monthly_volume: Option<f64>) {
+    println!("\nChannel mix:");
+    for c in channels {
+        let cap = c.capacity.map(|v| format!("up to {} customers/month", precision::fixed(v, 0))).unwrap_or_else(|| "no capacity limit".to_string());
+        wrapln!(" - {}: starts at {} per customer, {}.", c.name, money(c.cac), cap);
+    }
+    match total_capacity(channels) {
+        Some(cap) => wrapln!(" - Combined ceiling: {} customers/month, no matter the budget.", precision::fixed(cap, 0)),
+        None => wrapln!(" - At least one channel has no capacity limit."),
+    }
+
+    let ceiling = max_volume_within(channels, ltgp / 3.0);
+    if ceiling.is_finite() {
+        wrapln!(" - Most you can buy while every marginal customer clears LTGP:CAC > 3: about {} customers/month.", precision::fixed(ceiling, 0));
+    } else {
+        wrapln!(" - An unlimited channel clears LTGP:CAC > 3 at its entered CAC, so volume is not capped by economics.");
+    }
+
+    if let Some(target) = monthly_volume {
+        match allocate(channels, target) {
+            Some(a) => {
+                println!("\nCheapest mix for {} customers/month:", precision::fixed(target, 0));
+                for (c, v) in channels.iter().zip(&a.volumes) {
+                    wrapln!(" - {}: {} customers (spend {})", c.name, precision::fixed(*v, 0), money(c.spend_for(*v)));
+                }
+                wrapln!(" - Blended CAC {}; marginal CAC {} (marginal LTGP:CAC {}).", money(a.blended_cac()), money(a.marginal_cac), precision::fixed(ltgp / a.marginal_cac, 2));
+                if ltgp / a.marginal_cac <= 3.0 {
+                    println!("\nWarning: At this volume the last customers cost more than a third of LTGP; saturation is eating your margin.");
+                }
+            }
+            None => println!("\nWarning: {} customers/month exceeds what these channels can deliver.", precision::fixed(target, 0)),
+        }
+    }
+}
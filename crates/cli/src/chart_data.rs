@@ -0,0 +1,72 @@
+// Chart series as CSV: with `--chart-data out.csv`, every chart drawn in the run adds the points
+// behind it to one long table, `chart,series,x,y`, so they can be re-plotted in a spreadsheet,
+// notebook, or BI tool instead of being read off the terminal.
+//
+//   chart,series,x,y
+//   payback_curve,cumulative_gross_profit,0,0
+//   ratio_histogram,runs,2.42,1846
+
+use std::fmt::Display;
+
+const HEADER: &str = "chart,series,x,y";
+
+#[derive(Debug, Default)]
+pub struct ChartData {
+    rows: Vec<[String; 4]>,
+}
+
+/// Quotes a CSV field when it holds a comma, quote, or line break.
+fn field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) { format!("\"{}\"", s.replace('"', "\"\"")) } else { s.to_string() }
+}
+
+fn number(v: f64) -> String {
+    if !v.is_finite() {
+        return String::new();
+    }
+    let s = format!("{:.4}", v);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" { "0".to_string() } else { s.to_string() }
+}
+
+impl ChartData {
+    pub fn new() -> ChartData {
+        ChartData::default()
+    }
+
+    /// Adds one point; `y` is written with up to four decimals, and left empty when it isn't finite.
+    pub fn push(&mut self, chart: &str, series: &str, x: impl Display, y: f64) {
+        self.rows.push([chart.to_string(), series.to_string(), x.to_string(), number(y)]);
+    }
+
+    /// `push` with a numeric `x`, written the same way as `y`.
+    pub fn push_xy(&mut self, chart: &str, series: &str, x: f64, y: f64) {
+        self.push(chart, series, number(x), y);
+    }
+
+    pub fn write(&self, path: &str) -> Result<(), String> {
+        let mut text = String::from(HEADER);
+        text.push('\n');
+        for row in &self.rows {
+            text.push_str(&row.iter().map(|f| field(f)).collect::<Vec<_>>().join(","));
+            text.push('\n');
+        }
+        std::fs::write(path, text).map_err(|e| format!("could not write {}: {}", path, e))
+    }
+}
+
+/// Writes `data` to `path` when one was given, reporting like the SVG exports do.
+pub fn save(data: &ChartData, path: Option<&str>) {
+    let Some(path) = path else { return };
+    if data.rows.is_empty() {
+        println!("\nChart data not written: no charts were drawn in this run.");
+        return;
+    }
+    match data.write(path) {
+        Ok(()) => println!("\nChart data written to {}.", path),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
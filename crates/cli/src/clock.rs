@@ -0,0 +1,65 @@
+// Run timestamps: every evaluation is stamped with when it ran, as RFC 3339 in the configured
+// timezone, in `--format json`, webhooks, the servers, the audit log, and the `--save-run`
+// history, so runs from different machines order correctly and join against other time series.
+// The timezone is `--timezone`, else `timezone` in the config file, else UTC; `local` is the
+// machine's own:
+//
+//   "evaluated_at": "2026-10-17T09:12:44+02:00", "timezone": "Europe/Berlin"
+//
+// Dates written on their own (the history's `date` column, spreadsheet and Airtable rows) are the
+// day in the same timezone.
+
+use std::sync::OnceLock;
+
+use jiff::tz::TimeZone;
+use jiff::{Timestamp, Zoned};
+
+use crate::report;
+
+static ZONE: OnceLock<(TimeZone, String)> = OnceLock::new();
+
+/// Resolves `name` (an IANA name such as `America/New_York`, `UTC`, or `local`; None for UTC) and
+/// stamps every result with it for the rest of the run.
+pub fn install(name: Option<&str>) -> Result<(), String> {
+    let zone = match name {
+        None => (TimeZone::UTC, "UTC".to_string()),
+        Some("local") => {
+            let tz = TimeZone::system();
+            let name = tz.iana_name().unwrap_or("local").to_string();
+            (tz, name)
+        }
+        Some(name) => (TimeZone::get(name).map_err(|_| format!("unknown timezone `{}` (use an IANA name such as Europe/Berlin, UTC, or local)", name))?, name.to_string()),
+    };
+    let _ = ZONE.set(zone);
+    report::set_clock(stamp);
+    Ok(())
+}
+
+fn zone() -> &'static (TimeZone, String) {
+    ZONE.get_or_init(|| (TimeZone::UTC, "UTC".to_string()))
+}
+
+fn now() -> Zoned {
+    Timestamp::now().to_zoned(zone().0.clone())
+}
+
+/// Now as RFC 3339 in the run's timezone, to the second.
+pub fn rfc3339() -> String {
+    now().strftime("%Y-%m-%dT%H:%M:%S%:z").to_string()
+}
+
+/// Today in the run's timezone, as YYYY-MM-DD.
+pub fn today() -> String {
+    now().date().to_string()
+}
+
+/// The time and timezone each result is stamped with.
+fn stamp() -> (String, String) {
+    (rfc3339(), zone().1.clone())
+}
+
+/// Orders RFC 3339 timestamps by the instant they name, whatever their offsets; None when `at`
+/// isn't one.
+pub fn instant(at: &str) -> Option<Timestamp> {
+    at.parse().ok()
+}
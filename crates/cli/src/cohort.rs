@@ -0,0 +1,105 @@
+// Bayesian updating of LTGP from a live cohort's observed monthly gross profit.
+//
+// The prior is the entered LTGP (normal, using its "±" spread or 30% when none was given).
+// The cohort's observed months are certain cash; only the tail beyond them is uncertain. The
+// tail is projected from a log-linear fit of the monthly decay, and its spread comes from the
+// fit's standard error. Prior and data are combined by precision weighting.
+
+use crate::evaluation::{self, money, ratio_text, Inputs};
+use crate::layout::wrapln;
+use crate::precision;
+
+/// Spread assumed for a prior LTGP entered without "±".
+const DEFAULT_PRIOR_CV: f64 = 0.30;
+/// Retention can't be projected at or above 100% forever; cap the fitted monthly retention here.
+const MAX_RETENTION: f64 = 0.99;
+
+#[derive(Debug, Clone)]
+pub struct CohortUpdate {
+    pub prior_mean: f64,
+    pub prior_sd: f64,
+    pub observed_months: usize,
+    pub observed_gp: f64,
+    pub retention: f64,
+    pub retention_capped: bool,
+    pub tail_mean: f64,
+    pub tail_sd: f64,
+    pub posterior_mean: f64,
+    pub posterior_sd: f64,
+}
+
+/// `monthly_gp` is average gross profit per originally acquired customer in each month since acquisition.
+pub fn update(prior_mean: f64, prior_sd: f64, monthly_gp: &[f64]) -> Result<CohortUpdate, String> {
+    if monthly_gp.len() < 2 { return Err("need at least two observed months".to_string()); }
+    if monthly_gp.iter().any(|g| *g <= 0.0) { return Err("observed monthly gross profit must be positive".to_string()); }
+    let prior_sd = if prior_sd > 0.0 { prior_sd } else { prior_mean.abs() * DEFAULT_PRIOR_CV };
+
+    // Log-linear fit: ln(gp_t) = a + b t
+    let k = monthly_gp.len() as f64;
+    let ts: Vec<f64> = (0..monthly_gp.len()).map(|t| t as f64).collect();
+    let ys: Vec<f64> = monthly_gp.iter().map(|g| g.ln()).collect();
+    let (mean_t, mean_y) = (ts.iter().sum::<f64>() / k, ys.iter().sum::<f64>() / k);
+    let stt: f64 = ts.iter().map(|t| (t - mean_t).powi(2)).sum();
+    let b = ts.iter().zip(&ys).map(|(t, y)| (t - mean_t) * (y - mean_y)).sum::<f64>() / stt;
+    let a = mean_y - b * mean_t;
+    let se_b = if monthly_gp.len() > 2 {
+        let sse: f64 = ts.iter().zip(&ys).map(|(t, y)| (y - (a + b * t)).powi(2)).sum();
+        (sse / (k - 2.0) / stt).sqrt()
+    } else {
+        // Two points fit exactly; treat the slope as no better known than its own size.
+        b.abs().max(0.05)
+    };
+
+    let fitted_retention = b.exp();
+    let retention = fitted_retention.min(MAX_RETENTION);
+    let last = (a + b * (k - 1.0)).exp();
+    let tail_mean = last * retention / (1.0 - retention);
+    // Delta method: d tail / d r = last / (1 - r)^2, and sd(r) ≈ r · sd(b)
+    let tail_sd = (last / (1.0 - retention).powi(2)) * retention * se_b;
+    let observed_gp: f64 = monthly_gp.iter().sum();
+    let data_mean = observed_gp + tail_mean;
+
+    let (prior_prec, data_prec) = (1.0 / prior_sd.powi(2), if tail_sd > 0.0 { 1.0 / tail_sd.powi(2) } else { f64::INFINITY });
+    let (posterior_mean, posterior_sd) = if data_prec.is_infinite() {
+        (data_mean, 0.0)
+    } else {
+        ((prior_mean * prior_prec + data_mean * data_prec) / (prior_prec + data_prec), (1.0 / (prior_prec + data_prec)).sqrt())
+    };
+
+    Ok(CohortUpdate {
+        prior_mean,
+        prior_sd,
+        observed_months: monthly_gp.len(),
+        observed_gp,
+        retention,
+        retention_capped: fitted_retention > MAX_RETENTION,
+        tail_mean,
+        tail_sd,
+        posterior_mean,
+        posterior_sd,
+    })
+}
+
+pub fn print_cohort_report(inputs: &Inputs, ltgp_sd: f64, monthly_gp: &[f64]) {
+    let u = match update(inputs.ltgp, ltgp_sd, monthly_gp) {
+        Ok(u) => u,
+        Err(e) => return println!("\nCohort update could not be computed: {}.", e),
+    };
+    let before = evaluation::evaluate(inputs);
+    let after = evaluation::evaluate(&Inputs { ltgp: u.posterior_mean, ..inputs.clone() });
+
+    println!("\nCohort update ({} months observed):", u.observed_months);
+    wrapln!(" - Prior LTGP: {} ± {}.", money(u.prior_mean), money(u.prior_sd));
+    wrapln!(" - Collected so far: {} per customer; fitted monthly retention of gross profit {}%.", money(u.observed_gp), precision::fixed(u.retention * 100.0, 1));
+    wrapln!(" - Projected remaining gross profit: {} ± {}.", money(u.tail_mean), money(u.tail_sd));
+    wrapln!(" - Posterior LTGP: {} ± {}.", money(u.posterior_mean), money(u.posterior_sd));
+    wrapln!(" - LTGP:CAC moves from {} to {}.", ratio_text(before.ratio), ratio_text(after.ratio));
+    if after.verdict != before.verdict {
+        println!("\nUpdated verdict: {}", after.verdict);
+    } else {
+        wrapln!(" - The verdict is unchanged by the cohort data.");
+    }
+    if u.retention_capped {
+        println!("\nNote: The cohort shows no decay yet, so retention was capped at {}% per month for the projection.", precision::fixed(MAX_RETENTION * 100.0, 0));
+    }
+}
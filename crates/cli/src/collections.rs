@@ -0,0 +1,85 @@
+// First-30-day collections: the Hormozi 2x rule, which asks whether what a customer pays in their
+// first 30 days covers at least twice the cost to acquire and fulfill them, and the
+// client-financed-acquisition window, which asks whether that cash covers CAC at all.
+//
+// Gross collections are estimated as CFA plus month 0 gross profit plus the 30-day fulfillment
+// cost (COGS), since gross profit is what's left after COGS; pass the measured figure to skip the
+// estimate.
+
+use crate::evaluation::{money, Inputs};
+use crate::layout::{icon, wrapln};
+use crate::period::DAYS_PER_MONTH;
+use crate::precision;
+use crate::severity::Severity;
+use crate::plan::UnitCashFlow;
+
+/// Per-customer cash collected and cost incurred in the first 30 days.
+#[derive(Debug, Clone, Copy)]
+pub struct FirstMonth {
+    pub collections: f64,
+    pub cfa: f64,
+    /// Gross profit per day during the first month, after the upfront CFA.
+    pub daily_gp: f64,
+    pub cac: f64,
+    pub cogs: f64,
+    /// True when `collections` came from the user rather than the estimate.
+    pub measured: bool,
+}
+
+impl FirstMonth {
+    pub fn new(inputs: &Inputs, cogs: f64, collections: Option<f64>) -> FirstMonth {
+        let unit = UnitCashFlow::from_inputs(inputs);
+        // Month 0 is an average calendar month, so the first 30 days get 30 of its days.
+        let daily_gp = unit.gp_in_month(0) / DAYS_PER_MONTH;
+        let estimate = unit.cfa + daily_gp * 30.0 + cogs;
+        FirstMonth { collections: collections.unwrap_or(estimate), cfa: unit.cfa, daily_gp, cac: inputs.cac, cogs, measured: collections.is_some() }
+    }
+
+    /// Twice the cost to acquire and fulfill one customer.
+    pub fn two_x_target(&self) -> f64 {
+        2.0 * (self.cac + self.cogs)
+    }
+
+    pub fn passes_two_x(&self) -> bool {
+        self.collections >= self.two_x_target()
+    }
+
+    /// Cash kept from the first 30 days once fulfillment is paid for.
+    pub fn net_cash(&self) -> f64 {
+        self.collections - self.cogs
+    }
+
+    /// Day within the first 30 on which the customer has paid back CAC, if they do.
+    /// Uses the CFA-plus-daily-gross-profit timing, or a straight line when collections were entered.
+    pub fn financed_on_day(&self) -> Option<f64> {
+        if self.net_cash() < self.cac { return None; }
+        if self.measured {
+            return Some(if self.net_cash() > 0.0 { 30.0 * self.cac / self.net_cash() } else { 0.0 });
+        }
+        if self.cfa >= self.cac { return Some(0.0); }
+        Some(((self.cac - self.cfa) / self.daily_gp).min(30.0))
+    }
+}
+
+pub fn print_two_x_rule(m: &FirstMonth) {
+    println!("\n2x rule (30-day gross collections ≥ 2 × (CAC + COGS)):");
+    let basis = if m.measured { "as entered" } else { "estimated from CFA, first-month gross profit, and COGS" };
+    wrapln!(" - Collected in the first 30 days: {} ({}).", money(m.collections), basis);
+    wrapln!(" - Target: {} (2 × ({} CAC + {} COGS)).", money(m.two_x_target()), money(m.cac), money(m.cogs));
+    if m.passes_two_x() {
+        wrapln!(" - {}PASS: each customer funds their own acquisition and fulfillment plus another customer's, so acquisition can compound on customer cash.", icon(Severity::Healthy));
+    } else {
+        let short = m.two_x_target() - m.collections;
+        wrapln!(" - {}FAIL: {} short per customer. Raise upfront pricing, add an upsell or deposit in the first 30 days, or cut CAC or fulfillment cost.", icon(Severity::Critical), money(short));
+    }
+}
+
+pub fn print_financing_window(m: &FirstMonth) {
+    println!("\n30-day client-financed acquisition:");
+    wrapln!(" - Cash kept from the first 30 days: {} ({} collected − {} fulfillment) against {} CAC.", money(m.net_cash()), money(m.collections), money(m.cogs), money(m.cac));
+    match m.financed_on_day() {
+        Some(day) if day <= 0.0 => wrapln!(" - {}Client-financed from day 0: upfront cash alone covers CAC.", icon(Severity::Healthy)),
+        Some(day) => wrapln!(" - {}Client-financed by day {}: the customer repays their acquisition cost inside the first month.", icon(Severity::Healthy), precision::fixed(day.ceil(), 0)),
+        None => wrapln!(" - {}Not client-financed within 30 days: {} of CAC is still uncovered at day 30, whatever the lifetime ratio says.", icon(Severity::Critical), money(m.cac - m.net_cash())),
+    }
+}
@@ -0,0 +1,122 @@
+// Side-by-side comparison of two or more full scenario files, with a grouped bar chart of the
+// tradeoffs between them.
+
+use clap::Args;
+
+use crate::attribution;
+use crate::chart_data::{self, ChartData};
+use crate::evaluation::{self, money, ratio_text, Evaluation, Inputs};
+use crate::layout::{self, icon, wrap};
+use crate::precision;
+use crate::report::EvaluationResult;
+use crate::scenario::{self, Scenario};
+use crate::scenario_chart;
+
+const LABEL_WIDTH: usize = 22;
+/// Widest a scenario column gets; narrower terminals shrink the columns to fit.
+const COLUMN_WIDTH: usize = 36;
+const MIN_COLUMN_WIDTH: usize = 16;
+
+fn column_width(columns: usize) -> usize {
+    let columns = columns.max(1);
+    layout::width().map(|w| (w.saturating_sub(LABEL_WIDTH + 2 * columns - 1) / columns).clamp(MIN_COLUMN_WIDTH, COLUMN_WIDTH)).unwrap_or(COLUMN_WIDTH)
+}
+
+#[derive(Args, Debug)]
+pub struct CompareArgs {
+    /// First scenario file (TOML)
+    pub a: String,
+
+    /// Second scenario file (TOML)
+    pub b: String,
+
+    /// More scenario files to put alongside the first two
+    pub more: Vec<String>,
+
+    /// Also write the grouped bar chart of ratio, payback, and net outlay as an SVG image to this path
+    #[arg(long)]
+    pub chart_svg: Option<String>,
+
+    /// Write the charted values to a CSV file (`chart,series,x,y`, one series per scenario)
+    #[arg(long)]
+    pub chart_data: Option<String>,
+}
+
+fn row<S: AsRef<str>>(label: &str, cells: &[S]) {
+    let cw = column_width(cells.len());
+    let cells: Vec<Vec<String>> = cells.iter().map(|c| wrap(c.as_ref(), cw)).collect();
+    for i in 0..cells.iter().map(|c| c.len()).max().unwrap_or(0).max(1) {
+        let label = if i == 0 { label } else { "" };
+        let mut line = format!("{:<lw$}", label, lw = LABEL_WIDTH);
+        for lines in &cells {
+            line.push_str(&format!(" {:<cw$} ", lines.get(i).map(String::as_str).unwrap_or_default()));
+        }
+        println!("{}", line.trim_end());
+    }
+}
+
+fn payback(r: &EvaluationResult) -> String {
+    match (r.payback, r.payback_days) {
+        (Some(value), Some(days)) => format!("{} {} (≈ {} days)", precision::fixed(value, 2), r.inputs.period, precision::fixed(days, 1)),
+        _ => "not estimated".to_string(),
+    }
+}
+
+pub fn run(args: &CompareArgs) {
+    let load = |path: &str| Scenario::load(path).and_then(|s| Ok((s.label().to_string(), scenario::checked(s.raw_inputs(), path)?))).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(2);
+    });
+    let scenarios: Vec<(String, Inputs)> = [&args.a, &args.b].into_iter().chain(&args.more).map(|p| load(p)).collect();
+    let evals = print_comparison(&scenarios);
+
+    let named: Vec<(&str, &Evaluation)> = scenarios.iter().map(|(label, _)| label.as_str()).zip(&evals).collect();
+    scenario_chart::print_chart(&named);
+    let mut data = ChartData::new();
+    scenario_chart::chart_data(&named, &mut data);
+    if let Some(path) = &args.chart_svg {
+        match std::fs::write(path, scenario_chart::svg(&named)) {
+            Ok(()) => println!("\nScenario chart written to {}.", path),
+            Err(e) => {
+                eprintln!("Error: could not write {}: {}", path, e);
+                std::process::exit(1);
+            }
+        }
+    }
+    chart_data::save(&data, args.chart_data.as_deref());
+}
+
+/// Prints the labeled scenarios in aligned columns, then what changed from the first to the second.
+pub fn print_comparison(scenarios: &[(String, Inputs)]) -> Vec<Evaluation> {
+    let evals: Vec<Evaluation> = scenarios.iter().map(|(_, inputs)| evaluation::evaluate(inputs)).collect();
+    let results: Vec<EvaluationResult> = evals.iter().map(EvaluationResult::from).collect();
+    let cells = |f: &dyn Fn(&EvaluationResult) -> String| results.iter().map(f).collect::<Vec<_>>();
+
+    println!("\n=== Scenario Comparison ===\n");
+    row("", &scenarios.iter().map(|(label, _)| label.as_str()).collect::<Vec<_>>());
+    println!("{}", "-".repeat(LABEL_WIDTH + results.len() * (column_width(results.len()) + 2) - 1));
+    row("CAC", &cells(&|r| money(r.inputs.cac)));
+    row("CFA", &cells(&|r| money(r.inputs.cfa)));
+    row("LTGP", &cells(&|r| money(r.inputs.ltgp)));
+    row("Net upfront outlay", &cells(&|r| money(r.net_outlay)));
+    row("LTGP:CAC", &cells(&|r| ratio_text(r.ratio_value())));
+    row("CAC classification", &cells(&|r| r.cac_class.cac_label().to_string()));
+    row("CFA classification", &cells(&|r| r.cfa_class.cfa_label().to_string()));
+    row("Payback", &cells(&payback));
+    println!();
+    row("Quadrant", &cells(&|r| r.quadrant_description.clone()));
+    if results.iter().any(|r| r.degenerate.is_some()) {
+        row("Special case", &cells(&|r| r.degenerate.as_ref().map(|d| d.kind.headline().to_string()).unwrap_or_default()));
+    }
+    println!();
+    row("Verdict", &cells(&|r| format!("{}{}", icon(r.severity.level), r.verdict)));
+    if results.iter().any(|r| !r.warnings.is_empty()) {
+        println!();
+        row("Warnings", &cells(&|r| r.warnings.join(" ")));
+    }
+
+    if let [a, b, ..] = evals.as_slice() {
+        attribution::print_attribution(&a.inputs, &b.inputs);
+    }
+    evals
+}
@@ -0,0 +1,99 @@
+// Config file: settings that outlive a single run, in TOML. Read from `--config PATH`, else from
+// `ltgp.toml` in the working directory when there is one; without either, every setting keeps its
+// built-in default.
+//
+//   theme = "brand"
+//   precision = 2                       # places for every figure; --precision wins
+//   rounding = "bankers"                # or "half-up" (the default); --rounding wins
+//   locale = "de-DE"                    # typed amounts read as 1.234,56; --locale wins
+//   timezone = "Europe/Berlin"          # results are timestamped in it; --timezone wins
+//   audit_log = "audit.jsonl"           # every evaluation, appended; --audit-log wins
+//
+//   [themes.brand]
+//   base = "dark"
+//   green = "#2a9d8f"
+//   red = "#e76f51"
+//
+//   [airtable]
+//   base = "appXXXXXXXXXXXXXX"
+//   table = "Experiments"
+//
+//   [guard_rails]                       # allowed range per input, checked on every run
+//   cac = { min = 10, max = 50000 }
+//   ltgp = { max = 1000000 }
+//
+//   [slack]
+//   webhook = "https://hooks.slack.com/services/T000/B000/XXXX"
+//
+//   [schedule]                          # what `serve --schedule` runs
+//   scenario = "base.toml"
+//   imports = [["stripe"]]
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use crate::airtable::AirtableConfig;
+use crate::guard_rails::GuardRails;
+use crate::notify::SlackConfig;
+use crate::precision::Rounding;
+use crate::schedule::Job;
+use crate::theme::Palette;
+
+/// Read when `--config` isn't given, if it exists.
+pub const DEFAULT_PATH: &str = "ltgp.toml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Theme to use when `--theme` isn't given: a built-in name or one of `themes`.
+    #[serde(default)]
+    pub theme: Option<String>,
+    /// Decimal places when `--precision` isn't given.
+    #[serde(default)]
+    pub precision: Option<usize>,
+    /// Tie rounding when `--rounding` isn't given.
+    #[serde(default)]
+    pub rounding: Option<Rounding>,
+    /// Language tag typed amounts are read in when `--locale` isn't given.
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// Timezone results are timestamped in when `--timezone` isn't given.
+    #[serde(default)]
+    pub timezone: Option<String>,
+    /// File every evaluation is appended to when `--audit-log` isn't given.
+    #[serde(default)]
+    pub audit_log: Option<String>,
+    /// User-defined palettes, by name.
+    #[serde(default)]
+    pub themes: BTreeMap<String, Palette>,
+    /// Airtable table every evaluation is appended to.
+    #[serde(default)]
+    pub airtable: Option<AirtableConfig>,
+    /// Allowed range per input, on top of the built-in checks.
+    #[serde(default)]
+    pub guard_rails: Option<GuardRails>,
+    /// Slack webhook for `--notify slack`.
+    #[serde(default)]
+    pub slack: Option<SlackConfig>,
+    /// The evaluation `serve --schedule` repeats.
+    #[serde(default)]
+    pub schedule: Option<Job>,
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Config, String> {
+        toml::from_str(text).map_err(|e| e.to_string())
+    }
+
+    /// Loads `path`, or `ltgp.toml` if it exists; an explicitly named file must exist.
+    pub fn load(path: Option<&str>) -> Result<Config, String> {
+        let path = match path {
+            Some(p) => p,
+            None if std::path::Path::new(DEFAULT_PATH).is_file() => DEFAULT_PATH,
+            None => return Ok(Config::default()),
+        };
+        let text = std::fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path, e))?;
+        Config::parse(&text).map_err(|e| format!("{}: {}", path, e))
+    }
+}
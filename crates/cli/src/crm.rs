@@ -0,0 +1,110 @@
+// CRM imports: closed-won deals as acquisition records. Each CRM maps its deals (HubSpot) or
+// opportunities (Salesforce) into `Acquisition`, and the report is shared: acquisitions over the
+// period and per month, the sales cycle from creation to close, first-invoice cash (CFA), and
+// the same by lead source. With `--spend`, CAC is that spend over the acquisitions; the sales
+// cycle feeds the timeline (`--sales-cycle-days`) and says how far back the spend behind this
+// period's deals went out.
+
+use std::collections::BTreeMap;
+
+use clap::Args;
+
+use crate::evaluation::money;
+use crate::history::date_from_days;
+use crate::import::{Imported, PeriodArgs};
+use crate::locale;
+use crate::period::DAYS_PER_MONTH;
+use crate::precision;
+
+/// One customer won, as any CRM records it.
+#[derive(Debug, Clone)]
+pub struct Acquisition {
+    /// Lead source or channel; None when the CRM has none recorded.
+    pub source: Option<String>,
+    /// Cash on the first invoice; None when the deal has no amount.
+    pub first_invoice: Option<f64>,
+    /// Days since the epoch the deal was created and closed.
+    pub created: i64,
+    pub closed: i64,
+}
+
+impl Acquisition {
+    fn cycle_days(&self) -> f64 {
+        (self.closed - self.created).max(0) as f64
+    }
+}
+
+/// Options shared by every CRM source.
+#[derive(Args, Debug, Clone)]
+pub struct CrmArgs {
+    /// Deals closed in this period count as acquisitions
+    #[command(flatten)]
+    pub period: PeriodArgs,
+
+    /// Sales and marketing spend over the same period, for CAC per deal won
+    #[arg(long, value_parser = locale::number)]
+    pub spend: Option<f64>,
+}
+
+fn median(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    Some(if values.len().is_multiple_of(2) { (values[mid - 1] + values[mid]) / 2.0 } else { values[mid] })
+}
+
+fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, n) = values.fold((0.0, 0), |(s, n), v| (s + v, n + 1));
+    (n > 0).then(|| sum / n as f64)
+}
+
+/// Prints acquisitions, sales cycle, and first-invoice cash overall and by source, and returns
+/// CFA, CAC (with `--spend`), and the sales cycle and monthly volume as calculator flags.
+pub fn report(crm: &str, deals: &[Acquisition], args: &CrmArgs) -> Result<Imported, String> {
+    let (since, until) = args.period.days()?;
+    if deals.is_empty() {
+        return Err(format!("no closed-won deals in {} between {} and {}", crm, date_from_days(since), date_from_days(until)));
+    }
+    let months = (until - since + 1) as f64 / DAYS_PER_MONTH;
+    let per_month = deals.len() as f64 / months;
+    let cycle = median(deals.iter().map(Acquisition::cycle_days).collect()).unwrap_or(0.0);
+    let cfa = mean(deals.iter().filter_map(|d| d.first_invoice));
+
+    println!("\n=== {} import ===\n", crm);
+    println!("{} closed-won deals from {} to {} ({} per month).", deals.len(), date_from_days(since), date_from_days(until), precision::fixed(per_month, 1));
+    println!("  {:<24}{} days median from creation to close (mean {})", "Sales cycle", precision::fixed(cycle, 0), precision::fixed(mean(deals.iter().map(Acquisition::cycle_days)).unwrap_or(0.0), 0));
+    println!("  {:<24}{}", "First-invoice cash", cfa.map(money).unwrap_or_else(|| "no deal amounts".to_string()));
+
+    let mut by_source: BTreeMap<&str, Vec<&Acquisition>> = BTreeMap::new();
+    for d in deals {
+        by_source.entry(d.source.as_deref().unwrap_or("(no source)")).or_default().push(d);
+    }
+    println!("\nBy source:");
+    println!("  {:<24}  {:>6}  {:>13}  {:>12}", "Source", "Deals", "First invoice", "Sales cycle");
+    for (source, group) in &by_source {
+        let invoice = mean(group.iter().filter_map(|d| d.first_invoice)).map(money).unwrap_or_else(|| "-".to_string());
+        let cycle = median(group.iter().map(|d| d.cycle_days()).collect()).unwrap_or(0.0);
+        let name: String = source.chars().take(24).collect();
+        println!("  {:<24}  {:>6}  {:>13}  {:>9} d", name, group.len(), invoice, precision::fixed(cycle, 0));
+    }
+
+    let cac = args.spend.map(|spend| spend / deals.len() as f64);
+    if let (Some(spend), Some(cac)) = (args.spend, cac) {
+        println!("\nCAC = {} spend ÷ {} deals = {}.", money(spend), deals.len(), money(cac));
+        if cycle >= 1.0 {
+            let lag = cycle.round() as i64;
+            println!("These deals started about {} days before they closed, so the spend that won them went out around {} to {}; use spend from that stretch if it differs.", precision::fixed(cycle, 0), date_from_days(since - lag), date_from_days(until - lag));
+        }
+    } else {
+        println!("\nPass --spend with sales and marketing spend over the period for CAC per deal.");
+    }
+
+    Ok(Imported {
+        cac,
+        cfa,
+        options: vec![("sales-cycle-days", cycle), ("monthly-volume", per_month)],
+        ..Imported::default()
+    })
+}
@@ -0,0 +1,65 @@
+// Capital efficiency dashboard: unit economics next to company-level efficiency (burn multiple,
+// magic number, runway) on one screen, each with a color-coded status, from the metric pipeline
+// in `ltgp_core::metric`.
+
+use clap::Args;
+
+use crate::evaluation;
+use crate::layout::icon;
+use crate::locale;
+use crate::metric::{Company, Context, Pipeline};
+use crate::precision;
+use crate::scenario::ScenarioArgs;
+use crate::severity::{self, Severity};
+
+#[derive(Args, Debug)]
+pub struct DashboardArgs {
+    #[command(flatten)]
+    pub base: ScenarioArgs,
+
+    /// Net cash burned per month in dollars (0 or negative if cash-flow positive)
+    #[arg(long, value_parser = locale::number, allow_hyphen_values = true)]
+    pub net_burn: f64,
+
+    /// Current annual recurring revenue in dollars
+    #[arg(long, value_parser = locale::number)]
+    pub arr: Option<f64>,
+
+    /// ARR added in the last month, net of churn and contraction
+    #[arg(long, value_parser = locale::number, allow_hyphen_values = true)]
+    pub net_new_arr: f64,
+
+    /// Sales and marketing spend in the last month
+    #[arg(long, value_parser = locale::number)]
+    pub sm_spend: f64,
+
+    /// Cash on hand today
+    #[arg(long, value_parser = locale::number)]
+    pub cash: f64,
+}
+
+/// GOOD, WATCH, or RISK, padded before coloring since escape codes would throw off column widths.
+fn status(severity: Severity) -> String {
+    let name = match severity {
+        Severity::Healthy => "GOOD",
+        Severity::Watch => "WATCH",
+        Severity::AtRisk | Severity::Critical => "RISK",
+    };
+    severity::paint(severity, &format!("{:<5}", name))
+}
+
+pub fn run(args: &DashboardArgs) {
+    let inputs = args.base.inputs_or_exit();
+    let e = evaluation::evaluate(&inputs);
+
+    println!("\n=== Capital Efficiency Dashboard ===\n");
+    if let Some(arr) = args.arr {
+        println!(" ARR ${}, net new ARR ${}/month, S&M ${}/month, net burn ${}/month, cash ${}\n", precision::fixed(arr, 0), precision::fixed(args.net_new_arr, 0), precision::fixed(args.sm_spend, 0), precision::fixed(args.net_burn, 0), precision::fixed(args.cash, 0));
+    }
+
+    let company = Company { net_burn: args.net_burn, net_new_arr: args.net_new_arr, sm_spend: args.sm_spend, cash: args.cash };
+    for m in Pipeline::builtin().run(&Context { evaluation: &e, company: Some(company) }) {
+        let r = &m.reading;
+        println!(" {}{:<18} {:>14}  {}  {}", icon(r.severity), m.name, r.shown, status(r.severity), r.note);
+    }
+}
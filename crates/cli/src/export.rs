@@ -0,0 +1,24 @@
+// Exports: a run's report published to the tools where the team keeps its documents, one
+// subcommand per destination.
+
+use clap::{Args, Subcommand};
+
+use crate::notion;
+
+#[derive(Args, Debug)]
+pub struct ExportArgs {
+    #[command(subcommand)]
+    pub target: Target,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Target {
+    /// Write the report to a Notion page, creating it under --parent or replacing --page
+    Notion(notion::NotionArgs),
+}
+
+pub fn run(args: &ExportArgs) {
+    match &args.target {
+        Target::Notion(a) => notion::run(a),
+    }
+}
@@ -0,0 +1,121 @@
+// Output formats: each renders the typed evaluation result, and the registry maps `--format`
+// names to them. A new format (e.g. an internal wire format) is a `Formatter` impl plus one `register`
+// call in `Registry::builtin`, optionally behind a cargo feature like `json-output`.
+
+use crate::evaluation;
+use crate::html;
+use crate::layout;
+use crate::plot;
+use crate::precision;
+use crate::report::{self, EvaluationResult};
+use crate::severity;
+use crate::sweep::verdict_label;
+
+pub trait Formatter {
+    /// Name used to pick the format with `--format`.
+    fn name(&self) -> &'static str;
+
+    /// Renders the result, whose verdict is the rules' or a script's replacement.
+    fn render(&self, r: &EvaluationResult) -> String;
+}
+
+/// The human-readable report, fitted to the terminal width; only this format is followed by the
+/// other report sections.
+pub struct Text;
+
+impl Formatter for Text {
+    fn name(&self) -> &'static str {
+        "text"
+    }
+
+    fn render(&self, r: &EvaluationResult) -> String {
+        let report = if layout::compact() { report::render_compact_report(r) } else { report::render_report(r) };
+        layout::fit(&report)
+    }
+}
+
+/// A single line for dashboards and scripts, ending in a sparkline of the cumulative cash position.
+pub struct Summary;
+
+impl Formatter for Summary {
+    fn name(&self) -> &'static str {
+        "summary"
+    }
+
+    fn render(&self, r: &EvaluationResult) -> String {
+        let payback = r.payback_days.map(|d| format!("payback {}d", precision::fixed(d, 0))).unwrap_or_else(|| "payback n/a".to_string());
+        let cash = plot::cash_position(&r.inputs, 36);
+        let end = cash.last().copied().unwrap_or_default();
+        let severity = r.severity.level;
+        format!("LTGP:CAC {} | {} | {} ({}) | {} | cash {} {}", evaluation::ratio_text(r.ratio_value()), r.quadrant, verdict_label(&r.verdict), severity::paint(severity, severity.name()), payback, plot::sparkline(&cash), evaluation::money(end))
+    }
+}
+
+/// `key=value` pairs on one line with no color or icons, for status bars, chat bots, and cron
+/// digests; keys and value shapes stay fixed so a `grep` or `split` keeps working across releases.
+// `--precision` doesn't change them; `--rounding` still decides how ties round.
+pub struct Oneline;
+
+/// Lower-case words joined by `-`, e.g. "Capital-Intensive Trap" → "capital-intensive-trap".
+fn slug(s: &str) -> String {
+    s.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()).map(|w| w.to_lowercase()).collect::<Vec<_>>().join("-")
+}
+
+impl Formatter for Oneline {
+    fn name(&self) -> &'static str {
+        "oneline"
+    }
+
+    fn render(&self, r: &EvaluationResult) -> String {
+        let ratio = r.ratio.map(|v| format!("{:.2}", precision::round(v, 2))).unwrap_or_else(|| "inf".to_string());
+        let payback = r.payback_days.map(|d| format!("{:.0}d", precision::round(d, 0))).unwrap_or_else(|| "none".to_string());
+        format!("ratio={} quadrant={} payback={} verdict={} severity={}", ratio, slug(&r.quadrant), payback, slug(verdict_label(&r.verdict)).to_uppercase(), r.severity.level.name())
+    }
+}
+
+/// One JSON object with the inputs and computed metrics, for other tools to consume.
+#[cfg(feature = "json-output")]
+pub struct Json;
+
+#[cfg(feature = "json-output")]
+impl Formatter for Json {
+    fn name(&self) -> &'static str {
+        "json"
+    }
+
+    fn render(&self, r: &EvaluationResult) -> String {
+        serde_json::to_string_pretty(r).expect("evaluation serializes to JSON")
+    }
+}
+
+pub struct Registry {
+    formatters: Vec<Box<dyn Formatter>>,
+}
+
+impl Registry {
+    /// Every format compiled into this build.
+    pub fn builtin() -> Registry {
+        let mut registry = Registry { formatters: Vec::new() };
+        registry.register(Box::new(Text));
+        registry.register(Box::new(Summary));
+        registry.register(Box::new(Oneline));
+        registry.register(Box::new(html::Html));
+        #[cfg(feature = "json-output")]
+        registry.register(Box::new(Json));
+        registry
+    }
+
+    /// Adds a format; a later registration with the same name replaces the earlier one.
+    pub fn register(&mut self, formatter: Box<dyn Formatter>) {
+        self.formatters.retain(|f| f.name() != formatter.name());
+        self.formatters.push(formatter);
+    }
+
+    /// Looks up a format by name, listing the available names when it isn't found.
+    pub fn get(&self, name: &str) -> Result<&dyn Formatter, String> {
+        self.formatters.iter().find(|f| f.name().eq_ignore_ascii_case(name)).map(|f| f.as_ref()).ok_or_else(|| {
+            let names = self.formatters.iter().map(|f| f.name()).collect::<Vec<_>>().join(", ");
+            format!("unknown format `{}` (available: {})", name, names)
+        })
+    }
+}
@@ -0,0 +1,156 @@
+// Google Ads import: campaign cost and conversions, either from the Google Ads API (a GAQL
+// searchStream over the period) or from a campaign report downloaded as CSV, turned into
+// blended and per-campaign CAC by `ads::report`.
+
+use std::collections::BTreeMap;
+
+use clap::Args;
+use serde::Deserialize;
+
+use crate::ads::{self, Campaign, Platform, SpendArgs};
+use crate::import::{self, Imported, Importer, OutputArgs};
+
+const MICROS: f64 = 1_000_000.0;
+const API_VERSION: &str = "v18";
+const API_URL: &str = "https://googleads.googleapis.com";
+
+#[derive(Args, Debug)]
+pub struct GoogleAdsArgs {
+    /// Campaign report downloaded from Google Ads as CSV, instead of calling the API
+    #[arg(long)]
+    pub csv: Option<String>,
+
+    /// Google Ads customer ID to query (`123-456-7890`)
+    #[arg(long)]
+    pub customer_id: Option<String>,
+
+    /// Manager account ID, when access to the customer goes through one
+    #[arg(long)]
+    pub login_customer_id: Option<String>,
+
+    /// API developer token (default: the GOOGLE_ADS_DEVELOPER_TOKEN environment variable)
+    #[arg(long)]
+    pub developer_token: Option<String>,
+
+    /// OAuth access token for the adwords scope (default: the GOOGLE_ADS_ACCESS_TOKEN environment variable)
+    #[arg(long)]
+    pub access_token: Option<String>,
+
+    /// Channel name for `--channel`
+    #[arg(long, default_value = "google-ads")]
+    pub channel: String,
+
+    /// Google Ads API version
+    #[arg(long, default_value = API_VERSION)]
+    pub api_version: String,
+
+    /// API base URL, e.g. a mock server for trying the import out
+    #[arg(long, default_value = API_URL)]
+    pub api_url: String,
+
+    #[command(flatten)]
+    pub spend: SpendArgs,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Deserialize, Debug)]
+struct Batch {
+    #[serde(default)]
+    results: Vec<Row>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Row {
+    campaign: CampaignName,
+    metrics: Metrics,
+}
+
+#[derive(Deserialize, Debug)]
+struct CampaignName {
+    name: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Metrics {
+    /// int64 fields come back as JSON strings.
+    #[serde(default)]
+    cost_micros: Option<String>,
+    #[serde(default)]
+    conversions: Option<f64>,
+}
+
+fn fetch(args: &GoogleAdsArgs, customer: &str) -> Result<Vec<Campaign>, String> {
+    let developer_token = import::credential(&args.developer_token, "developer-token", "GOOGLE_ADS_DEVELOPER_TOKEN")?;
+    let access_token = import::credential(&args.access_token, "access-token", "GOOGLE_ADS_ACCESS_TOKEN")?;
+    let (since, until) = args.spend.period.dates()?;
+    let customer = customer.replace('-', "");
+    let url = format!("{}/{}/customers/{}/googleAds:searchStream", args.api_url.trim_end_matches('/'), args.api_version, customer);
+    let query = format!(
+        "SELECT campaign.name, metrics.cost_micros, metrics.conversions FROM campaign WHERE segments.date BETWEEN '{}' AND '{}' AND metrics.cost_micros > 0",
+        since, until
+    );
+    let bearer = format!("Bearer {}", access_token);
+    let login = args.login_customer_id.as_deref().map(|id| id.replace('-', ""));
+    let mut headers = vec![("Authorization", bearer.as_str()), ("developer-token", developer_token.as_str())];
+    if let Some(id) = &login {
+        headers.push(("login-customer-id", id));
+    }
+    let body = serde_json::json!({ "query": query }).to_string();
+    let batches: Vec<Batch> = import::post(&url, &headers, &body)?;
+
+    let mut campaigns = BTreeMap::new();
+    for row in batches.into_iter().flat_map(|b| b.results) {
+        let cost = row.metrics.cost_micros.as_deref().and_then(|m| m.parse::<f64>().ok()).unwrap_or(0.0) / MICROS;
+        ads::add(&mut campaigns, Campaign { name: row.campaign.name, cost, conversions: Some(row.metrics.conversions.unwrap_or(0.0)) });
+    }
+    Ok(campaigns.into_values().collect())
+}
+
+impl GoogleAdsArgs {
+    /// Campaigns from the CSV report if one was given, else from the API.
+    fn platform(&self) -> Result<Platform, String> {
+        let (campaigns, source) = match (&self.csv, &self.customer_id) {
+            (Some(path), _) => (ads::read_report(path, &["campaign"], &["cost"], &["conversions", "conv."])?, path.clone()),
+            (None, Some(customer)) => {
+                let (since, until) = self.spend.period.dates()?;
+                (fetch(self, customer)?, format!("customer {}, {} to {}", customer, since, until))
+            }
+            (None, None) => return Err("provide --csv with a Google Ads campaign report or --customer-id to use the API".to_string()),
+        };
+        Ok(Platform { name: "Google Ads", channel: self.channel.clone(), source, campaigns })
+    }
+
+    /// The Google Ads side of `import ads`, with defaults for everything but the report or account.
+    pub fn platform_of(csv: Option<String>, customer_id: Option<String>, spend: &SpendArgs) -> Result<Platform, String> {
+        let args = GoogleAdsArgs {
+            csv,
+            customer_id,
+            login_customer_id: None,
+            developer_token: None,
+            access_token: None,
+            channel: "google-ads".to_string(),
+            api_version: API_VERSION.to_string(),
+            api_url: API_URL.to_string(),
+            spend: spend.clone(),
+            output: OutputArgs { out: None, name: None },
+        };
+        args.platform()
+    }
+}
+
+impl Importer for GoogleAdsArgs {
+    fn name(&self) -> &'static str {
+        "Google Ads"
+    }
+
+    fn output(&self) -> &OutputArgs {
+        &self.output
+    }
+
+    fn import(&self) -> Result<Imported, String> {
+        ads::report(self.name(), &[self.platform()?], &self.spend)
+    }
+}
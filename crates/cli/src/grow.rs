@@ -0,0 +1,108 @@
+// Reinvestment growth simulator: each month, collected CFA and gross profit are put back into
+// acquisition, starting from a pool of starting cash.
+
+use clap::Args;
+
+use crate::channel::{self, Channel};
+use crate::evaluation::{money, Inputs};
+use crate::layout::wrapln;
+use crate::locale;
+use crate::plan::UnitCashFlow;
+use crate::precision;
+use crate::scenario::{non_negative_or_exit, ScenarioArgs};
+
+pub use ltgp_core::growth::{self_funding_month, simulate, GrowthPolicy, MonthRow};
+
+#[derive(Args, Debug)]
+pub struct GrowArgs {
+    #[command(flatten)]
+    pub base: ScenarioArgs,
+
+    /// Months to simulate
+    #[arg(long, default_value_t = 24)]
+    pub months: usize,
+
+    /// Cash available for acquisition at the start
+    #[arg(long, value_parser = locale::number, default_value_t = 0.0)]
+    pub starting_cash: f64,
+
+    /// Percent of available cash put into acquisition each month
+    #[arg(long, value_parser = locale::number, default_value_t = 100.0)]
+    pub reinvest_pct: f64,
+
+    /// Upper limit on acquisition spend per month
+    #[arg(long, value_parser = locale::number)]
+    pub max_monthly_spend: Option<f64>,
+
+    /// Acquisition channel as name:cac[:capacity per month]; replaces the flat CAC when given
+    #[arg(long = "channel")]
+    pub channels: Vec<Channel>,
+
+    /// Also write the month-by-month projection as CSV to this path ("-" for stdout)
+    #[arg(long)]
+    pub csv: Option<String>,
+}
+
+pub fn run(args: &GrowArgs) {
+    let inputs = args.base.inputs_or_exit();
+    let policy = GrowthPolicy {
+        months: args.months.max(1),
+        starting_cash: non_negative_or_exit("starting-cash", args.starting_cash),
+        reinvest: (args.reinvest_pct / 100.0).clamp(0.0, 1.0),
+        max_monthly_spend: args.max_monthly_spend,
+        channels: args.channels.clone(),
+    };
+    if UnitCashFlow::from_inputs(&inputs).monthly_gp <= 0.0 {
+        eprintln!("Error: the growth simulator needs --early-gp-rate (or early_gp_rate in the scenario)");
+        std::process::exit(2);
+    }
+    let rows = simulate(&inputs, &policy);
+    if args.csv.as_deref() == Some("-") {
+        return print!("{}", projection_csv(&rows));
+    }
+    print_growth_summary(&inputs, &policy, &rows);
+    if let Some(path) = &args.csv {
+        match std::fs::write(path, projection_csv(&rows)) {
+            Ok(()) => println!("\nProjection written to {}.", path),
+            Err(e) => {
+                eprintln!("Error: could not write {}: {}", path, e);
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// Month-by-month projection as CSV, ready to paste into a financial model.
+pub fn projection_csv(rows: &[MonthRow]) -> String {
+    let mut out = String::from("month,customers_acquired,active_customers,cash_in,cash_out,ending_cash\n");
+    for r in rows {
+        out.push_str(&format!("{},{:.2},{:.2},{:.2},{:.2},{:.2}\n", r.month, r.acquired, r.active, r.cash_in, r.spend, r.ending_cash));
+    }
+    out
+}
+
+pub fn print_growth_summary(inputs: &Inputs, policy: &GrowthPolicy, rows: &[MonthRow]) {
+    let Some(last) = rows.last() else { return };
+    let total_acquired: f64 = rows.iter().map(|r| r.acquired).sum();
+    println!("\n=== Reinvestment Growth ({} months, {} starting cash, reinvesting {}%) ===\n", policy.months, money(policy.starting_cash), precision::fixed(policy.reinvest * 100.0, 0));
+    println!("CAC {} · CFA {} · LTGP {}", money(inputs.cac), money(inputs.cfa), money(inputs.ltgp));
+    if !policy.channels.is_empty() && let Some(cap) = channel::total_capacity(&policy.channels) {
+        println!("Channel ceiling: {} customers/month.", precision::fixed(cap, 0));
+    }
+
+    println!("\n{:>5}  {:>10}  {:>10}  {:>14}  {:>14}  {:>14}", "Month", "Acquired", "Active", "Cash in", "Cash out", "Ending cash");
+    for r in rows {
+        println!("{:>5}  {:>10}  {:>10}  {:>14}  {:>14}  {:>14}", r.month, precision::fixed(r.acquired, 1), precision::fixed(r.active, 1), money(r.cash_in), money(r.spend), money(r.ending_cash));
+    }
+
+    println!("\nResult:");
+    wrapln!(" - Customers acquired over {} months: {} ({} still active).", policy.months, precision::fixed(total_acquired, 1), precision::fixed(last.active, 1));
+    wrapln!(" - Ending cash position: {}.", money(last.ending_cash));
+    match self_funding_month(rows) {
+        Some(m) => wrapln!(" - Self-funding from month {}: that month's collections covered its acquisition spend.", m),
+        None => wrapln!(" - Not self-funding within {} months; acquisition still depends on starting cash.", policy.months),
+    }
+    if policy.starting_cash <= 0.0 && inputs.cfa < inputs.cac {
+        println!("\nNote: With no starting cash and CFA below CAC, there is nothing to reinvest. Pass --starting-cash.");
+    }
+}
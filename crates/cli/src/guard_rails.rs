@@ -0,0 +1,95 @@
+// Guard rails: organization-wide bounds on each input, set in the config file, so a number off by
+// 100× (cents typed as dollars, a monthly figure entered as lifetime) stops the run instead of
+// reaching a report. They narrow the built-in range checks rather than replace them, apply to
+// flags, scenario files, batch rows, and the interactive form alike (the form asks again), and
+// `--clamp` never moves a value inside them:
+//
+//   [guard_rails]
+//   cac = { min = 10, max = 50000 }
+//   ltgp = { max = 1000000 }
+//
+//   Error: CAC $5.00 is below the guard rail of $10.00 (config [guard_rails])
+
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use crate::evaluation::{money, Field, Inputs};
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Bounds {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+/// Bounds per input; an input without an entry is only held to the built-in checks.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GuardRails {
+    pub cac: Option<Bounds>,
+    pub cfa: Option<Bounds>,
+    pub ltgp: Option<Bounds>,
+    pub early_gp_rate: Option<Bounds>,
+    pub low_cac_fraction: Option<Bounds>,
+}
+
+static RAILS: OnceLock<GuardRails> = OnceLock::new();
+
+/// Sets the guard rails for the rest of the run, after checking each min is at most its max.
+pub fn install(rails: GuardRails) -> Result<(), String> {
+    for field in Field::ALL {
+        if let Some(Bounds { min: Some(min), max: Some(max) }) = rails.bounds(field)
+            && min > max
+        {
+            return Err(format!("[guard_rails] {}: min {} is above max {}", field.name(), min, max));
+        }
+    }
+    let _ = RAILS.set(rails);
+    Ok(())
+}
+
+fn active() -> &'static GuardRails {
+    RAILS.get_or_init(GuardRails::default)
+}
+
+/// `value` in the field's own terms: dollars for amounts, a plain number for the fraction.
+fn show(field: Field, value: f64) -> String {
+    match field {
+        Field::LowCacFraction => value.to_string(),
+        _ => money(value),
+    }
+}
+
+impl GuardRails {
+    fn bounds(&self, field: Field) -> Option<Bounds> {
+        match field {
+            Field::Cac => self.cac,
+            Field::Cfa => self.cfa,
+            Field::Ltgp => self.ltgp,
+            Field::EarlyGpRate => self.early_gp_rate,
+            Field::LowCacFraction => self.low_cac_fraction,
+        }
+    }
+
+    /// Why `value` is outside the field's guard rail, if it is.
+    pub fn problem(&self, field: Field, value: f64) -> Option<String> {
+        let bounds = self.bounds(field)?;
+        match (bounds.min, bounds.max) {
+            (Some(min), _) if value < min => Some(format!("{} {} is below the guard rail of {}", field.name(), show(field, value), show(field, min))),
+            (_, Some(max)) if value > max => Some(format!("{} {} is above the guard rail of {}", field.name(), show(field, value), show(field, max))),
+            _ => None,
+        }
+    }
+}
+
+/// Why `value` is outside the active guard rail for `field`, if it is.
+pub fn problem(field: Field, value: f64) -> Option<String> {
+    active().problem(field, value)
+}
+
+/// Every input outside its guard rail, joined into one message.
+pub fn check(inputs: &Inputs) -> Result<(), String> {
+    let problems: Vec<String> = Field::ALL.iter().filter_map(|f| problem(*f, f.get(inputs))).collect();
+    if problems.is_empty() { Ok(()) } else { Err(format!("{} (config [guard_rails])", problems.join("; "))) }
+}
@@ -0,0 +1,202 @@
+// Company-level health shown next to the unit-economics verdict.
+
+use std::str::FromStr;
+
+use crate::evaluation::{money, ratio_text, Evaluation};
+use crate::layout::{icon, wrapln};
+use crate::period::DAYS_PER_MONTH;
+use crate::precision;
+use crate::severity::Severity;
+
+/// Revenue growth plus operating margin, both in percent.
+#[derive(Debug, Clone, Copy)]
+pub struct RuleOf40 {
+    pub growth_pct: f64,
+    pub margin_pct: f64,
+}
+
+impl RuleOf40 {
+    pub fn score(&self) -> f64 {
+        self.growth_pct + self.margin_pct
+    }
+
+    pub fn passes(&self) -> bool {
+        self.score() >= 40.0
+    }
+
+    pub fn interpretation(&self) -> &'static str {
+        if self.passes() {
+            "Healthy: growth and profitability together clear the 40% bar."
+        } else if self.score() >= 20.0 {
+            "Below bar: the company is trading off growth and margin without clearing 40%."
+        } else {
+            "Weak: neither growth nor margin is carrying the company."
+        }
+    }
+}
+
+/// One period's MRR movements, in dollars.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MrrMovements {
+    pub new: f64,
+    pub expansion: f64,
+    pub contraction: f64,
+    pub churned: f64,
+}
+
+impl MrrMovements {
+    /// SaaS quick ratio: (new + expansion) ÷ (contraction + churned); infinite with no losses.
+    pub fn quick_ratio(&self) -> f64 {
+        let lost = self.contraction + self.churned;
+        if lost > 0.0 { (self.new + self.expansion) / lost } else { f64::INFINITY }
+    }
+
+    pub fn passes(&self) -> bool {
+        self.quick_ratio() >= 4.0
+    }
+
+    pub fn interpretation(&self) -> &'static str {
+        let q = self.quick_ratio();
+        if q >= 4.0 {
+            "Efficient: you add at least $4 of MRR for every $1 you lose."
+        } else if q >= 1.0 {
+            "Leaky: MRR grows, but churn and downgrades eat much of what you add."
+        } else {
+            "Shrinking: you lose MRR faster than you add it."
+        }
+    }
+}
+
+/// Points each score component is worth, summing to 100.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreWeights {
+    pub ratio: f64,
+    pub payback: f64,
+    pub coverage: f64,
+    pub safety: f64,
+}
+
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        ScoreWeights { ratio: 40.0, payback: 25.0, coverage: 20.0, safety: 15.0 }
+    }
+}
+
+impl FromStr for ScoreWeights {
+    type Err = String;
+
+    /// Parses a preset (`balanced`, `bootstrapped`, `funded`) or `ratio=20,payback=50,...`, where
+    /// unnamed components keep their default weight and the result is rescaled to 100.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut w = match s.trim().to_lowercase().as_str() {
+            "balanced" => return Ok(ScoreWeights::default()),
+            "bootstrapped" => return Ok(ScoreWeights { ratio: 20.0, payback: 45.0, coverage: 25.0, safety: 10.0 }),
+            "funded" => return Ok(ScoreWeights { ratio: 55.0, payback: 10.0, coverage: 10.0, safety: 25.0 }),
+            _ => ScoreWeights::default(),
+        };
+        for part in s.split(',') {
+            let (key, value) = part.split_once('=').ok_or_else(|| format!("expected component=weight, got `{}`", part.trim()))?;
+            let value = value.trim().parse::<f64>().ok().filter(|v| v.is_finite() && *v >= 0.0).ok_or_else(|| format!("invalid weight in `{}`", part.trim()))?;
+            match key.trim() {
+                "ratio" => w.ratio = value,
+                "payback" => w.payback = value,
+                "coverage" => w.coverage = value,
+                "safety" => w.safety = value,
+                other => return Err(format!("unknown component `{}` (use ratio, payback, coverage, safety)", other)),
+            }
+        }
+        let sum = w.ratio + w.payback + w.coverage + w.safety;
+        if sum <= 0.0 {
+            return Err("weights must not all be zero".to_string());
+        }
+        let k = 100.0 / sum;
+        Ok(ScoreWeights { ratio: w.ratio * k, payback: w.payback * k, coverage: w.coverage * k, safety: w.safety * k })
+    }
+}
+
+/// Composite unit-economics score out of 100, split by component.
+///
+/// Rubric (points scale linearly between the bounds and are clamped; default weights shown):
+/// - Ratio, 40 pts: 0 at LTGP:CAC 1:1, full at 5:1.
+/// - Payback speed, 25 pts: full when net outlay is zero or recovered within 3 months, 0 at 24 months or when payback is unknown.
+/// - CFA coverage, 20 pts: share of CAC collected upfront.
+/// - Margin of safety, 15 pts: how far LTGP can fall before the ratio drops to 3:1; full at a 50% cushion.
+#[derive(Debug, Clone, Copy)]
+pub struct HealthScore {
+    pub weights: ScoreWeights,
+    pub ratio: f64,
+    pub payback: f64,
+    pub coverage: f64,
+    pub safety: f64,
+}
+
+impl HealthScore {
+    pub fn from_evaluation(e: &Evaluation, weights: ScoreWeights) -> HealthScore {
+        let i = &e.inputs;
+        let scale = |value: f64, zero: f64, full: f64| ((value - zero) / (full - zero)).clamp(0.0, 1.0);
+        let payback_months = if e.net_outlay == 0.0 { Some(0.0) } else { e.payback_days().map(|d| d / DAYS_PER_MONTH) };
+        HealthScore {
+            weights,
+            ratio: weights.ratio * scale(e.ratio, 1.0, 5.0),
+            payback: payback_months.map_or(0.0, |m| weights.payback * scale(m, 24.0, 3.0)),
+            coverage: weights.coverage * if i.cac > 0.0 { (i.cfa / i.cac).clamp(0.0, 1.0) } else { 1.0 },
+            safety: if i.ltgp > 0.0 { weights.safety * scale(1.0 - 3.0 * i.cac / i.ltgp, 0.0, 0.5) } else { 0.0 },
+        }
+    }
+
+    pub fn total(&self) -> f64 {
+        self.ratio + self.payback + self.coverage + self.safety
+    }
+
+    /// Letter grade for the total score, with the sentence that justifies its boundary.
+    pub fn grade(&self) -> (char, &'static str) {
+        match self.total() {
+            t if t >= 85.0 => ('A', "Strong returns, fast payback, and room for error; scale with confidence."),
+            t if t >= 70.0 => ('B', "Sound economics with one soft spot worth tightening before scaling hard."),
+            t if t >= 55.0 => ('C', "Workable, but thin on returns, payback, or cushion; grow carefully."),
+            t if t >= 40.0 => ('D', "Marginal: several components are weak and small misses could push you underwater."),
+            _ => ('F', "Customers do not pay back their acquisition cost reliably; fix the economics before spending more."),
+        }
+    }
+}
+
+/// Whether the unit-economics verdict counts as healthy: its severity level is healthy, so a
+/// 3:1 ratio still fails when the quadrant makes the verdict fragile.
+fn unit_healthy(e: &Evaluation) -> bool {
+    Severity::of_verdict(e.verdict) == Severity::Healthy
+}
+
+fn health_label(healthy: bool) -> String {
+    format!("{}{}", icon(Severity::of_pass(healthy)), if healthy { "healthy" } else { "unhealthy" })
+}
+
+pub fn print_health_dashboard(e: &Evaluation, weights: ScoreWeights, rule: Option<RuleOf40>, mrr: Option<MrrMovements>) {
+    println!("\nHealth dashboard:");
+    wrapln!(" - Unit level: LTGP:CAC {}, {} → {}", ratio_text(e.ratio), e.quadrant.name(), health_label(unit_healthy(e)));
+    let s = HealthScore::from_evaluation(e, weights);
+    let w = s.weights;
+    wrapln!(" - Health score: {}/100 (ratio {}/{}, payback {}/{}, CFA coverage {}/{}, margin of safety {}/{})", precision::fixed(s.total(), 0), precision::fixed(s.ratio, 0), precision::fixed(w.ratio, 0), precision::fixed(s.payback, 0), precision::fixed(w.payback, 0), precision::fixed(s.coverage, 0), precision::fixed(w.coverage, 0), precision::fixed(s.safety, 0), precision::fixed(w.safety, 0));
+    let (grade, why) = s.grade();
+    wrapln!(" - Grade: {} — {}", grade, why);
+    if let Some(r) = rule {
+        wrapln!(" - Company level: Rule of 40 = {}% ({}% growth, {}% operating margin) → {}", precision::fixed(r.score(), 1), precision::signed(r.growth_pct, 1), precision::signed(r.margin_pct, 1), health_label(r.passes()));
+        println!("   {}", r.interpretation());
+    }
+    if let Some(m) = mrr {
+        wrapln!(" - Revenue quality: quick ratio {} ({} new + expansion vs {} contraction + churn) → {}", precision::fixed(m.quick_ratio(), 2), money(m.new + m.expansion), money(m.contraction + m.churned), health_label(m.passes()));
+        println!("   {}", m.interpretation());
+    }
+
+    // Company signals pass only if every one that was given passes.
+    let company: Vec<bool> = rule.map(|r| r.passes()).into_iter().chain(mrr.map(|m| m.passes())).collect();
+    if company.is_empty() {
+        return;
+    }
+    let summary = match (unit_healthy(e), company.iter().all(|&ok| ok)) {
+        (true, true) => "Both units and the company are healthy; acquisition can scale.",
+        (true, false) => "Customers are worth acquiring, but the company overall is not; look at fixed costs, growth pace, or churn.",
+        (false, true) => "The company looks healthy, but each new customer erodes it; fix unit economics before scaling acquisition.",
+        (false, false) => "Neither units nor the company are healthy; fix unit economics first.",
+    };
+    wrapln!(" - Together: {}", summary);
+}
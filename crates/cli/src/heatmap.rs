@@ -0,0 +1,122 @@
+// Two-way sweep heatmap: every combination of two inputs, each cell colored by the verdict it
+// lands in, so the frontier where the verdict flips (e.g. from Good to Unsustainable) shows as an
+// edge between colors. Rendered with ANSI colors in the terminal or as a standalone SVG file.
+
+use colored::Colorize;
+
+use crate::chart_data::ChartData;
+use crate::evaluation::{self, Field, Inputs};
+use crate::precision;
+use crate::rules::{self, COLORS};
+use crate::sweep::verdict_label;
+use crate::theme;
+
+/// One verdict's area in the grid.
+struct Zone {
+    verdict: &'static str,
+    color: &'static str,
+    /// Letter printed in each cell, so the zones stay readable without color.
+    mark: char,
+}
+
+pub struct Grid {
+    x: (Field, Vec<f64>),
+    y: (Field, Vec<f64>),
+    /// Zone index per cell, `cells[row][col]`, with row 0 at the first y value.
+    cells: Vec<Vec<usize>>,
+    zones: Vec<Zone>,
+}
+
+impl Grid {
+    pub fn new(base: &Inputs, x: (Field, Vec<f64>), y: (Field, Vec<f64>)) -> Grid {
+        let mut verdicts: Vec<&'static str> = Vec::new();
+        let cells = y.1.iter().map(|&yv| x.1.iter().map(|&xv| {
+            let mut inputs = base.clone();
+            x.0.set(&mut inputs, xv);
+            y.0.set(&mut inputs, yv);
+            let verdict = evaluation::evaluate(&inputs).verdict;
+            verdicts.iter().position(|v| *v == verdict).unwrap_or_else(|| {
+                verdicts.push(verdict);
+                verdicts.len() - 1
+            })
+        }).collect()).collect();
+
+        // Rules without a color take the palette colors no other zone has claimed.
+        let named: Vec<Option<&'static str>> = verdicts.iter().map(|v| rules::active().color(v).and_then(|c| COLORS.iter().copied().find(|n| *n == c))).collect();
+        let spare: Vec<&'static str> = COLORS.iter().copied().filter(|c| !named.contains(&Some(*c))).collect();
+        let mut spare = spare.into_iter().cycle();
+        let letters: Vec<char> = verdicts.iter().map(|v| verdict_label(v).chars().next().unwrap_or('?').to_ascii_uppercase()).collect();
+        let unique = letters.iter().enumerate().all(|(n, l)| !letters[..n].contains(l));
+        let zones = verdicts.iter().copied().zip(named).enumerate().map(|(n, (verdict, color))| Zone {
+            verdict,
+            color: color.or_else(|| spare.next()).unwrap_or("red"),
+            mark: if unique { letters[n] } else { char::from_digit(n as u32 + 1, 36).unwrap_or('?') },
+        }).collect();
+        Grid { x, y, cells, zones }
+    }
+
+    pub fn print(&self) {
+        let (xf, xs) = &self.x;
+        let (yf, ys) = &self.y;
+        println!("\n=== Heatmap: {} across, {} up ===\n", xf.name(), yf.name());
+        for (row, yv) in self.cells.iter().zip(ys).rev() {
+            let line: String = row.iter().map(|&z| {
+                let zone = &self.zones[z];
+                format!(" {} ", zone.mark).black().on_color(theme::color(zone.color)).to_string()
+            }).collect();
+            println!("{:>10} │{}", precision::fixed(*yv, 2), line);
+        }
+        println!("{:>10} └{}", "", "─".repeat(xs.len() * 3));
+        let (first, last) = (xs.first().copied().unwrap_or_default(), xs.last().copied().unwrap_or_default());
+        println!("{:>10}  {:<w$}{:>10}", "", precision::fixed(first, 2), precision::fixed(last, 2), w = (xs.len() * 3).saturating_sub(10).max(1));
+        println!();
+        for zone in &self.zones {
+            println!(" {} {}", format!(" {} ", zone.mark).black().on_color(theme::color(zone.color)), zone.verdict);
+        }
+    }
+
+    /// One point per cell at its x and y values, in a series per verdict.
+    pub fn chart_data(&self, data: &mut ChartData) {
+        for (row, yv) in self.cells.iter().zip(&self.y.1) {
+            for (&z, xv) in row.iter().zip(&self.x.1) {
+                data.push_xy("heatmap", verdict_label(self.zones[z].verdict), *xv, *yv);
+            }
+        }
+    }
+
+    /// Standalone SVG of the heatmap.
+    pub fn svg(&self) -> String {
+        let (xf, xs) = &self.x;
+        let (yf, ys) = &self.y;
+        let (cell, left, top) = (28.0, 80.0, 20.0);
+        let grid_w = xs.len() as f64 * cell;
+        let grid_h = ys.len() as f64 * cell;
+        let legend_top = top + grid_h + 80.0;
+        let width = (left + grid_w + 20.0).max(520.0);
+        let height = legend_top + self.zones.len() as f64 * 20.0 + 10.0;
+
+        let theme = theme::active();
+        let mut out = theme.svg_open(width, height, 11);
+        for (r, (row, yv)) in self.cells.iter().zip(ys).rev().enumerate() {
+            let y = top + r as f64 * cell;
+            for (c, &z) in row.iter().enumerate() {
+                let zone = &self.zones[z];
+                out.push_str(&format!(r#"<rect x="{x}" y="{y}" width="{cell}" height="{cell}" fill="{fill}"><title>{title}</title></rect>"#, x = left + c as f64 * cell, fill = theme.hex(zone.color), title = verdict_label(zone.verdict)));
+            }
+            out.push_str(&format!(r#"<text x="{x}" y="{ty}" text-anchor="end">{yv:.2}</text>"#, x = left - 6.0, ty = y + cell / 2.0 + 4.0));
+        }
+        for (c, xv) in xs.iter().enumerate() {
+            let x = left + c as f64 * cell + cell / 2.0;
+            out.push_str(&format!(r#"<text x="{x}" y="{ty}" text-anchor="end" transform="rotate(-45 {x} {ty})">{xv:.2}</text>"#, ty = top + grid_h + 14.0));
+        }
+        out.push_str(&format!(r#"<text x="{x}" y="{ty}" text-anchor="middle">{name}</text>"#, x = left + grid_w / 2.0, ty = top + grid_h + 62.0, name = xf.name()));
+        out.push_str(&format!(r#"<text x="14" y="{ty}" text-anchor="middle" transform="rotate(-90 14 {ty})">{name}</text>"#, ty = top + grid_h / 2.0, name = yf.name()));
+        for (n, zone) in self.zones.iter().enumerate() {
+            let y = legend_top + n as f64 * 20.0;
+            out.push_str(&format!(r#"<rect x="{left}" y="{y}" width="14" height="14" fill="{fill}"/>"#, fill = theme.hex(zone.color)));
+            out.push_str(&format!(r#"<text x="{x}" y="{ty}">{label}</text>"#, x = left + 20.0, ty = y + 11.0, label = verdict_label(zone.verdict)));
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+}
@@ -0,0 +1,232 @@
+// Run history: `--save-run history.csv` appends each evaluation with its date and timestamp, and
+// `trend` reads the file back to show how ratio, CAC, and payback moved, with `--chart` drawing all
+// three as one SVG for the monthly growth review. Runs are ordered by timestamp, so histories
+// merged from machines in different timezones still read in order; files from before the
+// timestamp columns are read as they are.
+//
+//   date,cac,cfa,ltgp,ratio,payback_days,evaluated_at,timezone
+//   2026-09-01,520.00,100.00,2100.00,4.04,21.0,2026-09-01T09:12:44-04:00,America/New_York
+
+use std::io::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use clap::Args;
+use jiff::Timestamp;
+
+use crate::chart_data::{self, ChartData};
+use crate::clock;
+use crate::plot;
+use crate::precision;
+use crate::report::EvaluationResult;
+use crate::theme;
+
+const HEADER: &str = "date,cac,cfa,ltgp,ratio,payback_days,evaluated_at,timezone";
+/// The header before runs were timestamped.
+const OLD_HEADER: &str = "date,cac,cfa,ltgp,ratio,payback_days";
+
+#[derive(Args, Debug)]
+pub struct TrendArgs {
+    /// History file written by --save-run
+    pub file: String,
+
+    /// Write the ratio, CAC, and payback over time as one SVG image to this path
+    #[arg(long)]
+    pub chart: Option<String>,
+
+    /// Write the charted series to a CSV file (`chart,series,x,y`, x the run date)
+    #[arg(long)]
+    pub chart_data: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub date: String,
+    pub cac: f64,
+    pub cfa: f64,
+    pub ltgp: f64,
+    pub ratio: f64,
+    pub payback_days: Option<f64>,
+    /// RFC 3339; None in files from before the timestamp columns.
+    pub evaluated_at: Option<String>,
+}
+
+/// Days since the Unix epoch as YYYY-MM-DD (proleptic Gregorian, civil-from-days).
+pub fn date_from_days(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Days since the Unix epoch as (year, month, day).
+pub fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Parses YYYY-MM-DD into days since the Unix epoch (days-from-civil, the inverse of `date_from_days`).
+pub fn days_from_date(date: &str) -> Option<i64> {
+    let mut parts = date.trim().splitn(3, '-').map(|p| p.parse::<i64>().ok());
+    let (year, month, day) = (parts.next()??, parts.next()??, parts.next()??);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+    (date_from_days(days) == format!("{:04}-{:02}-{:02}", year, month, day)).then_some(days)
+}
+
+/// Today (UTC) in days since the Unix epoch.
+pub fn today_days() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs() / 86_400).unwrap_or_default() as i64
+}
+
+/// Appends the run to the history file, writing the header first when the file is new.
+pub fn append(path: &str, r: &EvaluationResult) -> Result<(), String> {
+    let is_new = std::fs::metadata(path).map(|m| m.len() == 0).unwrap_or(true);
+    let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path).map_err(|err| format!("could not open {}: {}", path, err))?;
+    let i = &r.inputs;
+    let payback = r.payback_days.map(|d| format!("{:.1}", d)).unwrap_or_default();
+    let mut line = String::new();
+    if is_new {
+        line.push_str(HEADER);
+        line.push('\n');
+    }
+    let (at, timezone) = (r.evaluated_at.clone().unwrap_or_else(clock::rfc3339), r.timezone.as_deref().unwrap_or_default());
+    let date = at.get(..10).unwrap_or_default();
+    line.push_str(&format!("{},{:.2},{:.2},{:.2},{:.2},{},{},{}\n", date, i.cac, i.cfa, i.ltgp, r.ratio_value(), payback, at, timezone));
+    file.write_all(line.as_bytes()).map_err(|err| format!("could not write {}: {}", path, err))
+}
+
+/// Reads the history in timestamp order; blank lines, `#` comments, and header rows are skipped.
+pub fn read(path: &str) -> Result<Vec<Run>, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path, e))?;
+    let mut runs = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line == HEADER || line == OLD_HEADER { continue; }
+        let fields: Vec<&str> = line.split(',').map(|f| f.trim()).collect();
+        let num = |s: &str| s.parse::<f64>().ok();
+        let (fields, evaluated_at) = match fields.as_slice() {
+            [rest @ .., at, _timezone] if rest.len() == 6 => (rest, Some(at.to_string())),
+            fields => (fields, None),
+        };
+        let run = match fields {
+            [date, cac, cfa, ltgp, ratio, payback] => num(cac).zip(num(cfa)).zip(num(ltgp)).zip(num(ratio)).map(|(((cac, cfa), ltgp), ratio)| Run {
+                date: date.to_string(),
+                cac,
+                cfa,
+                ltgp,
+                ratio,
+                payback_days: num(payback),
+                evaluated_at: evaluated_at.clone(),
+            }),
+            _ => None,
+        };
+        runs.push(run.ok_or_else(|| format!("{}:{}: expected `{}`", path, i + 1, HEADER))?);
+    }
+    // Untimestamped runs sort as the start of their (UTC) day; the sort is stable, so runs on
+    // the same day without timestamps keep their file order.
+    let start_of_day = |date: &str| days_from_date(date).and_then(|d| Timestamp::from_second(d * 86_400).ok());
+    runs.sort_by_key(|r| r.evaluated_at.as_deref().and_then(clock::instant).or_else(|| start_of_day(&r.date)));
+    Ok(runs)
+}
+
+/// The three series charted, each with its rule color.
+fn series(runs: &[Run]) -> [(&'static str, Vec<Option<f64>>, &'static str); 3] {
+    [
+        ("LTGP:CAC", runs.iter().map(|r| Some(r.ratio).filter(|v| v.is_finite())).collect(), "blue"),
+        ("CAC ($)", runs.iter().map(|r| Some(r.cac)).collect(), "red"),
+        ("Payback (days)", runs.iter().map(|r| r.payback_days).collect(), "green"),
+    ]
+}
+
+fn chart_svg(runs: &[Run]) -> String {
+    let (panel_w, panel_h, gap, left, top) = (600.0, 120.0, 40.0, 70.0, 20.0);
+    let n = runs.len();
+    let x = |k: usize| left + if n > 1 { k as f64 / (n - 1) as f64 * panel_w } else { panel_w / 2.0 };
+    let height = top + 3.0 * (panel_h + gap) + 20.0;
+    let theme = theme::active();
+    let grid = &theme.grid;
+    let mut out = theme.svg_open(left + panel_w + 30.0, height, 11);
+    for (p, (name, values, color)) in series(runs).iter().enumerate() {
+        let color = theme.hex(color);
+        let y0 = top + p as f64 * (panel_h + gap);
+        let known: Vec<f64> = values.iter().flatten().copied().collect();
+        let lo = known.iter().copied().fold(f64::INFINITY, f64::min).min(0.0);
+        let hi = known.iter().copied().fold(f64::NEG_INFINITY, f64::max).max(lo + 1.0);
+        let y = |v: f64| y0 + (1.0 - (v - lo) / (hi - lo)) * panel_h;
+        out.push_str(&format!(r#"<text x="{left}" y="{ty}" font-size="12">{name}</text>"#, ty = y0 - 6.0));
+        out.push_str(&format!(r#"<rect x="{left}" y="{y0}" width="{panel_w}" height="{panel_h}" fill="none" stroke="{grid}"/>"#));
+        out.push_str(&format!(r#"<text x="{lx}" y="{ty:.1}" text-anchor="end">{hi:.1}</text><text x="{lx}" y="{by:.1}" text-anchor="end">{lo:.1}</text>"#, lx = left - 6.0, ty = y0 + 10.0, by = y0 + panel_h));
+        let points: Vec<String> = values.iter().enumerate().filter_map(|(k, v)| v.map(|v| format!("{:.1},{:.1}", x(k), y(v)))).collect();
+        out.push_str(&format!(r#"<polyline points="{}" fill="none" stroke="{color}" stroke-width="2"/>"#, points.join(" ")));
+        for (k, v) in values.iter().enumerate() {
+            if let Some(v) = v {
+                out.push_str(&format!(r#"<circle cx="{cx:.1}" cy="{cy:.1}" r="3" fill="{color}"><title>{date}: {v:.2}</title></circle>"#, cx = x(k), cy = y(*v), date = runs[k].date));
+            }
+        }
+    }
+    let label_y = top + 3.0 * (panel_h + gap) - gap + 16.0;
+    if let (Some(first), Some(last)) = (runs.first(), runs.last()) {
+        out.push_str(&format!(r#"<text x="{l}" y="{label_y}">{d}</text><text x="{r}" y="{label_y}" text-anchor="end">{e}</text>"#, l = left, r = left + panel_w, d = first.date, e = last.date));
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+pub fn run(args: &TrendArgs) {
+    let runs = read(&args.file).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(2);
+    });
+    if runs.is_empty() {
+        println!("\nNo saved runs in {} yet; add --save-run {} to an evaluation.", args.file, args.file);
+        return;
+    }
+
+    println!("\n=== Trend: {} runs from {} to {} ===\n", runs.len(), runs[0].date, runs[runs.len() - 1].date);
+    println!("{:<12}  {:>10}  {:>10}  {:>10}  {:>8}  {:>12}", "Date", "CAC", "CFA", "LTGP", "LTGP:CAC", "Payback");
+    for r in &runs {
+        let payback = r.payback_days.map(|d| format!("{} days", precision::fixed(d, 1))).unwrap_or_else(|| "-".to_string());
+        println!("{:<12}  {:>10}  {:>10}  {:>10}  {:>8}  {:>12}", r.date, precision::fixed(r.cac, 2), precision::fixed(r.cfa, 2), precision::fixed(r.ltgp, 2), precision::fixed(r.ratio, 2), payback);
+    }
+    println!();
+    for (name, values, _) in series(&runs) {
+        let known: Vec<f64> = values.iter().flatten().copied().collect();
+        if let (Some(first), Some(last)) = (known.first(), known.last()) {
+            println!(" {:<15} {}  {} → {}", name, plot::sparkline(&known), precision::fixed(*first, 2), precision::fixed(*last, 2));
+        }
+    }
+
+    if let Some(path) = &args.chart {
+        match std::fs::write(path, chart_svg(&runs)) {
+            Ok(()) => println!("\nTrend chart written to {}.", path),
+            Err(e) => {
+                eprintln!("Error: could not write {}: {}", path, e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    let mut data = ChartData::new();
+    for (key, (_, values, _)) in ["ltgp_cac", "cac", "payback_days"].into_iter().zip(series(&runs)) {
+        for (r, v) in runs.iter().zip(values) {
+            if let Some(v) = v {
+                data.push("trend", key, &r.date, v);
+            }
+        }
+    }
+    chart_data::save(&data, args.chart_data.as_deref());
+}
@@ -0,0 +1,268 @@
+// Interactive HTML report: one self-contained page with sliders for CAC, CFA, LTGP, and the early
+// gross profit rate, a quadrant plot, and a payback curve. The page carries the active decision
+// tree and verdict rules as JSON and re-evaluates them in embedded JS, so recipients can explore
+// assumptions in a browser without installing the CLI and still get the same policy's answers.
+// Page and chart colors come from the active theme.
+
+use crate::evaluation::money;
+use crate::format::Formatter;
+use crate::period::DAYS_PER_MONTH;
+use crate::report::EvaluationResult;
+use crate::severity::Severity;
+use crate::rules::COLORS;
+use crate::{precision, rules, theme, tree};
+
+/// Escapes text placed in HTML element content.
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+pub struct Html;
+
+impl Formatter for Html {
+    fn name(&self) -> &'static str {
+        "html"
+    }
+
+    fn render(&self, r: &EvaluationResult) -> String {
+        let i = &r.inputs;
+        let t = theme::active();
+        let data = serde_json::json!({
+            "inputs": {
+                "cac": i.cac,
+                "cfa": i.cfa,
+                "ltgp": i.ltgp,
+                "early_gp_rate": i.early_gp_rate,
+                "low_cac_fraction": i.low_cac_fraction,
+                "high_cfa_fraction": i.high_cfa_fraction,
+                "low_cac_max": i.low_cac_max,
+                "high_cfa_min": i.high_cfa_min,
+            },
+            "period": i.period,
+            "period_days": i.period.days(),
+            "days_per_month": DAYS_PER_MONTH,
+            "precision": precision::explicit(),
+            "rounding": precision::rounding(),
+            "tree": tree::active().to_json(),
+            "rules": rules::active().to_json(),
+            "severity_colors": Severity::ALL.iter().map(|s| (s.name().to_string(), s.hex().into())).collect::<serde_json::Map<_, _>>(),
+            "theme": {
+                "name": t.name,
+                "axis": t.axis,
+                "guide": t.guide,
+                "colors": COLORS.iter().map(|c| (c.to_string(), t.hex(c).into())).collect::<serde_json::Map<_, _>>(),
+            },
+        });
+        // `</` would end the script element early if it appeared inside a label.
+        let data = data.to_string().replace("</", "<\\/");
+        let summary = format!("CAC {}, CFA {}, LTGP {}: {} ({})", money(i.cac), money(i.cfa), money(i.ltgp), r.verdict, r.severity.level.name());
+        let css = format!("--background: {}; --text: {}; --muted: {};", t.background, t.text, t.muted);
+        TEMPLATE.replace("{{THEME}}", &css).replace("{{SUMMARY}}", &escape(&summary)).replace("{{DATA}}", &data)
+    }
+}
+
+const TEMPLATE: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Growth Model Evaluation</title>
+<style>
+  :root { {{THEME}} }
+  body { font-family: sans-serif; max-width: 980px; margin: 2em auto; color: var(--text); background: var(--background); }
+  .as-run { color: var(--muted); }
+  .controls { display: grid; grid-template-columns: 12em 1fr 8em; gap: .4em 1em; align-items: center; }
+  .results td { padding: .2em 1em .2em 0; }
+  .charts { display: flex; flex-wrap: wrap; gap: 2em; }
+  svg text { font-size: 11px; fill: var(--text); }
+  .severity { color: var(--background); font-weight: bold; padding: .1em .5em; border-radius: .3em; }
+</style>
+</head>
+<body>
+<h1>Growth Model Evaluation</h1>
+<p class="as-run">As run: {{SUMMARY}}</p>
+
+<h2>Assumptions</h2>
+<div class="controls" id="controls"></div>
+
+<h2>Results</h2>
+<table class="results"><tbody id="results"></tbody></table>
+
+<div class="charts">
+  <div><h3>Quadrant</h3><svg id="quadrant" width="420" height="320"></svg></div>
+  <div><h3>Payback curve</h3><svg id="curve" width="420" height="320"></svg></div>
+</div>
+
+<script>
+const DATA = {{DATA}};
+const THEME = DATA.theme;
+const base = DATA.inputs;
+const inputs = Object.assign({}, base);
+// Display rounding, as the CLI's `precision::fixed`: `--precision` places, else `places`.
+function fixed(v, places) {
+  const p = DATA.precision ?? places;
+  const scaled = Math.round(Math.abs(v) * 10 ** p * 1e6) / 1e6;
+  const whole = scaled % 1 === 0.5 && DATA.rounding === "bankers" ? 2 * Math.round(scaled / 2) : Math.round(scaled);
+  return (Math.sign(v) * whole / 10 ** p).toFixed(p);
+}
+const money = v => (v < 0 ? "-$" : "$") + fixed(Math.abs(v), 2);
+
+// Same formulas as the CLI's evaluation.
+function evaluate(i) {
+  const lowCacThresh = i.low_cac_max ?? Math.min(Math.max(i.low_cac_fraction, 0), 1) * i.ltgp;
+  const highCfaThresh = i.high_cfa_min ?? Math.max(i.high_cfa_fraction, 0) * i.cac;
+  const netOutlay = Math.max(i.cac - i.cfa, 0);
+  const ratio = i.cac > 0 ? i.ltgp / i.cac : i.ltgp > 0 ? Infinity : 0;
+  const payback = i.early_gp_rate > 0 ? netOutlay / i.early_gp_rate : null;
+  const gpPerMonth = DATA.period_days > 0 ? i.early_gp_rate * DATA.days_per_month / DATA.period_days : 0;
+  const e = {
+    inputs: i, lowCacThresh, highCfaThresh, netOutlay, ratio, payback,
+    cacPaybackMonths: gpPerMonth > 0 ? i.cac / gpPerMonth : null,
+    lowCac: i.cac <= lowCacThresh,
+    highCfa: i.cfa >= highCfaThresh,
+  };
+  let node = DATA.tree;
+  while (node.test) node = holds(node.test, e) ? node.yes : node.no;
+  e.quadrant = node;
+  const rule = DATA.rules.find(r => r.when.every(c => holds(c, e)));
+  e.verdict = rule ? rule.verdict : "Unclassified: No verdict rule matched these numbers.";
+  e.severity = rule ? rule.severity : "watch";
+  return e;
+}
+
+// Unknown paybacks are NaN so, as in the CLI, they never satisfy a comparison.
+function metric(name, e) {
+  const i = e.inputs;
+  switch (name) {
+    case "cac": return i.cac;
+    case "cfa": return i.cfa;
+    case "ltgp": return i.ltgp;
+    case "ratio": return e.ratio;
+    case "net_outlay": return e.netOutlay;
+    case "cfa_coverage": return i.cac > 0 ? i.cfa / i.cac : Infinity;
+    case "payback_days": return e.payback === null ? NaN : e.payback * DATA.period_days;
+    case "cac_payback_months": return e.cacPaybackMonths ?? NaN;
+    case "low_cac": return e.lowCac ? 1 : 0;
+    case "high_cfa": return e.highCfa ? 1 : 0;
+  }
+}
+
+function holds(c, e) {
+  const a = metric(c.metric, e), b = c.value;
+  switch (c.op) {
+    case "<": return a < b;
+    case "<=": return a <= b;
+    case ">": return a > b;
+    case ">=": return a >= b;
+    case "==": return a === b;
+    case "!=": return a !== b;
+  }
+}
+
+const SLIDERS = [
+  ["cac", "CAC ($)", Math.max(base.cac * 3, 100)],
+  ["cfa", "CFA ($)", Math.max(base.cfa * 3, base.cac * 2, 100)],
+  ["ltgp", "LTGP ($)", Math.max(base.ltgp * 3, 100)],
+  ["early_gp_rate", "Early GP per " + DATA.period.replace(/s$/, "") + " ($)", Math.max(base.early_gp_rate * 3, 10)],
+];
+
+const controls = document.getElementById("controls");
+for (const [key, label, max] of SLIDERS) {
+  const name = document.createElement("label");
+  name.textContent = label;
+  const slider = document.createElement("input");
+  Object.assign(slider, { type: "range", min: 0, max, step: max / 300, value: base[key] });
+  const value = document.createElement("span");
+  value.textContent = money(base[key]);
+  slider.addEventListener("input", () => {
+    inputs[key] = Number(slider.value);
+    value.textContent = money(inputs[key]);
+    render();
+  });
+  controls.append(name, slider, value);
+}
+
+function svgEl(svg, tag, attrs, text) {
+  const el = document.createElementNS("http://www.w3.org/2000/svg", tag);
+  for (const [k, v] of Object.entries(attrs)) el.setAttribute(k, v);
+  if (text !== undefined) el.textContent = text;
+  svg.appendChild(el);
+}
+
+// Axes stay at the slider ranges so moving a slider visibly moves the point.
+function drawQuadrant(e) {
+  const svg = document.getElementById("quadrant");
+  svg.replaceChildren();
+  const [left, top, w, h] = [50, 10, 350, 270];
+  const xMax = SLIDERS[0][2], yMax = SLIDERS[1][2];
+  const x = v => left + Math.min(v / xMax, 1) * w, y = v => top + (1 - Math.min(v / yMax, 1)) * h;
+  const boundary = cac => e.inputs.high_cfa_min ?? Math.max(e.inputs.high_cfa_fraction, 0) * cac;
+  svgEl(svg, "rect", { x: left, y: top, width: w, height: h, fill: "none", stroke: THEME.axis });
+  svgEl(svg, "line", { x1: x(e.lowCacThresh), x2: x(e.lowCacThresh), y1: top, y2: top + h, stroke: THEME.guide, "stroke-dasharray": "4 3" });
+  svgEl(svg, "line", { x1: x(0), x2: x(xMax), y1: y(boundary(0)), y2: y(boundary(xMax)), stroke: THEME.guide, "stroke-dasharray": "4 3" });
+  svgEl(svg, "circle", { cx: x(e.inputs.cac), cy: y(e.inputs.cfa), r: 6, fill: THEME.colors.blue });
+  svgEl(svg, "text", { x: x(e.inputs.cac) + 9, y: y(e.inputs.cfa) + 4 }, e.quadrant.label);
+  svgEl(svg, "text", { x: left + w, y: top + h + 16, "text-anchor": "end" }, "CAC " + money(xMax));
+  svgEl(svg, "text", { x: left - 6, y: top + 10, "text-anchor": "end" }, "CFA");
+}
+
+function drawCurve(e) {
+  const svg = document.getElementById("curve");
+  svg.replaceChildren();
+  const [left, top, w, h] = [50, 10, 350, 270];
+  const i = e.inputs;
+  svgEl(svg, "rect", { x: left, y: top, width: w, height: h, fill: "none", stroke: THEME.axis });
+  if (e.payback === null) {
+    svgEl(svg, "text", { x: left + w / 2, y: top + h / 2, "text-anchor": "middle" }, "No early gross profit, so no curve.");
+    return;
+  }
+  const horizon = i.ltgp / i.early_gp_rate;
+  const breakeven = e.netOutlay <= i.ltgp ? e.payback : null;
+  const xMax = Math.max(Math.max(horizon, breakeven ?? 0) * 1.15, 1);
+  const yMax = Math.max(Math.max(i.ltgp, e.netOutlay) * 1.1, 1);
+  const x = t => left + t / xMax * w, y = v => top + (1 - v / yMax) * h;
+  const gp = t => Math.min(i.early_gp_rate * t, i.ltgp);
+  svgEl(svg, "line", { x1: x(0), x2: x(xMax), y1: y(e.netOutlay), y2: y(e.netOutlay), stroke: THEME.colors.red, "stroke-width": 2 });
+  svgEl(svg, "line", { x1: x(horizon), x2: x(horizon), y1: top, y2: top + h, stroke: THEME.guide, "stroke-dasharray": "4 3" });
+  const points = [0, Math.min(horizon, xMax), xMax].map(t => x(t).toFixed(1) + "," + y(gp(t)).toFixed(1)).join(" ");
+  svgEl(svg, "polyline", { points, fill: "none", stroke: THEME.colors.green, "stroke-width": 2.5 });
+  if (breakeven !== null) {
+    svgEl(svg, "circle", { cx: x(breakeven), cy: y(e.netOutlay), r: 5, fill: THEME.colors.blue });
+    svgEl(svg, "text", { x: x(breakeven) + 8, y: y(e.netOutlay) + 16 }, "breakeven " + breakeven.toFixed(1));
+  }
+  svgEl(svg, "text", { x: left + w, y: top + h + 16, "text-anchor": "end" }, xMax.toFixed(1) + " " + DATA.period);
+  svgEl(svg, "text", { x: left - 6, y: top + 10, "text-anchor": "end" }, money(yMax));
+}
+
+function render() {
+  const e = evaluate(inputs);
+  const rows = [
+    ["Net outlay", money(e.netOutlay)],
+    ["LTGP:CAC", isFinite(e.ratio) ? fixed(e.ratio, 2) : "unbounded"],
+    ["Quadrant", e.quadrant.description],
+    ["Verdict", e.verdict],
+    ["Payback", e.payback === null ? "unknown" : fixed(e.payback, 2) + " " + DATA.period],
+    ["CAC payback", e.cacPaybackMonths === null ? "unknown" : fixed(e.cacPaybackMonths, 1) + " months"],
+  ];
+  const body = document.getElementById("results");
+  body.replaceChildren();
+  for (const [name, value] of rows) {
+    const tr = body.insertRow();
+    tr.insertCell().textContent = name;
+    tr.insertCell().textContent = value;
+  }
+  const badge = document.createElement("span");
+  badge.className = "severity";
+  badge.style.background = DATA.severity_colors[e.severity];
+  badge.textContent = e.severity;
+  const tr = body.insertRow(4);
+  tr.insertCell().textContent = "Severity";
+  tr.insertCell().append(badge);
+  drawQuadrant(e);
+  drawCurve(e);
+}
+
+render();
+</script>
+</body>
+</html>
+"##;
@@ -0,0 +1,148 @@
+// HubSpot import: closed-won deals from the CRM search API, mapped into acquisition records for
+// `crm::report`. The first invoice is the deal amount unless `--cfa-property` names a property
+// that holds it (e.g. a custom "first invoice" field, or hs_mrr for monthly billing), and the
+// source is the deal's original traffic source.
+
+use std::collections::HashMap;
+
+use clap::Args;
+use serde::Deserialize;
+
+use crate::crm::{self, Acquisition, CrmArgs};
+use crate::history::days_from_date;
+use crate::import::{self, Imported, Importer, OutputArgs, PeriodArgs};
+
+const PAGE_SIZE: usize = 100;
+const API_URL: &str = "https://api.hubapi.com";
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+#[derive(Args, Debug)]
+pub struct HubSpotArgs {
+    /// Private app access token with crm.objects.deals.read (default: the HUBSPOT_ACCESS_TOKEN environment variable)
+    #[arg(long)]
+    pub access_token: Option<String>,
+
+    /// Deal property holding the first invoice's cash (CFA)
+    #[arg(long, default_value = "amount")]
+    pub cfa_property: String,
+
+    /// Deal property holding the lead source
+    #[arg(long, default_value = "hs_analytics_source")]
+    pub source_property: String,
+
+    /// API base URL, e.g. a mock server for trying the import out
+    #[arg(long, default_value = API_URL)]
+    pub api_url: String,
+
+    #[command(flatten)]
+    pub crm: CrmArgs,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Deserialize, Debug)]
+struct Page {
+    results: Vec<Deal>,
+    paging: Option<Paging>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Paging {
+    next: Next,
+}
+
+#[derive(Deserialize, Debug)]
+struct Next {
+    after: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct Deal {
+    /// Every property comes back as a string, or null when unset.
+    properties: HashMap<String, Option<String>>,
+}
+
+impl Deal {
+    fn property(&self, name: &str) -> Option<&str> {
+        self.properties.get(name).and_then(|v| v.as_deref()).filter(|v| !v.is_empty())
+    }
+
+    /// `2026-03-14T09:30:00Z` → days since the epoch.
+    fn day(&self, name: &str) -> Option<i64> {
+        self.property(name).and_then(|v| v.get(..10)).and_then(days_from_date)
+    }
+
+    fn acquisition(&self, args: &HubSpotArgs) -> Option<Acquisition> {
+        Some(Acquisition {
+            source: self.property(&args.source_property).map(str::to_string),
+            first_invoice: self.property(&args.cfa_property).and_then(|v| v.parse().ok()),
+            created: self.day("createdate")?,
+            closed: self.day("closedate")?,
+        })
+    }
+}
+
+/// Closed-won deals with a close date in the period, paging with `after`.
+fn fetch(args: &HubSpotArgs, token: &str) -> Result<Vec<Deal>, String> {
+    let (since, until) = args.crm.period.days()?;
+    let url = format!("{}/crm/v3/objects/deals/search", args.api_url.trim_end_matches('/'));
+    let bearer = format!("Bearer {}", token);
+    let mut all = Vec::new();
+    let mut after: Option<String> = None;
+    loop {
+        let mut body = serde_json::json!({
+            "filterGroups": [{ "filters": [
+                { "propertyName": "hs_is_closed_won", "operator": "EQ", "value": "true" },
+                { "propertyName": "closedate", "operator": "GTE", "value": (since * MILLIS_PER_DAY).to_string() },
+                { "propertyName": "closedate", "operator": "LT", "value": ((until + 1) * MILLIS_PER_DAY).to_string() },
+            ]}],
+            "properties": ["createdate", "closedate", args.cfa_property, args.source_property],
+            "limit": PAGE_SIZE,
+        });
+        if let Some(cursor) = &after {
+            body["after"] = cursor.clone().into();
+        }
+        let page: Page = import::post(&url, &[("Authorization", &bearer)], &body.to_string())?;
+        all.extend(page.results);
+        match page.paging {
+            Some(paging) => after = Some(paging.next.after),
+            None => return Ok(all),
+        }
+    }
+}
+
+impl HubSpotArgs {
+    fn acquisitions(&self) -> Result<Vec<Acquisition>, String> {
+        let token = import::credential(&self.access_token, "access-token", "HUBSPOT_ACCESS_TOKEN")?;
+        let deals = fetch(self, &token)?;
+        Ok(deals.iter().filter_map(|d| d.acquisition(self)).collect())
+    }
+
+    /// Deals won in `period` with the default properties, for imports that need the acquisitions.
+    pub fn acquisitions_in(period: &PeriodArgs) -> Result<Vec<Acquisition>, String> {
+        let args = HubSpotArgs {
+            access_token: None,
+            cfa_property: "amount".to_string(),
+            source_property: "hs_analytics_source".to_string(),
+            api_url: API_URL.to_string(),
+            crm: CrmArgs { period: period.clone(), spend: None },
+            output: OutputArgs { out: None, name: None },
+        };
+        args.acquisitions()
+    }
+}
+
+impl Importer for HubSpotArgs {
+    fn name(&self) -> &'static str {
+        "HubSpot"
+    }
+
+    fn output(&self) -> &OutputArgs {
+        &self.output
+    }
+
+    fn import(&self) -> Result<Imported, String> {
+        crm::report(self.name(), &self.acquisitions()?, &self.crm)
+    }
+}
@@ -0,0 +1,324 @@
+// Importing inputs from the systems that already hold them. Each source derives whichever of CAC,
+// CFA, LTGP, and the early gross profit rate it can, prints how it got them, and can save them as
+// a scenario file (`--out`) for `compare`, `batch`, or any subcommand's `--scenario`. Inputs a
+// source can't know are left as commented placeholders to fill in.
+//
+// A new source implements `Importer` for its clap args and gets a variant in `Source`; fetching,
+// reporting, and saving the scenario are shared.
+
+use clap::{Args, Subcommand};
+use serde::de::DeserializeOwned;
+
+use crate::channel::Channel;
+use crate::evaluation::money;
+use crate::history::{date_from_days, days_from_date, today_days};
+use crate::precision;
+use crate::{ads, google_ads, hubspot, meta_ads, quickbooks, saas_metrics, salesforce, shopify, stripe, xero};
+use crate::period::Period;
+
+const DEFAULT_PERIOD_DAYS: i64 = 30;
+
+#[derive(Args, Debug)]
+pub struct ImportArgs {
+    #[command(subcommand)]
+    pub source: Source,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Source {
+    /// Derive CFA, LTGP, and the monthly gross profit rate from Stripe charges, refunds, and subscriptions
+    Stripe(stripe::StripeArgs),
+
+    /// Derive LTGP and the monthly gross profit rate from ChartMogul's ARPA, churn, and LTV
+    Chartmogul(saas_metrics::ChartMogulArgs),
+
+    /// Derive LTGP and the monthly gross profit rate from Baremetrics' ARPU, churn, and LTV
+    Baremetrics(saas_metrics::BaremetricsArgs),
+
+    /// Derive LTGP and the monthly gross profit rate from ProfitWell (Paddle) ARPU and revenue retention
+    Profitwell(saas_metrics::ProfitWellArgs),
+
+    /// Derive first-order cash (CFA) and repeat-purchase LTGP from Shopify order history
+    Shopify(shopify::ShopifyArgs),
+
+    /// Derive blended and per-campaign CAC from Google Ads cost and conversions (API or CSV report)
+    GoogleAds(google_ads::GoogleAdsArgs),
+
+    /// Derive blended and per-campaign CAC from Meta (Facebook/Instagram) Ads spend and conversions (API or CSV report)
+    MetaAds(meta_ads::MetaAdsArgs),
+
+    /// Combine Google Ads and Meta Ads spend into blended and per-platform CAC in one import
+    Ads(ads::AdsArgs),
+
+    /// Derive acquisitions, sales cycle, and first-invoice CFA from HubSpot closed-won deals
+    Hubspot(hubspot::HubSpotArgs),
+
+    /// Derive acquisitions, sales cycle, and first-invoice CFA from Salesforce closed-won opportunities
+    Salesforce(salesforce::SalesforceArgs),
+
+    /// Derive a fully-loaded CAC from QuickBooks Online sales and marketing expenses
+    Quickbooks(quickbooks::QuickBooksArgs),
+
+    /// Derive a fully-loaded CAC from Xero sales and marketing expenses
+    Xero(xero::XeroArgs),
+}
+
+impl Source {
+    pub fn importer(&self) -> &dyn Importer {
+        match self {
+            Source::Stripe(args) => args,
+            Source::Chartmogul(args) => args,
+            Source::Baremetrics(args) => args,
+            Source::Profitwell(args) => args,
+            Source::Shopify(args) => args,
+            Source::GoogleAds(args) => args,
+            Source::MetaAds(args) => args,
+            Source::Ads(args) => args,
+            Source::Hubspot(args) => args,
+            Source::Salesforce(args) => args,
+            Source::Quickbooks(args) => args,
+            Source::Xero(args) => args,
+        }
+    }
+}
+
+/// One place inputs can be imported from.
+pub trait Importer {
+    /// The source's name, shown in reports and used as the default scenario name.
+    fn name(&self) -> &'static str;
+
+    fn output(&self) -> &OutputArgs;
+
+    /// Fetches the source's data, prints how the inputs were derived, and returns them.
+    fn import(&self) -> Result<Imported, String>;
+}
+
+/// Where imported inputs go, shared by every source.
+#[derive(Args, Debug, Clone)]
+pub struct OutputArgs {
+    /// Save the imported inputs as a scenario file (TOML) at this path
+    #[arg(long)]
+    pub out: Option<String>,
+
+    /// Scenario name to save with --out (default: the source's name)
+    #[arg(long)]
+    pub name: Option<String>,
+}
+
+/// The stretch of time an API import covers, shared by sources that query by date.
+#[derive(Args, Debug, Clone)]
+pub struct PeriodArgs {
+    /// First day to fetch, YYYY-MM-DD (default: 30 days before --until)
+    #[arg(long)]
+    pub since: Option<String>,
+
+    /// Last day to fetch, YYYY-MM-DD (default: today)
+    #[arg(long)]
+    pub until: Option<String>,
+}
+
+impl PeriodArgs {
+    /// The `(since, until)` days since the epoch, checked and defaulted.
+    pub fn days(&self) -> Result<(i64, i64), String> {
+        let day = |flag: &str, given: &Option<String>, default: i64| match given {
+            Some(date) => days_from_date(date).ok_or_else(|| format!("--{}: expected YYYY-MM-DD, got `{}`", flag, date)),
+            None => Ok(default),
+        };
+        let until = day("until", &self.until, today_days())?;
+        let since = day("since", &self.since, until - DEFAULT_PERIOD_DAYS)?;
+        if since > until {
+            return Err("--since is after --until".to_string());
+        }
+        Ok((since, until))
+    }
+
+    /// `days` as YYYY-MM-DD dates.
+    pub fn dates(&self) -> Result<(String, String), String> {
+        let (since, until) = self.days()?;
+        Ok((date_from_days(since), date_from_days(until)))
+    }
+}
+
+/// Inputs derived by an import; None where the source has nothing to go on.
+#[derive(Debug, Clone, Default)]
+pub struct Imported {
+    pub cac: Option<f64>,
+    pub cfa: Option<f64>,
+    pub ltgp: Option<f64>,
+    pub early_gp_rate: Option<f64>,
+    /// Period unit of `early_gp_rate`.
+    pub period: Option<Period>,
+    /// Per-channel CAC for `--channel`; scenario files have no place for them.
+    pub channels: Vec<Channel>,
+    /// Other calculator flags the source informs, like `sales-cycle-days`; command line only too.
+    pub options: Vec<(&'static str, f64)>,
+}
+
+impl Imported {
+    fn fields(&self) -> [(&'static str, Option<f64>); 4] {
+        [("cac", self.cac), ("cfa", self.cfa), ("ltgp", self.ltgp), ("early_gp_rate", self.early_gp_rate)]
+    }
+
+    /// Scenario TOML; missing inputs are commented out so the file won't load until they're set.
+    pub fn scenario(&self, name: &str, source: &str) -> String {
+        let mut text = format!("# Imported from {}.\nname = \"{}\"\n", source, name.replace('\\', "\\\\").replace('"', "\\\""));
+        for (key, value) in self.fields() {
+            match value {
+                Some(v) => text.push_str(&format!("{} = {:.2}\n", key, v)),
+                None => text.push_str(&format!("# {} = ?    # not available from {}; set it before running\n", key, source)),
+            }
+        }
+        if let Some(period) = self.period {
+            text.push_str(&format!("period = \"{}\"\n", period));
+        }
+        for c in &self.channels {
+            text.push_str(&format!("# --channel {}    # pass on the command line; scenario files don't hold channels\n", channel_flag(c)));
+        }
+        for (flag, value) in &self.options {
+            text.push_str(&format!("# --{} {:.1}    # pass on the command line; scenario files don't hold it\n", flag, value));
+        }
+        text
+    }
+
+    /// The calculator flags for the inputs that were derived.
+    fn flags(&self) -> String {
+        let mut flags: Vec<String> = self.fields().iter().filter_map(|(key, v)| v.map(|v| format!("--{} {:.2}", key.replace('_', "-"), v))).collect();
+        if let Some(period) = self.period {
+            flags.push(format!("--period {}", period));
+        }
+        flags.extend(self.channels.iter().map(|c| format!("--channel {}", channel_flag(c))));
+        flags.extend(self.options.iter().map(|(flag, value)| format!("--{} {:.1}", flag, value)));
+        flags.join(" ")
+    }
+}
+
+fn channel_flag(c: &Channel) -> String {
+    format!("{}:{:.2}", c.name, c.cac)
+}
+
+/// `url` without its query string, for logs: some APIs put tokens there.
+fn endpoint(url: &str) -> &str {
+    url.split('?').next().unwrap_or(url)
+}
+
+/// GETs `url` with an `Authorization` header and parses the JSON reply.
+pub fn get<T: DeserializeOwned>(url: &str, authorization: &str, query: &[(&str, String)]) -> Result<T, String> {
+    get_with(url, &[("Authorization", authorization)], query)
+}
+
+/// `get` for APIs that take their credentials in headers of their own.
+#[tracing::instrument(level = "debug", skip_all, fields(url = endpoint(url)), err(level = "debug"))]
+pub fn get_with<T: DeserializeOwned>(url: &str, headers: &[(&str, &str)], query: &[(&str, String)]) -> Result<T, String> {
+    let mut request = ureq::get(url);
+    for (k, v) in headers {
+        request = request.header(*k, *v);
+    }
+    for (k, v) in query {
+        request = request.query(*k, v);
+    }
+    let body = request.call().and_then(|mut r| r.body_mut().read_to_string()).map_err(|e| format!("could not fetch {}: {}", url, e))?;
+    serde_json::from_str(&body).map_err(|e| format!("{}: {}", url, e))
+}
+
+/// POSTs a JSON `body` to `url` with the given headers and parses the JSON reply.
+pub fn post<T: DeserializeOwned>(url: &str, headers: &[(&str, &str)], body: &str) -> Result<T, String> {
+    send(ureq::post(url), url, headers, body)
+}
+
+/// `post` with PATCH, for APIs that update in place.
+pub fn patch<T: DeserializeOwned>(url: &str, headers: &[(&str, &str)], body: &str) -> Result<T, String> {
+    send(ureq::patch(url), url, headers, body)
+}
+
+#[tracing::instrument(level = "debug", skip_all, fields(url = endpoint(url)), err(level = "debug"))]
+fn send<T: DeserializeOwned>(mut request: ureq::RequestBuilder<ureq::typestate::WithBody>, url: &str, headers: &[(&str, &str)], body: &str) -> Result<T, String> {
+    request = request.content_type("application/json");
+    for (k, v) in headers {
+        request = request.header(*k, *v);
+    }
+    let reply = request.send(body).and_then(|mut r| r.body_mut().read_to_string()).map_err(|e| format!("could not fetch {}: {}", url, e))?;
+    serde_json::from_str(&reply).map_err(|e| format!("{}: {}", url, e))
+}
+
+/// DELETEs `url` with the given headers and parses the JSON reply.
+#[tracing::instrument(level = "debug", skip_all, fields(url = endpoint(url)), err(level = "debug"))]
+pub fn delete<T: DeserializeOwned>(url: &str, headers: &[(&str, &str)]) -> Result<T, String> {
+    let mut request = ureq::delete(url);
+    for (k, v) in headers {
+        request = request.header(*k, *v);
+    }
+    let reply = request.call().and_then(|mut r| r.body_mut().read_to_string()).map_err(|e| format!("could not fetch {}: {}", url, e))?;
+    serde_json::from_str(&reply).map_err(|e| format!("{}: {}", url, e))
+}
+
+/// `s` percent-encoded for use as one URL path segment.
+pub fn path_segment(s: &str) -> String {
+    s.bytes().map(|b| if b.is_ascii_alphanumeric() || b"-._~!$'()*+,;=:@".contains(&b) { (b as char).to_string() } else { format!("%{:02X}", b) }).collect()
+}
+
+/// `Basic` authorization for APIs that take the key as the username and no password.
+pub fn basic_auth(user: &str) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let bytes = format!("{}:", user).into_bytes();
+    let mut encoded = String::new();
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (k, b)| n | (*b as u32) << (16 - 8 * k));
+        for k in 0..4 {
+            encoded.push(if k <= chunk.len() { ALPHABET[(n >> (18 - 6 * k) & 63) as usize] as char } else { '=' });
+        }
+    }
+    format!("Basic {}", encoded)
+}
+
+/// The credential given as `--<flag>`, else the environment variable `var`.
+pub fn credential(given: &Option<String>, flag: &str, var: &str) -> Result<String, String> {
+    given.clone().or_else(|| std::env::var(var).ok()).ok_or_else(|| format!("provide --{} or set {}", flag, var))
+}
+
+pub fn check_gross_margin(margin: f64) -> Result<(), String> {
+    if (0.0..=1.0).contains(&margin) { Ok(()) } else { Err("--gross-margin must be between 0 and 1".to_string()) }
+}
+
+/// Prints the derived inputs and writes the scenario file when `--out` was given.
+pub fn finish(imported: &Imported, source: &str, output: &OutputArgs) {
+    println!("\nImported inputs:");
+    for (key, value) in imported.fields() {
+        let shown = match (key, value) {
+            (_, None) => format!("not available from {}", source),
+            ("early_gp_rate", Some(v)) => format!("{} per {}", money(v), imported.period.map_or("period", |p| p.unit())),
+            (_, Some(v)) => money(v),
+        };
+        println!("  {:<17} {}", key, shown);
+    }
+    for c in &imported.channels {
+        println!("  {:<17} {} CAC {}", "channel", c.name, money(c.cac));
+    }
+    for (flag, value) in &imported.options {
+        println!("  {:<17} {}", flag.replace('-', "_"), precision::fixed(*value, 1));
+    }
+    println!("\nRun with: ltgp_cac_calculator {}", imported.flags());
+    let Some(path) = &output.out else { return };
+    let name = output.name.as_deref().unwrap_or(source);
+    match std::fs::write(path, imported.scenario(name, source)) {
+        Ok(()) => println!("\nScenario written to {}.", path),
+        Err(e) => {
+            eprintln!("Error: could not write {}: {}", path, e);
+            std::process::exit(1);
+        }
+    }
+}
+
+pub fn run(args: &ImportArgs) {
+    let importer = args.source.importer();
+    let _span = tracing::info_span!("import", source = importer.name()).entered();
+    match importer.import() {
+        Ok(imported) => {
+            tracing::info!("imported");
+            finish(&imported, importer.name(), importer.output())
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(2);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+// Terminal-aware layout: detects the output width and reflows prose to fit it, keeping bullets and
+// numbered items hanging so wrapped lines stay readable. Below 80 columns reports switch to their
+// compact form. When stdout isn't a terminal and COLUMNS isn't set, text is left unwrapped so
+// piped output stays stable. Key lines carry a severity icon (✅ ⚠️ 🔶 🛑) unless `--no-icons`.
+
+use std::sync::OnceLock;
+
+use terminal_size::{terminal_size, Width};
+
+use crate::severity::Severity;
+
+/// Below this many columns, reports use their compact layout.
+pub const COMPACT_BELOW: usize = 80;
+
+/// Narrowest width wrapping is attempted at; anything tighter wraps at this anyway.
+const MIN_WIDTH: usize = 40;
+
+static WIDTH: OnceLock<Option<usize>> = OnceLock::new();
+static ICONS: OnceLock<bool> = OnceLock::new();
+
+/// Output width in columns: the terminal's, else `COLUMNS`, else None (don't wrap).
+pub fn width() -> Option<usize> {
+    *WIDTH.get_or_init(|| {
+        terminal_size().map(|(Width(w), _)| w as usize).or_else(|| std::env::var("COLUMNS").ok().and_then(|c| c.trim().parse().ok())).filter(|w| *w > 0).map(|w: usize| w.max(MIN_WIDTH))
+    })
+}
+
+pub fn compact() -> bool {
+    width().is_some_and(|w| w < COMPACT_BELOW)
+}
+
+/// Greedy word wrap into lines of at most `width` characters.
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() { line.push(' '); }
+        line.push_str(word);
+    }
+    if !line.is_empty() { lines.push(line); }
+    lines
+}
+
+/// Leading indent plus any bullet (`-`, `•`, `1.`) a wrapped line should hang under.
+fn hanging_prefix(line: &str) -> &str {
+    let indent = line.len() - line.trim_start().len();
+    let rest = &line[indent..];
+    let marker = if rest.starts_with("- ") || rest.starts_with("• ") {
+        rest.find(' ').map(|n| n + 1).unwrap_or(0)
+    } else {
+        let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digits > 0 && rest[digits..].starts_with(". ") { digits + 2 } else { 0 }
+    };
+    &line[..indent + marker]
+}
+
+/// Rewraps each line longer than `width`, continuing under its bullet or indent.
+pub fn reflow(text: &str, width: usize) -> String {
+    text.lines().flat_map(|line| {
+        if line.chars().count() <= width {
+            return vec![line.to_string()];
+        }
+        let prefix = hanging_prefix(line);
+        let pad = " ".repeat(prefix.chars().count());
+        let body_width = width.saturating_sub(pad.len()).max(MIN_WIDTH / 2);
+        wrap(&line[prefix.len()..], body_width).into_iter().enumerate().map(|(n, l)| format!("{}{}", if n == 0 { prefix } else { &pad }, l)).collect()
+    }).collect::<Vec<_>>().join("\n")
+}
+
+/// `reflow` at the detected width, or unchanged when there is none.
+pub fn fit(text: &str) -> String {
+    match width() {
+        Some(w) => reflow(text, w),
+        None => text.to_string(),
+    }
+}
+
+/// `println!` for prose lines: fits the formatted line to the terminal width.
+#[macro_export]
+macro_rules! wrapln {
+    ($($arg:tt)*) => {
+        println!("{}", $crate::layout::fit(&format!($($arg)*)))
+    };
+}
+pub use wrapln;
+
+/// Turns severity icons off for the rest of the run (`--no-icons`).
+pub fn set_icons(on: bool) {
+    let _ = ICONS.set(on);
+}
+
+/// The icon for `severity` followed by a space, or nothing when icons are off.
+pub fn icon(severity: Severity) -> &'static str {
+    if *ICONS.get_or_init(|| true) { severity.icon() } else { "" }
+}
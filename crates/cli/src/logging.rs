@@ -0,0 +1,40 @@
+// Diagnostic logs: `tracing` spans and events from the importers, batch runs, simulations, and
+// servers, written to stderr so they never mix with a report, JSON, or a JSON-RPC reply on stdout.
+// Only warnings and errors show unless `--log-level` asks for more; `serve` logs at info so a
+// deployment records every request without extra flags:
+//
+//   ltgp_cac_calculator batch rows.csv --log-level debug
+//   2026-01-05T09:12:44.120Z DEBUG batch{source="rows.csv"}: ltgp_cac_calculator::batch: row evaluated id=acme ratio=4.0 quadrant="Capital-Intensive Trap"
+
+use std::io::IsTerminal;
+
+use clap::ValueEnum;
+use tracing::level_filters::LevelFilter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LogLevel {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    fn filter(self) -> LevelFilter {
+        match self {
+            LogLevel::Off => LevelFilter::OFF,
+            LogLevel::Error => LevelFilter::ERROR,
+            LogLevel::Warn => LevelFilter::WARN,
+            LogLevel::Info => LevelFilter::INFO,
+            LogLevel::Debug => LevelFilter::DEBUG,
+            LogLevel::Trace => LevelFilter::TRACE,
+        }
+    }
+}
+
+/// Sends everything at `level` and above to stderr for the rest of the run.
+pub fn init(level: LogLevel) {
+    let _ = tracing_subscriber::fmt().with_max_level(level.filter()).with_writer(std::io::stderr).with_ansi(std::io::stderr().is_terminal()).try_init();
+}
@@ -0,0 +1,1000 @@
+// Rust CLI LTGP:CAC + CAC/CFA Decision Tree Calculator (Human-Readable)
+// Now supports an interactive guided form (use --interactive or run with no args).
+// Build & run examples:
+//   cargo run -- --cac 500.0 --cfa 200.0 --ltgp 2500.0 --early-gp-rate 50.0 --period days
+//   cargo run -- --interactive
+
+use clap::{Parser, Subcommand, ValueEnum};
+use std::io::{self, Write};
+
+mod ads;
+mod accounting;
+mod airtable;
+mod attribution;
+mod audit;
+mod batch;
+mod benchmark;
+mod channel;
+mod chart_data;
+mod clock;
+mod cohort;
+mod collections;
+mod compare;
+mod config;
+mod crm;
+mod dashboard;
+mod export;
+mod format;
+mod google_ads;
+mod grow;
+mod guard_rails;
+mod health;
+mod heatmap;
+mod history;
+mod html;
+mod hubspot;
+mod import;
+mod layout;
+mod logging;
+mod meta_ads;
+mod notion;
+mod notify;
+mod payback_curve;
+mod plan;
+mod plot;
+mod pricing;
+mod quickbooks;
+mod quality;
+mod radar;
+mod recommend;
+mod report;
+mod retention;
+mod saas_metrics;
+mod salesforce;
+mod scaling;
+mod scenario;
+mod scenario_chart;
+mod schedule;
+mod script;
+mod serve;
+mod severity;
+mod sheets;
+mod shopify;
+mod stress;
+mod stripe;
+mod sweep;
+mod theme;
+mod timeline;
+mod uncertainty;
+mod waterfall;
+mod webhook;
+mod xero;
+
+use ltgp_core::{evaluation, framework, locale, metric, period, precision, rules, simulation, tree, units};
+#[cfg(feature = "server")]
+use ltgp_server::{api, grpc, mcp, rpc};
+
+use channel::Channel;
+use chart_data::ChartData;
+use config::Config;
+use evaluation::{parse_money_like, Field, Inputs};
+use framework::Framework;
+use health::{MrrMovements, RuleOf40, ScoreWeights};
+use locale::Locale;
+use logging::LogLevel;
+use period::Period;
+use precision::Rounding;
+use quality::{Quality, Source};
+use report::EvaluationResult;
+use scaling::{CacElasticity, SpendCurve};
+use scenario::non_negative_or_exit;
+use stress::StressPreset;
+use theme::Theme;
+use uncertainty::{Estimate, Uncertainty};
+
+/// Human-readable calculator that evaluates unit economics and cash dynamics.
+#[derive(Parser, Debug)]
+#[command(author, version, about = "LTGP:CAC calculator with an interactive guided form.", long_about = None)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Decision playbook: thresholds, verdicts, and terminology to evaluate with
+    #[arg(long, value_enum, global = true, default_value_t = Framework::Hormozi)]
+    framework: Framework,
+
+    /// YAML file of verdict rules to use instead of the built-in verdicts
+    #[arg(long, global = true)]
+    rules: Option<String>,
+
+    /// YAML decision tree to place evaluations with instead of the built-in CAC/CFA quadrants
+    #[arg(long, global = true)]
+    tree: Option<String>,
+
+    /// Leave the ✅/⚠️/🛑 status icons off key report lines (for plain terminals and logs)
+    #[arg(long, global = true, default_value_t = false)]
+    no_icons: bool,
+
+    /// Move out-of-range inputs (a negative CFA, say) to the nearest valid value with a warning, instead of stopping
+    #[arg(long, global = true, default_value_t = false)]
+    clamp: bool,
+
+    /// Decimal places for every figure in every format (default: 2 for money and ratios, 1 for days and months)
+    #[arg(long, global = true, value_parser = clap::value_parser!(u8).range(0..=precision::MAX_PLACES as i64))]
+    precision: Option<u8>,
+
+    /// How ties round at the display precision [default: half-up]
+    #[arg(long, value_enum, global = true)]
+    rounding: Option<Rounding>,
+
+    /// How typed amounts are written, as a language tag: de reads 1.234,56, fr 1 234,56, de-CH 1'234.56 [default: en]
+    #[arg(long, global = true)]
+    locale: Option<Locale>,
+
+    /// Timezone results are timestamped in: an IANA name such as America/New_York, or local [default: UTC]
+    #[arg(long, global = true)]
+    timezone: Option<String>,
+
+    /// Diagnostic logs on stderr at this level and above [default: warn, or info for serve]
+    #[arg(long, value_enum, global = true)]
+    log_level: Option<LogLevel>,
+
+    /// TOML config file (default: ltgp.toml in the working directory, if present)
+    #[arg(long, global = true)]
+    config: Option<String>,
+
+    /// Color theme for terminal, HTML, and SVG output: light, dark, high-contrast, or one from the config file
+    #[arg(long, global = true)]
+    theme: Option<String>,
+
+    /// Tag recorded with results pushed to Airtable; repeat for each
+    #[arg(long = "tag", global = true)]
+    tags: Vec<String>,
+
+    /// Don't push results to the Airtable table in the config file
+    #[arg(long, global = true, default_value_t = false)]
+    no_airtable: bool,
+
+    /// Post a summary of each evaluation or batch here, configured in the config file; repeat for each
+    #[arg(long, value_enum, global = true)]
+    notify: Vec<notify::Notify>,
+
+    /// POST each evaluation or batch as signed JSON to this URL (secret in LTGP_WEBHOOK_SECRET)
+    #[arg(long, global = true)]
+    webhook: Option<String>,
+
+    /// Append each evaluation, with who ran it and how, as a JSON line to this file
+    #[arg(long, global = true)]
+    audit_log: Option<String>,
+
+    /// Launch an interactive guided form to enter inputs
+    #[arg(long, short = 'i', default_value_t = false)]
+    interactive: bool,
+
+    /// Answer JSON-RPC 2.0 requests on stdin, one per line, until it closes
+    #[arg(long, default_value_t = false, conflicts_with = "interactive")]
+    rpc: bool,
+
+    /// Serve the calculator, solver, and simulators as Model Context Protocol tools on stdin and stdout
+    #[arg(long, default_value_t = false, conflicts_with_all = ["interactive", "rpc"])]
+    mcp: bool,
+
+    /// How much it costs you to acquire a client (CAC) in dollars; "500 ± 80" adds uncertainty
+    #[arg(long)]
+    cac: Option<Estimate>,
+
+    /// How much money the client gives you upfront (CFA) in dollars; "200 ± 50" adds uncertainty
+    #[arg(long)]
+    cfa: Option<Estimate>,
+
+    /// Lifetime Gross Profit you expect from this client (LTGP) in dollars; "2500 ± 400" adds uncertainty
+    #[arg(long)]
+    ltgp: Option<Estimate>,
+
+    /// How much profit you earn from this client per period at the start
+    #[arg(long, value_parser = locale::number)]
+    early_gp_rate: Option<f64>,
+
+    /// Period unit for the early gross profit rate and payback output
+    #[arg(long, value_enum, ignore_case = true)]
+    period: Option<Period>,
+
+    /// Consider CAC 'low' if CAC < threshold_fraction * LTGP (e.g., 0.10 = 10%)
+    #[arg(long, value_parser = locale::number)]
+    low_cac_fraction: Option<f64>,
+
+    /// Consider CFA 'high' if CFA ≥ threshold_fraction * CAC (e.g., 0.50 = 50%)
+    #[arg(long, value_parser = locale::number, default_value_t = evaluation::DEFAULT_HIGH_CFA_FRACTION)]
+    high_cfa_fraction: f64,
+
+    /// Consider CAC 'low' at or below this many dollars (overrides --low-cac-fraction)
+    #[arg(long, value_parser = locale::number)]
+    low_cac_max: Option<f64>,
+
+    /// Consider CFA 'high' at or above this many dollars (overrides --high-cfa-fraction)
+    #[arg(long, value_parser = locale::number)]
+    high_cfa_min: Option<f64>,
+
+    /// Percent CAC rises per volume step as you scale (e.g., 2.0 = +2% per step)
+    #[arg(long, value_parser = locale::number)]
+    cac_elasticity: Option<f64>,
+
+    /// Additional customers per month that make up one elasticity step
+    #[arg(long, value_parser = locale::number, default_value_t = 100.0)]
+    elasticity_step: f64,
+
+    /// Customers you acquire per month today (anchors the scale check)
+    #[arg(long, value_parser = locale::number)]
+    monthly_volume: Option<f64>,
+
+    /// CSV of historical `spend,customers` per period, used to fit a marginal CAC curve
+    #[arg(long)]
+    spend_history: Option<String>,
+
+    /// Acquisition channel as name:cac[:capacity per month]; repeat for each channel
+    #[arg(long = "channel")]
+    channels: Vec<Channel>,
+
+    /// How the CAC figure was obtained
+    #[arg(long, value_enum)]
+    cac_source: Option<Source>,
+
+    /// How the CFA figure was obtained
+    #[arg(long, value_enum)]
+    cfa_source: Option<Source>,
+
+    /// How the LTGP figure was obtained
+    #[arg(long, value_enum)]
+    ltgp_source: Option<Source>,
+
+    /// How the early gross profit rate was obtained
+    #[arg(long, value_enum)]
+    early_gp_source: Option<Source>,
+
+    /// Observed average gross profit per acquired customer for each month of a live cohort, e.g. 120,110,104
+    #[arg(long, value_delimiter = ',')]
+    cohort_gp: Vec<f64>,
+
+    /// Monthly customer churn in percent, to project retention and check the LTGP assumption
+    #[arg(long, value_parser = locale::number, conflicts_with = "retention")]
+    churn: Option<f64>,
+
+    /// Share of acquired customers still active in each month since acquisition, e.g. 1,0.82,0.74,0.7
+    #[arg(long, value_delimiter = ',')]
+    retention: Vec<f64>,
+
+    /// Monthly acquisition budget in dollars, for the acquisition plan
+    #[arg(long, value_parser = locale::number)]
+    monthly_budget: Option<f64>,
+
+    /// Cash on hand today, for the growth ceiling and runway
+    #[arg(long, value_parser = locale::number)]
+    starting_cash: Option<f64>,
+
+    /// Monthly fixed costs outside of acquisition (payroll, rent, tools), for runway
+    #[arg(long, value_parser = locale::number)]
+    fixed_burn: Option<f64>,
+
+    /// Minimum cash balance you are willing to hold
+    #[arg(long, value_parser = locale::number, default_value_t = 0.0)]
+    cash_floor: f64,
+
+    /// Months to project cash flows over
+    #[arg(long, default_value_t = 36)]
+    horizon_months: usize,
+
+    /// Cost to fulfill one customer over their first 30 days, in dollars, for the 2x rule
+    #[arg(long, value_parser = locale::number, default_value_t = 0.0)]
+    cogs: f64,
+
+    /// Gross cash actually collected per customer in their first 30 days (otherwise estimated)
+    #[arg(long, value_parser = locale::number)]
+    collections_30d: Option<f64>,
+
+    /// Health score weights: balanced | bootstrapped | funded, or e.g. "ratio=20,payback=50"
+    #[arg(long, default_value = "balanced")]
+    score_weights: ScoreWeights,
+
+    /// Year-over-year revenue growth in percent, for the Rule of 40
+    #[arg(long, value_parser = locale::number, allow_hyphen_values = true)]
+    revenue_growth: Option<f64>,
+
+    /// Operating margin in percent (negative if burning), for the Rule of 40
+    #[arg(long, value_parser = locale::number, allow_hyphen_values = true)]
+    operating_margin: Option<f64>,
+
+    /// New MRR added this period in dollars, for the quick ratio
+    #[arg(long, value_parser = locale::number)]
+    new_mrr: Option<f64>,
+
+    /// Expansion MRR from existing customers this period, for the quick ratio
+    #[arg(long, value_parser = locale::number, default_value_t = 0.0)]
+    expansion_mrr: f64,
+
+    /// MRR lost to downgrades this period, for the quick ratio
+    #[arg(long, value_parser = locale::number, default_value_t = 0.0)]
+    contraction_mrr: f64,
+
+    /// MRR lost to cancellations this period, for the quick ratio
+    #[arg(long, value_parser = locale::number, default_value_t = 0.0)]
+    churned_mrr: f64,
+
+    /// Compare against typical peers for a business model, e.g. saas-smb, ecommerce, services
+    #[arg(long)]
+    benchmark: Option<String>,
+
+    /// URL of a JSON benchmark dataset to use instead of the bundled one
+    #[arg(long)]
+    benchmark_url: Option<String>,
+
+    /// Also write the radar chart against the --benchmark peers as an SVG image to this path
+    #[arg(long)]
+    radar_svg: Option<String>,
+
+    /// Output format: text, summary (one line with a cash sparkline), oneline (key=value pairs), html (interactive page with sliders and charts), or json; only text adds the other sections
+    #[arg(long, default_value = "text")]
+    format: String,
+
+    /// Print only `ratio=… quadrant=… payback=… verdict=… severity=…` on one line (same as --format oneline)
+    #[arg(long, default_value_t = false, conflicts_with = "format")]
+    oneline: bool,
+
+    /// Draw the CAC/CFA quadrant chart with your position marked
+    #[arg(long, default_value_t = false)]
+    plot: bool,
+
+    /// Chart upfront cash and cumulative gross profit against CAC until breakeven
+    #[arg(long, default_value_t = false)]
+    payback_chart: bool,
+
+    /// Plot cumulative gross profit against the net outlay over time, marking breakeven and the lifetime horizon
+    #[arg(long, default_value_t = false)]
+    payback_curve: bool,
+
+    /// Also write the payback curve as an SVG image to this path
+    #[arg(long)]
+    payback_curve_svg: Option<String>,
+
+    /// Draw a dated timeline of CAC spend, acquisition, CFA receipt, and breakeven
+    #[arg(long, default_value_t = false)]
+    timeline: bool,
+
+    /// Also write the acquisition-to-payback timeline as an SVG image to this path
+    #[arg(long)]
+    timeline_svg: Option<String>,
+
+    /// Days from spending CAC to the customer signing (sales cycle), for the timeline
+    #[arg(long, value_parser = locale::number, default_value_t = 0.0)]
+    sales_cycle_days: f64,
+
+    /// Days after signing until the upfront cash (CFA) arrives, for the timeline
+    #[arg(long, value_parser = locale::number, default_value_t = 0.0)]
+    cfa_delay_days: f64,
+
+    /// Date CAC is spent (YYYY-MM-DD) for the timeline; defaults to today
+    #[arg(long)]
+    spend_date: Option<String>,
+
+    /// Draw the per-customer cash-flow waterfall: CAC out, CFA in, gross profit in, lifetime net
+    #[arg(long, default_value_t = false)]
+    waterfall: bool,
+
+    /// Also write the cash-flow waterfall as an SVG image to this path
+    #[arg(long)]
+    waterfall_svg: Option<String>,
+
+    /// Write the data behind every chart drawn in this run to a CSV file (`chart,series,x,y`)
+    #[arg(long)]
+    chart_data: Option<String>,
+
+    /// Print the active decision policy as a diagram instead of the report; mermaid highlights the path your numbers took
+    #[arg(long, value_enum)]
+    export_tree: Option<tree::Export>,
+
+    /// Append this run (date, inputs, ratio, payback) to a history file for `trend`
+    #[arg(long)]
+    save_run: Option<String>,
+
+    /// Read name, CAC, CFA, LTGP, early gross profit, and period from the first row of a Google Sheets range (URL!A2:F2); flags given here win
+    #[arg(long, value_parser = sheets::Sheet::parse)]
+    from_sheet: Option<sheets::Sheet>,
+
+    /// Append this run's inputs and results as a row to a Google Sheet (URL!Results)
+    #[arg(long, value_parser = sheets::Sheet::parse)]
+    to_sheet: Option<sheets::Sheet>,
+
+    /// Rhai script that receives the computed result and adds metrics or overrides guidance
+    #[arg(long)]
+    script: Option<String>,
+
+    /// Apply a canned set of shocks and report whether the economics still clear
+    #[arg(long, value_enum)]
+    stress: Option<StressPreset>,
+
+    /// Number of Monte Carlo draws used when inputs carry uncertainty
+    #[arg(long, default_value_t = 10_000)]
+    simulations: usize,
+
+    /// Random seed for simulations, so repeated runs give identical results
+    #[arg(long, default_value_t = 42)]
+    seed: u64,
+
+    /// Also write histograms of the simulated LTGP:CAC and payback as an SVG image to this path
+    #[arg(long)]
+    histogram_svg: Option<String>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Evaluate every row of a CSV file and plot them all on the CAC-vs-CFA-coverage plane
+    Batch(batch::BatchArgs),
+
+    /// Evaluate two or more scenario files and print them side by side with a chart
+    Compare(compare::CompareArgs),
+
+    /// Compare two pricing structures under the same CAC
+    ComparePricing(pricing::ComparePricingArgs),
+
+    /// One-screen capital efficiency panel: unit economics, burn multiple, magic number, runway
+    Dashboard(dashboard::DashboardArgs),
+
+    /// Publish a run's report to a documentation tool
+    Export(export::ExportArgs),
+
+    /// Simulate reinvesting collected cash into acquisition month over month
+    Grow(grow::GrowArgs),
+
+    /// Derive inputs from billing and other business systems, optionally saving them as a scenario
+    Import(import::ImportArgs),
+
+    /// Keep running: serve the REST API or gRPC service, re-evaluate the [schedule] job from the config file on a cron schedule, or any mix
+    Serve(serve::ServeArgs),
+
+    /// Re-evaluate across a range of one input
+    Sweep(sweep::SweepArgs),
+
+    /// Show how ratio, CAC, and payback moved across runs saved with --save-run
+    Trend(history::TrendArgs),
+}
+
+fn read_line(prompt: &str) -> io::Result<String> {
+    print!("{}", prompt);
+    io::stdout().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
+}
+
+fn print_field_context(title: &str, what: &str, where_how: &str, why: &str, who: &str) {
+    println!("\n{}", title);
+    println!("• What it is: {}", what);
+    println!("• Where/how to get it: {}", where_how);
+    println!("• Why it matters: {}", why);
+    println!("• Who it applies to: {}", who);
+}
+
+fn prompt_f64_with_context(title: &str, what: &str, where_how: &str, why: &str, who: &str, prompt: &str, default: Option<f64>) -> f64 {
+    loop {
+        print_field_context(title, what, where_how, why, who);
+        let default_hint = default.map(|d| format!(" [default: {:.2}]", d)).unwrap_or_default();
+        let input = read_line(&format!("{}{}: ", prompt, default_hint)).unwrap_or_default();
+        if input.is_empty() && let Some(d) = default { return d; }
+        match parse_money_like(&input) {
+            Some(v) if v.is_finite() && v >= 0.0 => return v,
+            Some(v) if v < 0.0 => println!("Please enter a number of at least 0, not {}.", v),
+            _ => println!("Please enter a valid number (e.g., 500, {}).", locale::active().example()),
+        }
+    }
+}
+
+/// Like `prompt_f64_with_context`, but also accepts "500 ± 80" to record uncertainty.
+fn prompt_estimate_with_context(title: &str, what: &str, where_how: &str, why: &str, who: &str, prompt: &str, default: Option<f64>) -> Estimate {
+    loop {
+        print_field_context(title, what, where_how, why, who);
+        let default_hint = default.map(|d| format!(" [default: {:.2}]", d)).unwrap_or_default();
+        let input = read_line(&format!("{}{}: ", prompt, default_hint)).unwrap_or_default();
+        if input.is_empty() && let Some(d) = default { return Estimate::exact(d); }
+        match input.parse::<Estimate>() {
+            Ok(e) if e.mean >= 0.0 => return e,
+            Ok(e) => println!("Please enter an amount of at least 0, not {}.", e.mean),
+            Err(_) => println!("Please enter a valid number (e.g., 500, {}, or 500 ± 80 if you're unsure).", locale::active().example()),
+        }
+    }
+}
+
+/// Asks with `prompt` until the answer is inside the config's guard rail for `field`.
+fn guarded<T>(field: Field, value: impl Fn(&T) -> f64, prompt: impl Fn() -> T) -> T {
+    loop {
+        let answer = prompt();
+        match guard_rails::problem(field, value(&answer)) {
+            Some(problem) => println!("{}, set by your organization's config. Please check the figure and enter it again.", problem),
+            None => return answer,
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn prompt_choice_with_context(title: &str, what: &str, where_how: &str, why: &str, who: &str, prompt: &str, choices: &[&str], default: &str) -> String {
+    loop {
+        println!("\n{}", title);
+        println!("• What it is: {}", what);
+        println!("• Where/how to choose: {}", where_how);
+        println!("• Why it matters: {}", why);
+        println!("• Who it applies to: {}", who);
+        println!("Options: {}", choices.join(", "));
+        let input = read_line(&format!("{} [default: {}]: ", prompt, default)).unwrap_or_default();
+        let choice = if input.trim().is_empty() { default.to_string() } else { input.trim().to_lowercase() };
+        if choices.iter().any(|c| c.eq_ignore_ascii_case(&choice)) { return choice; }
+        println!("Please enter one of: {}", choices.join(", "));
+    }
+}
+
+fn prompt_source(input: &str) -> Source {
+    let choice = prompt_choice_with_context(
+        &format!("Data Quality — how did you get the {} figure?", input),
+        "Whether the number comes from real data, a model, or a best guess.",
+        "Measured: pulled from transactions or reports. Modeled: projected from cohorts or benchmarks. Guessed: an educated estimate.",
+        "Verdicts that hinge on guessed numbers deserve less confidence.",
+        "Anyone presenting these results to others.",
+        "Choose one of: measured, modeled, guessed",
+        &["measured", "modeled", "guessed"],
+        "modeled",
+    );
+    Source::from_str(&choice, true).unwrap_or(Source::Modeled)
+}
+
+fn maybe_interactive_collect(args: &Args) -> (Inputs, Uncertainty, Quality) {
+    // Defaults when prompting interactively
+    let default_low_frac = 0.10_f64;
+
+    // If interactive flag is set OR any required value is missing, prompt.
+    let need_interactive = args.interactive
+        || args.cac.is_none()
+        || args.ltgp.is_none()
+        || args.cfa.is_none()
+        || args.early_gp_rate.is_none()
+        || args.period.is_none()
+        || (args.low_cac_fraction.is_none() && args.low_cac_max.is_none());
+
+    if need_interactive {
+        println!("\nWelcome! This guided form will help you estimate growth economics.\nYou can press Enter to accept defaults where shown.\n");
+
+        let cac = args.cac.unwrap_or_else(|| guarded(Field::Cac, |e: &Estimate| e.mean, || prompt_estimate_with_context(
+            "Customer Acquisition Cost (CAC) — dollars per new customer",
+            "The average fully-loaded cost to acquire one new customer (ads, sales commissions, SDR/AE time, agency fees, attributable tooling).",
+            "From finance or growth analytics: take sales+marketing spend for a period and divide by the number of new customers acquired in that period.",
+            "Determines how much cash you invest upfront and affects payback and ROI.",
+            "Any business acquiring customers (SaaS, e‑commerce, services, marketplaces).",
+            "Enter CAC in dollars (or 500 ± 80 if unsure)",
+            None,
+        )));
+
+        let cfa = args.cfa.unwrap_or_else(|| guarded(Field::Cfa, |e: &Estimate| e.mean, || prompt_estimate_with_context(
+            "Customer Funds Upfront (CFA) — upfront cash from the customer",
+            "Cash collected at or before acquisition: deposits, setup fees, prepayments, first invoice paid upfront.",
+            "From pricing/billing: look at typical cash collected at purchase or at contract signature.",
+            "Offsets CAC, lowering your net cash outlay and risk while speeding up payback.",
+            "Businesses that collect money upfront. If you don’t, enter 0.",
+            "Enter CFA in dollars (or 200 ± 50 if unsure)",
+            Some(0.0),
+        )));
+
+        let ltgp = args.ltgp.unwrap_or_else(|| guarded(Field::Ltgp, |e: &Estimate| e.mean, || prompt_estimate_with_context(
+            "Lifetime Gross Profit (LTGP) — total gross profit per customer",
+            "Sum of (revenue − cost of goods sold) you expect over the customer’s lifetime.",
+            "From cohort LTV or unit economics: monthly gross profit × expected lifetime (months), or lifetime revenue × gross margin.",
+            "Primary measure of value; used to judge whether CAC is justified.",
+            "The segment/cohort you’re modeling. Use a conservative estimate.",
+            "Enter LTGP in dollars (or 2500 ± 400 if unsure)",
+            None,
+        )));
+
+        let early_gp_rate = args.early_gp_rate.unwrap_or_else(|| guarded(Field::EarlyGpRate, |v: &f64| *v, || prompt_f64_with_context(
+            "Early Gross Profit Rate — profit earned per chosen period at the start",
+            "Average gross profit per chosen period (e.g., per week) in the early customer lifecycle.",
+            "From recent transactions: compute average contribution per period during the first few periods.",
+            "Used to estimate how quickly you recover your upfront cash (payback period).",
+            "Applies to your early lifecycle; if unknown, you can leave it blank to skip payback.",
+            "Enter early gross profit per period",
+            Some(0.0),
+        )));
+
+        let period = args.period.unwrap_or_else(|| prompt_choice_with_context(
+            "Period Unit — time unit used for the payback estimate",
+            "The unit of time you want the payback estimate expressed in.",
+            "Choose the unit that matches how you measure early profit (e.g., if early GP is weekly, choose weeks). Months and years convert to days at calendar averages.",
+            "Ensures the payback figure is in a meaningful unit.",
+            "Anyone estimating payback.",
+            "Choose one of: days, weeks, months, years",
+            &Period::ALL.map(|p| p.name()),
+            Period::default().name(),
+        ).parse().unwrap_or_default());
+
+        let low_cac_fraction = args.low_cac_fraction.or(args.low_cac_max.map(|_| default_low_frac)).unwrap_or_else(|| guarded(Field::LowCacFraction, |v: &f64| *v, || prompt_f64_with_context(
+            "Low CAC Threshold — fraction of LTGP considered ‘low CAC’",
+            "A heuristic boundary: CAC < (threshold × LTGP).",
+            "Use 0.10 (10%) by default; adjust to your risk tolerance and capital availability.",
+            "Affects the quadrant label and qualitative guidance.",
+            "Anyone using the quadrant classification.",
+            "Enter threshold as a fraction (e.g., 0.10 for 10%)",
+            Some(default_low_frac),
+        )));
+
+        // Only ask where numbers came from for the ones entered in this form.
+        let quality = Quality {
+            cac: args.cac_source.or_else(|| args.cac.is_none().then(|| prompt_source("CAC"))),
+            cfa: args.cfa_source.or_else(|| args.cfa.is_none().then(|| prompt_source("CFA"))),
+            ltgp: args.ltgp_source.or_else(|| args.ltgp.is_none().then(|| prompt_source("LTGP"))),
+            early_gp: args.early_gp_source.or_else(|| (args.early_gp_rate.is_none() && early_gp_rate > 0.0).then(|| prompt_source("early gross profit"))),
+        };
+
+        let inputs = Inputs { cac: cac.mean, cfa: cfa.mean, ltgp: ltgp.mean, early_gp_rate, period, low_cac_fraction, high_cfa_fraction: args.high_cfa_fraction, low_cac_max: args.low_cac_max, high_cfa_min: args.high_cfa_min };
+        (inputs, Uncertainty { cac_sd: cac.sd, cfa_sd: cfa.sd, ltgp_sd: ltgp.sd }, quality)
+    } else {
+        // Non-interactive path: all values provided
+        let (cac, cfa, ltgp) = (args.cac.unwrap(), args.cfa.unwrap_or(Estimate::exact(0.0)), args.ltgp.unwrap());
+        let inputs = Inputs {
+            cac: cac.mean,
+            cfa: cfa.mean,
+            ltgp: ltgp.mean,
+            early_gp_rate: args.early_gp_rate.unwrap_or(0.0),
+            period: args.period.unwrap_or_default(),
+            low_cac_fraction: args.low_cac_fraction.unwrap_or(0.10),
+            high_cfa_fraction: args.high_cfa_fraction,
+            low_cac_max: args.low_cac_max,
+            high_cfa_min: args.high_cfa_min,
+        };
+        let quality = Quality { cac: args.cac_source, cfa: args.cfa_source, ltgp: args.ltgp_source, early_gp: args.early_gp_source };
+        (inputs, Uncertainty { cac_sd: cac.sd, cfa_sd: cfa.sd, ltgp_sd: ltgp.sd }, quality)
+    }
+}
+
+/// The locale from `--locale`, else the config file's `locale`, found before the flags are parsed
+/// since the amounts in them are read in it. Anything unreadable is left for the full parse to
+/// report.
+fn early_locale() -> Locale {
+    let argv: Vec<String> = std::env::args().collect();
+    let flag = |name: &str| {
+        argv.iter().enumerate().find_map(|(i, arg)| match arg.strip_prefix(name) {
+            Some("") => argv.get(i + 1).cloned(),
+            Some(value) => value.strip_prefix('=').map(str::to_string),
+            None => None,
+        })
+    };
+    let tag = flag("--locale").or_else(|| Config::load(flag("--config").as_deref()).ok()?.locale);
+    tag.and_then(|t| t.parse().ok()).unwrap_or_default()
+}
+
+fn main() {
+    locale::set(early_locale());
+    let mut args = Args::parse();
+
+    logging::init(args.log_level.unwrap_or(if matches!(args.command, Some(Command::Serve(_))) { LogLevel::Info } else { LogLevel::Warn }));
+    layout::set_icons(!args.no_icons);
+    scenario::set_clamp(args.clamp);
+    let config = Config::load(args.config.as_deref()).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(2);
+    });
+    precision::set(args.precision.map(usize::from).or(config.precision), args.rounding.or(config.rounding).unwrap_or_default());
+    if args.locale.is_none() && let Some(Err(e)) = config.locale.as_deref().map(str::parse::<Locale>) {
+        eprintln!("Error: config locale: {}", e);
+        std::process::exit(2);
+    }
+    if let Err(e) = clock::install(args.timezone.as_deref().or(config.timezone.as_deref())) {
+        eprintln!("Error: {}", e);
+        std::process::exit(2);
+    }
+    let theme_name = args.theme.as_deref().or(config.theme.as_deref()).unwrap_or("light");
+    match Theme::resolve(theme_name, &config.themes) {
+        Ok(t) => theme::install(t),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(2);
+        }
+    }
+    if let Err(e) = guard_rails::install(config.guard_rails.clone().unwrap_or_default()) {
+        eprintln!("Error: {}", e);
+        std::process::exit(2);
+    }
+    audit::install(args.audit_log.clone().or(config.audit_log.clone()));
+    airtable::install(config.airtable.clone().filter(|_| !args.no_airtable), args.tags.clone());
+    if let Err(e) = notify::install(&args.notify, config.slack.clone()).and_then(|()| webhook::install(args.webhook.clone())) {
+        eprintln!("Error: {}", e);
+        std::process::exit(2);
+    }
+    if let Err(e) = framework::install(args.framework, args.rules.as_deref(), args.tree.as_deref()) {
+        eprintln!("Error: {}", e);
+        std::process::exit(2);
+    }
+
+    if args.rpc {
+        #[cfg(feature = "server")]
+        return rpc::run();
+        #[cfg(not(feature = "server"))]
+        serve::unavailable("--rpc");
+    }
+    if args.mcp {
+        #[cfg(feature = "server")]
+        return mcp::run();
+        #[cfg(not(feature = "server"))]
+        serve::unavailable("--mcp");
+    }
+
+    match &args.command {
+        Some(Command::Batch(cmd)) => return batch::run(cmd),
+        Some(Command::Compare(cmd)) => return compare::run(cmd),
+        Some(Command::ComparePricing(cmd)) => return pricing::run(cmd),
+        Some(Command::Dashboard(cmd)) => return dashboard::run(cmd),
+        Some(Command::Export(cmd)) => return export::run(cmd),
+        Some(Command::Grow(cmd)) => return grow::run(cmd),
+        Some(Command::Import(cmd)) => return import::run(cmd),
+        Some(Command::Serve(cmd)) => return serve::run(cmd, &config),
+        Some(Command::Sweep(cmd)) => return sweep::run(cmd),
+        Some(Command::Trend(cmd)) => return history::run(cmd),
+        None => {}
+    }
+
+    let mut sheet_name = None;
+    if let Some(sheet) = &args.from_sheet {
+        let rows = sheets::read(sheet).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(2);
+        });
+        if rows.len() > 1 {
+            eprintln!("Note: {} has {} rows; using the first (batch --from-sheet evaluates them all).", sheet.range, rows.len());
+        }
+        let row = rows.into_iter().next().unwrap_or_default();
+        args.cac = args.cac.or(row.cac);
+        args.cfa = args.cfa.or(row.cfa);
+        args.ltgp = args.ltgp.or(row.ltgp);
+        args.early_gp_rate = args.early_gp_rate.or(row.early_gp_rate);
+        args.period = args.period.take().or(row.period);
+        sheet_name = row.name;
+    }
+
+    let (inputs, uncertainty, quality) = maybe_interactive_collect(&args);
+    let inputs = scenario::checked(inputs, "").unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(2);
+    });
+    // The amounts beside the inputs can't be negative either; checked before anything prints.
+    args.cogs = non_negative_or_exit("cogs", args.cogs);
+    args.collections_30d = args.collections_30d.map(|c| non_negative_or_exit("collections-30d", c));
+    args.new_mrr = args.new_mrr.map(|c| non_negative_or_exit("new-mrr", c));
+    args.expansion_mrr = non_negative_or_exit("expansion-mrr", args.expansion_mrr);
+    args.contraction_mrr = non_negative_or_exit("contraction-mrr", args.contraction_mrr);
+    args.churned_mrr = non_negative_or_exit("churned-mrr", args.churned_mrr);
+    args.starting_cash = args.starting_cash.map(|c| non_negative_or_exit("starting-cash", c));
+    args.fixed_burn = args.fixed_burn.map(|c| non_negative_or_exit("fixed-burn", c));
+    let eval = evaluation::evaluate(&inputs);
+    let previous = args.save_run.as_deref().and_then(|path| history::read(path).ok()).and_then(|runs| runs.last().cloned());
+    if let Some(path) = &args.save_run && let Err(e) = history::append(path, &EvaluationResult::from(&eval)) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+    let script_output = args.script.as_deref().map(|path| script::run(path, &eval).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(2);
+    }));
+    if let Some(export) = args.export_tree {
+        println!("{}", tree::active().export(export, &eval));
+        return;
+    }
+    let verdict = script_output.as_ref().and_then(|o| o.guidance.as_deref()).unwrap_or(eval.verdict);
+    let result = EvaluationResult::new(&eval, verdict);
+    if let Some(sheet) = &args.to_sheet && let Err(e) = sheets::append(sheet, vec![sheets::result_row(sheet_name.as_deref().unwrap_or(""), &result)]) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+    if let Err(e) = audit::record(&[(sheet_name.as_deref().unwrap_or(""), &result)]) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+    if let Err(e) = airtable::push(vec![airtable::record(sheet_name.as_deref().unwrap_or(""), &result)]) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+    let name = sheet_name.as_deref().unwrap_or("");
+    if let Err(e) = notify::evaluation(name, &result, previous.as_ref()).and_then(|()| webhook::evaluation(name, &result)) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+    let registry = format::Registry::builtin();
+    let formatter = registry.get(if args.oneline { "oneline" } else { &args.format }).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(2);
+    });
+    println!("{}", formatter.render(&result));
+    if formatter.name() != "text" {
+        return;
+    }
+    if let (Some(path), Some(out)) = (&args.script, &script_output) {
+        script::print_script_metrics(path, out);
+    }
+
+    let mut chart_data = ChartData::new();
+
+    if args.plot {
+        plot::print_quadrant_plot(&eval);
+        plot::quadrant_chart_data(&eval, &mut chart_data);
+    }
+
+    if args.payback_chart {
+        plot::print_payback_chart(&eval);
+        plot::payback_chart_data(&eval, &mut chart_data);
+    }
+
+    if args.payback_curve {
+        payback_curve::print_payback_curve(&eval);
+    }
+    if args.payback_curve || args.payback_curve_svg.is_some() {
+        payback_curve::chart_data(&eval, &mut chart_data);
+    }
+
+    if let Some(path) = &args.payback_curve_svg {
+        match payback_curve::svg(&eval).map(|svg| std::fs::write(path, svg)) {
+            Some(Ok(())) => println!("\nPayback curve written to {}.", path),
+            Some(Err(e)) => {
+                eprintln!("Error: could not write {}: {}", path, e);
+                std::process::exit(1);
+            }
+            None => println!("\nPayback curve not written: provide --early-gp-rate."),
+        }
+    }
+
+    if args.timeline || args.timeline_svg.is_some() {
+        let start = args.spend_date.as_deref().map(|d| history::days_from_date(d).unwrap_or_else(|| {
+            eprintln!("Error: --spend-date `{}` is not a YYYY-MM-DD date", d);
+            std::process::exit(2);
+        }));
+        let t = timeline::Timeline::new(&inputs, args.sales_cycle_days, args.cfa_delay_days, start);
+        timeline::chart_data(&t, &mut chart_data);
+        if args.timeline {
+            timeline::print_timeline(&t);
+        }
+        if let Some(path) = &args.timeline_svg {
+            match std::fs::write(path, timeline::svg(&t)) {
+                Ok(()) => println!("\nTimeline written to {}.", path),
+                Err(e) => {
+                    eprintln!("Error: could not write {}: {}", path, e);
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
+
+    if args.waterfall {
+        waterfall::print_waterfall(&inputs);
+    }
+    if args.waterfall || args.waterfall_svg.is_some() {
+        waterfall::chart_data(&inputs, &mut chart_data);
+    }
+
+    if let Some(path) = &args.waterfall_svg {
+        match std::fs::write(path, waterfall::svg(&inputs)) {
+            Ok(()) => println!("\nWaterfall chart written to {}.", path),
+            Err(e) => {
+                eprintln!("Error: could not write {}: {}", path, e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    recommend::print_recommendations(&eval);
+
+    let first_month = collections::FirstMonth::new(&inputs, args.cogs, args.collections_30d);
+    collections::print_two_x_rule(&first_month);
+    collections::print_financing_window(&first_month);
+
+    let rule_of_40 = args.revenue_growth.zip(args.operating_margin).map(|(growth_pct, margin_pct)| RuleOf40 { growth_pct, margin_pct });
+    let mrr = args.new_mrr.map(|new| MrrMovements { new, expansion: args.expansion_mrr, contraction: args.contraction_mrr, churned: args.churned_mrr });
+    health::print_health_dashboard(&eval, args.score_weights, rule_of_40, mrr);
+
+    if let Some(name) = &args.benchmark {
+        let benchmarks = match &args.benchmark_url {
+            Some(url) => benchmark::fetch(url),
+            None => Ok(benchmark::bundled()),
+        };
+        match benchmarks.and_then(|all| benchmark::find(&all, name)) {
+            Ok(b) => {
+                benchmark::print_benchmark_report(&eval, &b, args.operating_margin);
+                let spokes = radar::spokes(&eval, &b, args.operating_margin);
+                radar::print_radar(&spokes, &b);
+                if spokes.len() >= 3 {
+                    radar::chart_data(&spokes, &mut chart_data);
+                }
+                if let Some(path) = &args.radar_svg && spokes.len() >= 3 {
+                    match std::fs::write(path, radar::svg(&spokes, &b)) {
+                        Ok(()) => println!("\nRadar chart written to {}.", path),
+                        Err(e) => {
+                            eprintln!("Error: could not write {}: {}", path, e);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+            }
+            Err(e) => println!("\nBenchmark could not be compared: {}.", e),
+        }
+    }
+
+    if !args.cohort_gp.is_empty() {
+        cohort::print_cohort_report(&inputs, uncertainty.ltgp_sd, &args.cohort_gp);
+    }
+
+    let retention_model = match args.churn {
+        Some(pct) => Some(retention::Model::Churn(pct / 100.0)),
+        None if !args.retention.is_empty() => Some(retention::Model::Curve(args.retention.clone())),
+        None => None,
+    };
+    if let Some(model) = &retention_model {
+        retention::print_retention_plot(&inputs, model);
+        retention::chart_data(&inputs, model, &mut chart_data);
+    }
+
+    let planned_cohort = args.monthly_volume.or_else(|| args.monthly_budget.filter(|_| inputs.cac > 0.0).map(|b| b / inputs.cac));
+    plan::print_working_capital(&inputs, planned_cohort, args.horizon_months.max(1));
+
+    if let Some(budget) = args.monthly_budget {
+        plan::print_budget_plan(&inputs, budget, args.horizon_months.max(1));
+    }
+
+    if let Some(cash) = args.starting_cash {
+        let capacity = if args.channels.is_empty() { None } else { channel::total_capacity(&args.channels) };
+        plan::print_growth_ceiling(&inputs, cash, args.horizon_months.max(1), capacity);
+    }
+
+    if let (Some(cash), Some(burn)) = (args.starting_cash, args.fixed_burn) {
+        plan::print_runway(&inputs, cash, burn, args.cash_floor, planned_cohort, args.horizon_months.max(1));
+    }
+
+    if let Some(preset) = args.stress {
+        stress::print_stress_report(&inputs, preset);
+    }
+
+    if !quality.is_empty() {
+        quality::print_quality_report(&inputs, &quality);
+    }
+
+    if !uncertainty.is_empty() {
+        let samples = uncertainty::print_uncertainty_report(&inputs, &uncertainty, args.simulations.max(1), args.seed);
+        uncertainty::chart_data(&samples, &mut chart_data);
+        if let Some(path) = &args.histogram_svg {
+            match std::fs::write(path, uncertainty::histogram_svg(&samples)) {
+                Ok(()) => println!("\nHistograms written to {}.", path),
+                Err(e) => {
+                    eprintln!("Error: could not write {}: {}", path, e);
+                    std::process::exit(1);
+                }
+            }
+        }
+    } else if args.histogram_svg.is_some() {
+        println!("\nHistograms not written: enter CAC, CFA, or LTGP as \"mean ± sd\" to simulate.");
+    }
+
+    if let Some(pct) = args.cac_elasticity {
+        let elasticity = CacElasticity { pct_per_step: pct, step: args.elasticity_step };
+        scaling::print_elasticity_report(&elasticity, inputs.cac, inputs.ltgp, eval.low_cac_thresh, args.monthly_volume);
+    }
+
+    if !args.channels.is_empty() {
+        channel::print_channel_report(&args.channels, inputs.ltgp, args.monthly_volume);
+    }
+
+    if let Some(path) = &args.spend_history {
+        let fitted = scaling::read_spend_history(path).and_then(|points| {
+            let current_spend = points.last().map(|p| p.0).unwrap_or_default();
+            SpendCurve::fit(&points).map(|curve| (curve, current_spend))
+        });
+        match fitted {
+            Ok((curve, current_spend)) => scaling::print_marginal_cac_report(&curve, current_spend, inputs.ltgp),
+            Err(e) => println!("\nMarginal CAC could not be fitted: {}.", e),
+        }
+    }
+
+    chart_data::save(&chart_data, args.chart_data.as_deref());
+
+    println!("\nNotes:");
+    println!("{}", layout::fit(" - A lifetime return ratio above 3 means clients are worth it in the long run.\n - If net outlay is zero, clients are financing their own acquisition.\n - Low CAC and High CFA together create the safest and fastest growth."));
+}
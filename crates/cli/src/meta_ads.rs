@@ -0,0 +1,158 @@
+// Meta Ads import (Facebook and Instagram): campaign spend and conversions, either from the
+// Marketing API's campaign-level insights over the period or from an Ads Manager report exported
+// as CSV, turned into blended and per-campaign CAC by `ads::report`. A conversion is whichever
+// action type `--action-type` names; the CSV's Results column counts each campaign's own goal.
+
+use std::collections::BTreeMap;
+
+use clap::Args;
+use serde::Deserialize;
+
+use crate::ads::{self, Campaign, Platform, SpendArgs};
+use crate::import::{self, Imported, Importer, OutputArgs};
+
+const API_VERSION: &str = "v21.0";
+const API_URL: &str = "https://graph.facebook.com";
+
+#[derive(Args, Debug)]
+pub struct MetaAdsArgs {
+    /// Campaign report exported from Ads Manager as CSV, instead of calling the API
+    #[arg(long)]
+    pub csv: Option<String>,
+
+    /// Ad account ID (`act_1234567890` or just the number)
+    #[arg(long)]
+    pub account_id: Option<String>,
+
+    /// Access token with ads_read (default: the META_ACCESS_TOKEN environment variable)
+    #[arg(long)]
+    pub access_token: Option<String>,
+
+    /// Action type counted as a conversion, e.g. purchase, lead, complete_registration (API only)
+    #[arg(long, default_value = "purchase")]
+    pub action_type: String,
+
+    /// Channel name for `--channel`
+    #[arg(long, default_value = "meta-ads")]
+    pub channel: String,
+
+    /// Graph API version
+    #[arg(long, default_value = API_VERSION)]
+    pub api_version: String,
+
+    /// API base URL, e.g. a mock server for trying the import out
+    #[arg(long, default_value = API_URL)]
+    pub api_url: String,
+
+    #[command(flatten)]
+    pub spend: SpendArgs,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Deserialize, Debug)]
+struct Page {
+    data: Vec<Insight>,
+    #[serde(default)]
+    paging: Option<Paging>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Paging {
+    next: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Insight {
+    campaign_name: String,
+    /// Amounts come back as strings in the account's currency.
+    spend: Option<String>,
+    #[serde(default)]
+    actions: Vec<Action>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Action {
+    action_type: String,
+    value: String,
+}
+
+/// Campaign insights for the period, following `paging.next` to the end.
+fn fetch(args: &MetaAdsArgs, account: &str) -> Result<Vec<Campaign>, String> {
+    let token = import::credential(&args.access_token, "access-token", "META_ACCESS_TOKEN")?;
+    let (since, until) = args.spend.period.dates()?;
+    let account = format!("act_{}", account.trim_start_matches("act_"));
+    let bearer = format!("Bearer {}", token);
+    let mut url = format!("{}/{}/{}/insights", args.api_url.trim_end_matches('/'), args.api_version, account);
+    let mut query = vec![
+        ("level", "campaign".to_string()),
+        ("fields", "campaign_name,spend,actions".to_string()),
+        ("time_range", serde_json::json!({ "since": since, "until": until }).to_string()),
+        ("limit", "500".to_string()),
+    ];
+    let mut campaigns = BTreeMap::new();
+    loop {
+        let page: Page = import::get(&url, &bearer, &query)?;
+        for insight in page.data {
+            let cost = insight.spend.as_deref().and_then(|s| s.parse::<f64>().ok()).unwrap_or(0.0);
+            let conversions = insight.actions.iter().filter(|a| a.action_type == args.action_type).filter_map(|a| a.value.parse::<f64>().ok()).sum();
+            ads::add(&mut campaigns, Campaign { name: insight.campaign_name, cost, conversions: Some(conversions) });
+        }
+        match page.paging.and_then(|p| p.next) {
+            // The next link carries the whole query, token included.
+            Some(next) => {
+                url = next;
+                query.clear();
+            }
+            None => break,
+        }
+    }
+    campaigns.retain(|_, c| c.cost > 0.0);
+    Ok(campaigns.into_values().collect())
+}
+
+impl MetaAdsArgs {
+    /// Campaigns from the CSV report if one was given, else from the API.
+    fn platform(&self) -> Result<Platform, String> {
+        let (campaigns, source) = match (&self.csv, &self.account_id) {
+            (Some(path), _) => (ads::read_report(path, &["campaign name", "campaign"], &["amount spent", "spend"], &["results", "purchases", "conversions"])?, path.clone()),
+            (None, Some(account)) => {
+                let (since, until) = self.spend.period.dates()?;
+                (fetch(self, account)?, format!("account {}, {} to {}, {} actions", account, since, until, self.action_type))
+            }
+            (None, None) => return Err("provide --csv with a Meta Ads campaign report or --account-id to use the API".to_string()),
+        };
+        Ok(Platform { name: "Meta Ads", channel: self.channel.clone(), source, campaigns })
+    }
+
+    /// The Meta Ads side of `import ads`, with defaults for everything but the report or account.
+    pub fn platform_of(csv: Option<String>, account_id: Option<String>, action_type: &str, spend: &SpendArgs) -> Result<Platform, String> {
+        let args = MetaAdsArgs {
+            csv,
+            account_id,
+            access_token: None,
+            action_type: action_type.to_string(),
+            channel: "meta-ads".to_string(),
+            api_version: API_VERSION.to_string(),
+            api_url: API_URL.to_string(),
+            spend: spend.clone(),
+            output: OutputArgs { out: None, name: None },
+        };
+        args.platform()
+    }
+}
+
+impl Importer for MetaAdsArgs {
+    fn name(&self) -> &'static str {
+        "Meta Ads"
+    }
+
+    fn output(&self) -> &OutputArgs {
+        &self.output
+    }
+
+    fn import(&self) -> Result<Imported, String> {
+        ads::report(self.name(), &[self.platform()?], &self.spend)
+    }
+}
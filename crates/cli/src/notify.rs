@@ -0,0 +1,111 @@
+// Notifications: a compact summary of each evaluation or batch posted where the team will see
+// it. `--notify slack` posts to the incoming webhook in the config file:
+//
+//   [slack]
+//   webhook = "https://hooks.slack.com/services/T000/B000/XXXX"
+//
+// A single run is compared with the last run in its `--save-run` history, when there is one.
+
+use std::sync::OnceLock;
+
+use clap::ValueEnum;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::evaluation::money;
+use crate::history::Run;
+use crate::layout::icon;
+use crate::precision;
+use crate::report::EvaluationResult;
+use crate::severity::Severity;
+use crate::sweep::verdict_label;
+
+/// Batch rows listed in a message; the rest are counted.
+const MAX_ROWS: usize = 20;
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Notify {
+    Slack,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SlackConfig {
+    /// Incoming webhook URL for the channel to post to.
+    pub webhook: String,
+}
+
+static SLACK: OnceLock<Option<String>> = OnceLock::new();
+
+/// Checks the `--notify` targets against the config and sets them for the rest of the run.
+pub fn install(targets: &[Notify], slack: Option<SlackConfig>) -> Result<(), String> {
+    let webhook = match (targets.contains(&Notify::Slack), slack) {
+        (true, Some(c)) => Some(c.webhook),
+        (true, None) => return Err("--notify slack needs a [slack] section with the webhook URL in the config file".to_string()),
+        (false, _) => None,
+    };
+    SLACK.set(webhook).expect("notify installed once, at startup");
+    Ok(())
+}
+
+fn post(text: &str) -> Result<(), String> {
+    let Some(webhook) = SLACK.get().and_then(Option::as_ref) else { return Ok(()) };
+    ureq::post(webhook)
+        .content_type("application/json")
+        .send(json!({ "text": text }).to_string())
+        .map(|_| ())
+        .map_err(|e| format!("could not post to Slack: {}", e))
+}
+
+fn ratio(r: f64) -> String {
+    if r.is_finite() { precision::fixed(r, 2) } else { "∞".to_string() }
+}
+
+/// The quadrant, verdict, and severity, e.g. "Self-Funding Growth · ✅ Good (healthy)".
+fn outcome(r: &EvaluationResult) -> String {
+    let severity = r.severity.level;
+    format!("{} · {}{} ({})", r.quadrant, icon(severity), verdict_label(&r.verdict), severity.name())
+}
+
+/// Posts one run, with the change in ratio, CAC, and payback since `previous`.
+pub fn evaluation(name: &str, r: &EvaluationResult, previous: Option<&Run>) -> Result<(), String> {
+    let title = if name.is_empty() { String::new() } else { format!("*{}* ", name) };
+    let mut text = format!("{}LTGP:CAC *{}*", title, ratio(r.ratio_value()));
+    if let Some((now, p)) = r.ratio.zip(previous.filter(|p| p.ratio.is_finite())) {
+        text.push_str(&format!(" ({} since {})", precision::signed(now - p.ratio, 2), p.date));
+    }
+    text.push_str(&format!(" · {}\nCAC {}", outcome(r), money(r.inputs.cac)));
+    if let Some(p) = previous {
+        let d = r.inputs.cac - p.cac;
+        text.push_str(&format!(" ({}{})", if d < 0.0 { "-" } else { "+" }, money(d.abs())));
+    }
+    match r.payback_days {
+        Some(days) => {
+            text.push_str(&format!(" · payback {}d", precision::fixed(days, 0)));
+            if let Some(before) = previous.and_then(|p| p.payback_days) {
+                text.push_str(&format!(" ({}d)", precision::signed(days - before, 0)));
+            }
+        }
+        None => text.push_str(" · payback n/a"),
+    }
+    post(&text)
+}
+
+/// Posts a batch: the count per severity, then one line per row.
+pub fn batch(results: &[(String, EvaluationResult)]) -> Result<(), String> {
+    let levels = [Severity::Healthy, Severity::Watch, Severity::AtRisk, Severity::Critical];
+    let counts: Vec<String> = levels
+        .iter()
+        .map(|&s| (s, results.iter().filter(|(_, r)| r.severity.level == s).count()))
+        .filter(|(_, n)| *n > 0)
+        .map(|(s, n)| format!("{} {}", n, s.name()))
+        .collect();
+    let mut text = format!("*Batch: {} evaluated* ({})", results.len(), counts.join(", "));
+    for (id, r) in results.iter().take(MAX_ROWS) {
+        text.push_str(&format!("\n• {}: LTGP:CAC {} · {}", id, ratio(r.ratio_value()), outcome(r)));
+    }
+    if results.len() > MAX_ROWS {
+        text.push_str(&format!("\n…and {} more", results.len() - MAX_ROWS));
+    }
+    post(&text)
+}
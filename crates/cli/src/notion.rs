@@ -0,0 +1,208 @@
+// Notion export: the core report for a run written to a Notion page, so the monthly
+// unit-economics review sits with the rest of the documentation. `--page` replaces the content
+// of an existing page; `--parent` updates the child page with the same title, or creates it, so
+// re-running the month's export refreshes one page instead of piling up copies. The token is an
+// internal integration's secret in NOTION_TOKEN; share the page with the integration.
+
+use clap::Args;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::clock;
+use crate::evaluation;
+use crate::import;
+use crate::report::{self, EvaluationResult};
+use crate::scenario::{self, ScenarioArgs};
+
+const API_URL: &str = "https://api.notion.com/v1";
+const VERSION: &str = "2022-06-28";
+/// Notion takes at most this many blocks per request, and this many characters per text run.
+const MAX_BLOCKS: usize = 100;
+const MAX_TEXT: usize = 2000;
+
+#[derive(Args, Debug)]
+pub struct NotionArgs {
+    /// Page to replace with the report: its URL or ID
+    #[arg(long, value_parser = page_id, required_unless_present = "parent", conflicts_with = "parent")]
+    pub page: Option<String>,
+
+    /// Page to keep the report under, as a child page titled --title; an existing one is updated
+    #[arg(long, value_parser = page_id)]
+    pub parent: Option<String>,
+
+    /// Page title (default: "<scenario name> unit economics YYYY-MM")
+    #[arg(long)]
+    pub title: Option<String>,
+
+    #[command(flatten)]
+    pub base: ScenarioArgs,
+}
+
+#[derive(Deserialize, Debug)]
+struct Children {
+    results: Vec<Block>,
+    has_more: bool,
+    next_cursor: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Block {
+    id: String,
+    #[serde(rename = "type")]
+    kind: String,
+    child_page: Option<ChildPage>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ChildPage {
+    title: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct Page {
+    id: String,
+}
+
+/// The 32-hex-digit page ID from a page URL (`…/Review-1a2b…?v=…`) or a bare, possibly dashed, ID.
+fn page_id(s: &str) -> Result<String, String> {
+    let path = s.split(['?', '#']).next().unwrap_or_default();
+    let tail: String = path.rsplit('/').next().unwrap_or_default().chars().filter(|c| *c != '-').collect();
+    match tail.get(tail.len().saturating_sub(32)..) {
+        Some(id) if id.len() == 32 && id.chars().all(|c| c.is_ascii_hexdigit()) => Ok(id.to_lowercase()),
+        _ => Err(format!("expected a Notion page URL or ID, got `{}`", s)),
+    }
+}
+
+fn rich_text(content: &str) -> Value {
+    json!([{ "type": "text", "text": { "content": content.chars().take(MAX_TEXT).collect::<String>() } }])
+}
+
+fn block(kind: &str, content: &str) -> Value {
+    let mut block = json!({ "object": "block", "type": kind });
+    block[kind] = json!({ "rich_text": rich_text(content) });
+    block
+}
+
+/// The report's lines as blocks: `=== Title ===` headings, ` - ` bullets, and paragraphs.
+fn blocks(report: &str) -> Vec<Value> {
+    report
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(|line| {
+            if let Some(title) = line.strip_prefix("=== ").and_then(|l| l.strip_suffix(" ===")) {
+                block("heading_2", title)
+            } else if let Some(item) = line.strip_prefix("- ") {
+                block("bulleted_list_item", item)
+            } else {
+                block("paragraph", line)
+            }
+        })
+        .collect()
+}
+
+struct Client {
+    headers: Vec<(&'static str, String)>,
+}
+
+impl Client {
+    fn new() -> Result<Client, String> {
+        let token = std::env::var("NOTION_TOKEN").map_err(|_| "set NOTION_TOKEN to a Notion integration's secret".to_string())?;
+        Ok(Client { headers: vec![("Authorization", format!("Bearer {}", token)), ("Notion-Version", VERSION.to_string())] })
+    }
+
+    fn headers(&self) -> Vec<(&str, &str)> {
+        self.headers.iter().map(|(k, v)| (*k, v.as_str())).collect()
+    }
+
+    fn children(&self, id: &str) -> Result<Vec<Block>, String> {
+        let url = format!("{}/blocks/{}/children", API_URL, id);
+        let mut all = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let mut query = vec![("page_size", MAX_BLOCKS.to_string())];
+            query.extend(cursor.take().map(|c| ("start_cursor", c)));
+            let mut page: Children = import::get_with(&url, &self.headers(), &query)?;
+            all.append(&mut page.results);
+            match page.next_cursor.filter(|_| page.has_more) {
+                Some(next) => cursor = Some(next),
+                None => return Ok(all),
+            }
+        }
+    }
+
+    fn append(&self, id: &str, blocks: &[Value]) -> Result<(), String> {
+        for chunk in blocks.chunks(MAX_BLOCKS) {
+            let _: Value = import::patch(&format!("{}/blocks/{}/children", API_URL, id), &self.headers(), &json!({ "children": chunk }).to_string())?;
+        }
+        Ok(())
+    }
+
+    /// Swaps the page's content for `blocks`; sub-pages and databases on it are left alone.
+    fn replace(&self, id: &str, blocks: &[Value]) -> Result<(), String> {
+        for old in self.children(id)? {
+            if old.kind != "child_page" && old.kind != "child_database" {
+                let _: Value = import::delete(&format!("{}/blocks/{}", API_URL, old.id), &self.headers())?;
+            }
+        }
+        self.append(id, blocks)
+    }
+
+    fn rename(&self, id: &str, title: &str) -> Result<(), String> {
+        let _: Value = import::patch(&format!("{}/pages/{}", API_URL, id), &self.headers(), &json!({ "properties": { "title": { "title": rich_text(title) } } }).to_string())?;
+        Ok(())
+    }
+
+    fn create(&self, parent: &str, title: &str, blocks: &[Value]) -> Result<String, String> {
+        let (first, rest) = blocks.split_at(blocks.len().min(MAX_BLOCKS));
+        let body = json!({ "parent": { "page_id": parent }, "properties": { "title": { "title": rich_text(title) } }, "children": first });
+        let page: Page = import::post(&format!("{}/pages", API_URL), &self.headers(), &body.to_string())?;
+        self.append(&page.id, rest)?;
+        Ok(page.id)
+    }
+}
+
+/// Writes the blocks where `args` says and returns the page's ID.
+fn export(args: &NotionArgs, title: &str, blocks: &[Value]) -> Result<String, String> {
+    let client = Client::new()?;
+    if let Some(page) = &args.page {
+        if args.title.is_some() {
+            client.rename(page, title)?;
+        }
+        client.replace(page, blocks)?;
+        return Ok(page.clone());
+    }
+    let parent = args.parent.as_deref().expect("clap requires --page or --parent");
+    let existing = client.children(parent)?.into_iter().find(|b| b.child_page.as_ref().is_some_and(|p| p.title == title));
+    match existing {
+        Some(page) => client.replace(&page.id, blocks).map(|()| page.id),
+        None => client.create(parent, title, blocks),
+    }
+}
+
+pub fn run(args: &NotionArgs) {
+    let scenario = args.base.resolve().unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(2);
+    });
+    let inputs = scenario::checked(scenario.raw_inputs(), "").unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(2);
+    });
+    let eval = evaluation::evaluate(&inputs);
+    // Notion gets the text without terminal colors.
+    colored::control::set_override(false);
+    let report = ltgp_core::report::render_report(&EvaluationResult::from(&eval), &report::Plain);
+    let month = clock::today()[..7].to_string();
+    let title = args.title.clone().unwrap_or_else(|| match &scenario.name {
+        Some(name) => format!("{} unit economics {}", name, month),
+        None => format!("Unit economics {}", month),
+    });
+    match export(args, &title, &blocks(&report)) {
+        Ok(id) => println!("Report written to https://www.notion.so/{}.", id.replace('-', "")),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
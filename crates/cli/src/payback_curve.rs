@@ -0,0 +1,131 @@
+// Payback curve: cumulative gross profit per customer over time against the net outlay line
+// (CAC − CFA), marking where they cross (breakeven) and where lifetime gross profit runs out
+// (the lifetime horizon). Rendered in the terminal or as a standalone SVG file.
+
+use crate::chart_data::ChartData;
+use crate::evaluation::{money, Evaluation};
+use crate::period::Period;
+use crate::precision;
+use crate::theme;
+
+const WIDTH: usize = 60;
+const HEIGHT: usize = 16;
+
+/// The curve's shape, in the input's period unit.
+struct Curve {
+    rate: f64,
+    ltgp: f64,
+    net_outlay: f64,
+    /// Periods until lifetime gross profit is fully earned.
+    horizon: f64,
+    /// Periods until cumulative gross profit covers the net outlay; None if it never does.
+    breakeven: Option<f64>,
+    /// Right edge of the chart, a little past the horizon so the flat tail shows.
+    x_max: f64,
+    y_max: f64,
+}
+
+impl Curve {
+    fn new(e: &Evaluation) -> Option<Curve> {
+        let i = &e.inputs;
+        let payback = e.payback?;
+        let horizon = i.ltgp / i.early_gp_rate;
+        let breakeven = (e.net_outlay <= i.ltgp).then_some(payback);
+        let x_max = (horizon.max(breakeven.unwrap_or(0.0)) * 1.15).max(1.0);
+        let y_max = (i.ltgp.max(e.net_outlay) * 1.1).max(1.0);
+        Some(Curve { rate: i.early_gp_rate, ltgp: i.ltgp, net_outlay: e.net_outlay, horizon, breakeven, x_max, y_max })
+    }
+
+    fn gp_at(&self, t: f64) -> f64 {
+        (self.rate * t).min(self.ltgp)
+    }
+}
+
+fn summary(c: &Curve, period: Period) -> String {
+    match c.breakeven {
+        Some(b) => format!("Breakeven after {} {}; lifetime gross profit is earned by {} {}, leaving {} after the outlay.", precision::fixed(b, 1), period, precision::fixed(c.horizon, 1), period, money(c.ltgp - c.net_outlay)),
+        None => format!("Never breaks even: lifetime gross profit of {} runs out at {} {}, short of the {} net outlay.", money(c.ltgp), precision::fixed(c.horizon, 1), period, money(c.net_outlay)),
+    }
+}
+
+/// Points sampled along the curve (x in the input's period unit), plus the outlay line.
+pub fn chart_data(e: &Evaluation, data: &mut ChartData) {
+    let Some(c) = Curve::new(e) else { return };
+    for n in 0..=WIDTH {
+        let t = c.x_max * n as f64 / WIDTH as f64;
+        data.push_xy("payback_curve", "cumulative_gross_profit", t, c.gp_at(t));
+        data.push_xy("payback_curve", "net_outlay", t, c.net_outlay);
+    }
+}
+
+pub fn print_payback_curve(e: &Evaluation) {
+    let i = &e.inputs;
+    let Some(c) = Curve::new(e) else {
+        println!("\nPayback curve: no early gross profit, so there is no curve to draw. Provide --early-gp-rate.");
+        return;
+    };
+    let col = |t: f64| ((t / c.x_max) * (WIDTH - 1) as f64).round().clamp(0.0, (WIDTH - 1) as f64) as usize;
+    let row = |v: f64| HEIGHT - 1 - ((v / c.y_max) * (HEIGHT - 1) as f64).round().clamp(0.0, (HEIGHT - 1) as f64) as usize;
+
+    let mut grid = vec![vec![' '; WIDTH]; HEIGHT];
+    let horizon_col = col(c.horizon);
+    for cells in grid.iter_mut() {
+        cells[horizon_col] = ':';
+    }
+    let outlay_row = row(c.net_outlay);
+    for cell in grid[outlay_row].iter_mut() {
+        *cell = '-';
+    }
+    let curve_rows: Vec<usize> = (0..WIDTH).map(|x| row(c.gp_at(c.x_max * x as f64 / (WIDTH - 1) as f64))).collect();
+    for (x, r) in curve_rows.into_iter().enumerate() {
+        grid[r][x] = '*';
+    }
+    if let Some(b) = c.breakeven {
+        grid[outlay_row][col(b)] = 'X';
+    }
+
+    println!("\nPayback curve (* = cumulative gross profit, - = net outlay {}, X = breakeven, : = lifetime horizon):", money(c.net_outlay));
+    for (r, cells) in grid.iter().enumerate() {
+        let label = match r {
+            0 => format!("{:>9}", money(c.y_max)),
+            _ if r == HEIGHT - 1 => format!("{:>9}", "$0"),
+            _ => " ".repeat(9),
+        };
+        println!("{} │{}", label, cells.iter().collect::<String>());
+    }
+    println!("{} └{}", " ".repeat(9), "─".repeat(WIDTH));
+    println!("{}  0{:>width$}  {}", " ".repeat(9), precision::fixed(c.x_max, 1), i.period, width = WIDTH - 1);
+    println!(" {}", summary(&c, i.period));
+}
+
+/// Standalone SVG of the payback curve; None without early gross profit.
+pub fn svg(e: &Evaluation) -> Option<String> {
+    let c = Curve::new(e)?;
+    let (width, height, top, left) = (560.0, 300.0, 30.0, 70.0);
+    let x = |t: f64| left + t / c.x_max * width;
+    let y = |v: f64| top + (1.0 - v / c.y_max) * height;
+
+    let theme = theme::active();
+    let (axis, guide, muted) = (&theme.axis, &theme.guide, &theme.muted);
+    let (outlay, gp, mark) = (theme.hex("red"), theme.hex("green"), theme.hex("blue"));
+    let mut out = theme.svg_open(left + width + 30.0, top + height + 60.0, 11);
+    out.push_str(&format!(r#"<line x1="{l}" x2="{l}" y1="{t}" y2="{b}" stroke="{axis}"/><line x1="{l}" x2="{r}" y1="{b}" y2="{b}" stroke="{axis}"/>"#, l = left, r = left + width, t = top, b = top + height));
+    out.push_str(&format!(r#"<text x="{lx}" y="{ty:.1}" text-anchor="end">{v}</text><text x="{lx}" y="{b}" text-anchor="end">$0</text>"#, lx = left - 6.0, ty = y(c.y_max) + 4.0, v = money(c.y_max), b = top + height));
+    out.push_str(&format!(r#"<text x="{r}" y="{ly}" text-anchor="end">{xm:.1} {p}</text>"#, r = left + width, ly = top + height + 16.0, xm = c.x_max, p = e.inputs.period));
+
+    out.push_str(&format!(r#"<line x1="{l}" x2="{r}" y1="{yo:.1}" y2="{yo:.1}" stroke="{outlay}" stroke-width="2"/>"#, l = left, r = left + width, yo = y(c.net_outlay)));
+    out.push_str(&format!(r#"<text x="{r}" y="{ty:.1}" text-anchor="end" fill="{outlay}">net outlay {v}</text>"#, r = left + width, ty = y(c.net_outlay) - 5.0, v = money(c.net_outlay)));
+    out.push_str(&format!(r#"<line x1="{xh:.1}" x2="{xh:.1}" y1="{t}" y2="{b}" stroke="{guide}" stroke-dasharray="4 3"/>"#, xh = x(c.horizon), t = top, b = top + height));
+    out.push_str(&format!(r#"<text x="{xh:.1}" y="{ly}" text-anchor="middle" fill="{muted}">lifetime horizon {h:.1}</text>"#, xh = x(c.horizon), ly = top + height + 32.0, h = c.horizon));
+
+    let points = [0.0, c.horizon.min(c.x_max), c.x_max].iter().map(|t| format!("{},{}", precision::fixed(x(*t), 1), precision::fixed(y(c.gp_at(*t)), 1))).collect::<Vec<_>>().join(" ");
+    out.push_str(&format!(r#"<polyline points="{points}" fill="none" stroke="{gp}" stroke-width="2.5"/>"#));
+
+    if let Some(b) = c.breakeven {
+        out.push_str(&format!(r#"<circle cx="{bx:.1}" cy="{by:.1}" r="5" fill="{mark}"/>"#, bx = x(b), by = y(c.net_outlay)));
+        out.push_str(&format!(r#"<text x="{bx:.1}" y="{ty:.1}" fill="{mark}">breakeven {b:.1}</text>"#, bx = x(b) + 8.0, ty = y(c.net_outlay) + 16.0));
+    }
+    out.push_str(&format!(r#"<text x="{l}" y="{ty}" fill="{muted}">{s}</text>"#, l = left, ty = top + height + 52.0, s = summary(&c, e.inputs.period)));
+    out.push_str("</svg>\n");
+    Some(out)
+}
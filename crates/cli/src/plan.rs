@@ -0,0 +1,104 @@
+// The acquisition plan, growth ceiling, runway, and working-capital sections of the report, from
+// the cash-flow math in ltgp_core::plan.
+
+pub use ltgp_core::plan::*;
+
+use crate::evaluation::{money, Inputs};
+use crate::layout::wrapln;
+use crate::precision;
+
+pub fn print_budget_plan(inputs: &Inputs, monthly_budget: f64, horizon: usize) {
+    let unit = UnitCashFlow::from_inputs(inputs);
+    println!("\nAcquisition plan ({}/month budget over {} months):", money(monthly_budget), horizon);
+    if inputs.cac <= 0.0 {
+        return wrapln!(" - CAC is zero, so the budget does not limit acquisition.");
+    }
+    let per_month = monthly_budget / inputs.cac;
+    wrapln!(" - Customers per month: {} at {} each.", precision::fixed(per_month, 1), money(inputs.cac));
+    if unit.monthly_gp <= 0.0 {
+        return wrapln!(" - Provide --early-gp-rate to project working capital and cash-positive timing.");
+    }
+
+    match unit.breakeven_month(horizon) {
+        Some(m) => wrapln!(" - Each monthly cohort turns cash-positive in month {} after it is acquired.", m + 1),
+        None => wrapln!(" - A cohort does not turn cash-positive within {} months.", horizon),
+    }
+
+    let running = cumulative(&program_cash(&unit, &vec![per_month; horizon]));
+    let (trough_month, low) = trough(&program_low_points(&unit, &vec![per_month; horizon]));
+    if low < 0.0 {
+        wrapln!(" - Peak working capital required: {}, reached in month {}.", money(-low), trough_month + 1);
+    } else {
+        wrapln!(" - No working capital needed: upfront collections cover acquisition from day one.");
+    }
+    match running.iter().enumerate().skip(trough_month).find(|(_, c)| **c >= 0.0) {
+        Some((m, _)) if low < 0.0 => wrapln!(" - The program as a whole is cash-positive from month {} onward.", m + 1),
+        Some(_) => {}
+        None => wrapln!(" - The program as a whole is still cash-negative after {} months.", horizon),
+    }
+}
+
+pub fn print_growth_ceiling(inputs: &Inputs, starting_cash: f64, horizon: usize, channel_capacity: Option<f64>) {
+    let unit = UnitCashFlow::from_inputs(inputs);
+    println!("\nGrowth ceiling without outside capital ({} starting cash):", money(starting_cash));
+    if unit.monthly_gp <= 0.0 && inputs.cfa < inputs.cac {
+        return wrapln!(" - Provide --early-gp-rate to compute how fast cash lets you grow.");
+    }
+    match max_sustainable_rate(&unit, starting_cash, horizon) {
+        Some((rate, month)) => {
+            wrapln!(" - Max steady acquisition: {} customers/month ({}/month in CAC).", precision::fixed(rate, 1), money(rate * inputs.cac));
+            wrapln!(" - At that pace, cash bottoms out at $0 in month {} and recovers as cohorts pay back.", month + 1);
+            if let Some(cap) = channel_capacity && cap < rate {
+                wrapln!(" - Channel capacity ({} customers/month) binds before cash does.", precision::fixed(cap, 0));
+            }
+        }
+        None => wrapln!(" - Upfront collections and early gross profit cover acquisition, so cash does not cap growth; channel capacity and CAC creep do."),
+    }
+}
+
+pub fn print_runway(inputs: &Inputs, starting_cash: f64, fixed_burn: f64, floor: f64, pace: Option<f64>, horizon: usize) {
+    let unit = UnitCashFlow::from_inputs(inputs);
+    println!("\nRunway ({} cash, {}/month fixed burn, {} floor):", money(starting_cash), money(fixed_burn), money(floor));
+    if unit.monthly_gp <= 0.0 {
+        return wrapln!(" - Provide --early-gp-rate to couple runway to the acquisition plan.");
+    }
+
+    let pace = pace.unwrap_or(0.0);
+    let balance = cash_balance(&unit, starting_cash, fixed_burn, pace, horizon);
+    match balance.iter().position(|b| *b < floor) {
+        Some(m) => wrapln!(" - At today's pace ({} customers/month): {} months of runway before cash drops below the floor.", precision::fixed(pace, 1), m),
+        None => wrapln!(" - At today's pace ({} customers/month): cash stays above the floor for all {} months projected.", precision::fixed(pace, 1), horizon),
+    }
+
+    match feasible_rates(&unit, starting_cash, fixed_burn, floor, horizon) {
+        Some((_, hi)) if hi.is_infinite() => wrapln!(" - Cash does not cap acquisition: upfront collections fund new customers as fast as you can find them."),
+        Some((lo, hi)) => {
+            wrapln!(" - Fastest pace that keeps cash above the floor: {} customers/month ({}/month in CAC).", precision::fixed(hi, 1), money(hi * inputs.cac));
+            if lo > 0.0 {
+                wrapln!(" - You need at least {} customers/month for gross profit to cover the fixed burn over this horizon.", precision::fixed(lo, 1));
+            }
+            if pace > hi {
+                println!("\nWarning: Today's pace outruns your cash. Slow acquisition to {} customers/month or raise capital.", precision::fixed(hi, 1));
+            }
+        }
+        None => println!("\nWarning: No steady acquisition pace keeps cash above the floor for {} months; the fixed burn needs outside capital or cuts.", horizon),
+    }
+}
+
+pub fn print_working_capital(inputs: &Inputs, cohort_size: Option<f64>, horizon: usize) {
+    let unit = UnitCashFlow::from_inputs(inputs);
+    if unit.monthly_gp <= 0.0 { return; }
+    let wc = working_capital(&unit, horizon);
+    if wc.per_customer <= 0.0 { return; }
+
+    println!("\nWorking capital:");
+    match wc.months_to_close {
+        Some(m) => wrapln!(" - Per customer: {} tied up at acquisition, recovered by the end of month {}.", money(wc.per_customer), m + 1),
+        None => wrapln!(" - Per customer: {} tied up at acquisition, not recovered within {} months.", money(wc.per_customer), horizon),
+    }
+    let cohort = cohort_size.unwrap_or(1.0);
+    let label = if cohort_size.is_some() { format!("{} customers", precision::fixed(cohort, 0)) } else { "1 customer".to_string() };
+    wrapln!(" - Per monthly cohort of {}: {} at acquisition.", label, money(wc.per_customer * cohort));
+    let program = wc.program_per_monthly_customer * cohort;
+    wrapln!(" - Acquiring {} every month: peak cash gap {} in month {} ({}× a single cohort's net outlay).", label, money(program), wc.program_peak_month + 1, precision::fixed(program / (wc.per_customer * cohort), 1));
+}
@@ -0,0 +1,136 @@
+// Terminal charts. The quadrant chart puts CAC across and CFA up, with the low-CAC and high-CFA
+// boundaries drawn and the user's position marked, so the quadrant reads at a glance rather than
+// as a label. The payback chart stacks upfront cash and cumulative gross profit against CAC,
+// period by period, so it shows how front- or back-loaded the recovery is.
+
+use crate::chart_data::ChartData;
+use crate::evaluation::{self, money, Evaluation, Inputs};
+use crate::plan::{self, UnitCashFlow};
+use crate::precision;
+
+const WIDTH: usize = 56;
+const HEIGHT: usize = 16;
+
+/// High-CFA boundary at a given CAC: a fixed dollar cut, or a fraction of that CAC.
+fn cfa_boundary(i: &Inputs, cac: f64) -> f64 {
+    i.high_cfa_min.unwrap_or(i.high_cfa_fraction.max(0.0) * cac)
+}
+
+/// Quadrant name at a point, from the active decision tree, for labeling each region.
+fn region_name(i: &Inputs, cac: f64, cfa: f64) -> &'static str {
+    evaluation::evaluate(&Inputs { cac, cfa, ..i.clone() }).quadrant.name()
+}
+
+pub fn print_quadrant_plot(e: &Evaluation) {
+    let i = &e.inputs;
+    let x_max = (e.low_cac_thresh * 2.0).max(i.cac * 1.25).max(1.0);
+    let y_max = (cfa_boundary(i, x_max) * 1.25).max(i.cfa * 1.25).max(x_max * 0.25).max(1.0);
+    let col = |cac: f64| ((cac / x_max) * (WIDTH - 1) as f64).round().clamp(0.0, (WIDTH - 1) as f64) as usize;
+    let row = |cfa: f64| HEIGHT - 1 - ((cfa / y_max) * (HEIGHT - 1) as f64).round().clamp(0.0, (HEIGHT - 1) as f64) as usize;
+
+    let mut grid = vec![vec![' '; WIDTH]; HEIGHT];
+    let cac_line = col(e.low_cac_thresh);
+    for cells in grid.iter_mut() {
+        cells[cac_line] = '|';
+    }
+    let boundary_rows: Vec<usize> = (0..WIDTH).map(|c| row(cfa_boundary(i, x_max * c as f64 / (WIDTH - 1) as f64))).collect();
+    for (c, r) in boundary_rows.into_iter().enumerate() {
+        grid[r][c] = if grid[r][c] == '|' { '+' } else { '-' };
+    }
+    grid[row(i.cfa)][col(i.cac)] = '●';
+
+    // Region names come from evaluating a point in the middle of each region.
+    let (lo_cac, hi_cac) = (e.low_cac_thresh * 0.5, (e.low_cac_thresh + x_max) / 2.0);
+    let above = |cac: f64| (cfa_boundary(i, cac) + y_max) / 2.0;
+    let below = |cac: f64| cfa_boundary(i, cac) / 2.0;
+
+    println!("\nQuadrant chart (● = you, | = low-CAC cut at ${}, - = high-CFA cut):", precision::fixed(e.low_cac_thresh, 0));
+    println!("  top-left: {}   top-right: {}", region_name(i, lo_cac, above(lo_cac)), region_name(i, hi_cac, above(hi_cac)));
+    println!("  CFA");
+    for (r, cells) in grid.iter().enumerate() {
+        let label = match r {
+            0 => format!("{:>8}", precision::fixed(y_max, 0)),
+            _ if r == HEIGHT - 1 => format!("{:>8}", 0),
+            _ => " ".repeat(8),
+        };
+        println!("{} │{}", label, cells.iter().collect::<String>());
+    }
+    println!("{} └{}", " ".repeat(8), "─".repeat(WIDTH));
+    println!("{}  0{:>width$}  CAC", " ".repeat(8), precision::fixed(x_max, 0), width = WIDTH - 1);
+    println!("  bottom-left: {}   bottom-right: {}", region_name(i, lo_cac, below(lo_cac)), region_name(i, hi_cac, below(hi_cac)));
+}
+
+const BAR_WIDTH: usize = 40;
+const MAX_ROWS: usize = 24;
+
+/// Share of CAC recovered (CFA plus cumulative gross profit) at each period up to payback.
+pub fn payback_chart_data(e: &Evaluation, data: &mut ChartData) {
+    let i = &e.inputs;
+    let Some(payback) = e.payback else { return };
+    for p in 0..=payback.ceil().max(1.0) as usize {
+        data.push_xy("payback_chart", "recovered", p as f64, i.cfa + (i.early_gp_rate * p as f64).min(i.ltgp));
+    }
+    data.push_xy("payback_chart", "cac", 0.0, i.cac);
+}
+
+/// Your position and the quadrant cuts.
+pub fn quadrant_chart_data(e: &Evaluation, data: &mut ChartData) {
+    data.push_xy("quadrant", "you", e.inputs.cac, e.inputs.cfa);
+    data.push_xy("quadrant", "low_cac_cut", e.low_cac_thresh, 0.0);
+    data.push_xy("quadrant", "high_cfa_cut", e.inputs.cac, e.high_cfa_thresh);
+}
+
+pub fn print_payback_chart(e: &Evaluation) {
+    let i = &e.inputs;
+    let Some(payback) = e.payback else {
+        println!("\nPayback chart: no early gross profit, so there is no timeline to draw. Provide --early-gp-rate.");
+        return;
+    };
+    if i.cac <= 0.0 {
+        return;
+    }
+    let periods = payback.ceil().max(1.0) as usize;
+    // Long paybacks are grouped so the chart stays on one screen.
+    let step = periods.div_ceil(MAX_ROWS);
+    let scale = |v: f64| ((v / i.cac).clamp(0.0, 1.0) * BAR_WIDTH as f64).round() as usize;
+    let upfront = scale(i.cfa);
+
+    println!("\nPayback timeline (▓ = CFA upfront, █ = cumulative gross profit, bar end = CAC {}):", money(i.cac));
+    let mut p = 0;
+    loop {
+        let gp = (i.early_gp_rate * p as f64).min(i.ltgp);
+        let recovered = i.cfa + gp;
+        let filled = scale(recovered);
+        let bar = format!("{}{}{}", "▓".repeat(upfront.min(filled)), "█".repeat(filled.saturating_sub(upfront)), "·".repeat(BAR_WIDTH - filled));
+        let status = if recovered >= i.cac { "  ← breakeven" } else { "" };
+        println!(" {:>5} {:<6} │{}│ {:>5}%{}", p, i.period, bar, precision::fixed((recovered / i.cac * 100.0).min(999.0), 0), status);
+        if p >= periods {
+            break;
+        }
+        p = (p + step).min(periods);
+    }
+    let share_upfront = (i.cfa / i.cac).min(1.0) * 100.0;
+    let shape = if share_upfront >= 50.0 { "front-loaded: most of CAC comes back at signup" } else if share_upfront > 0.0 { "back-loaded: most of CAC comes back through gross profit over time" } else { "fully back-loaded: nothing is collected upfront" };
+    println!(" Recovery is {} ({}% upfront).", shape, precision::fixed(share_upfront, 0));
+}
+
+const SPARK: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// One character per value, scaled between the lowest and highest.
+pub fn sparkline(values: &[f64]) -> String {
+    let lo = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let hi = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    values.iter().map(|v| {
+        let t = if hi > lo { (v - lo) / (hi - lo) } else { 0.5 };
+        SPARK[(t * (SPARK.len() - 1) as f64).round() as usize]
+    }).collect()
+}
+
+/// Per-customer cumulative cash: at signup (CFA − CAC), then at the end of each month until
+/// lifetime gross profit is earned, capped at `max_months`.
+pub fn cash_position(inputs: &Inputs, max_months: usize) -> Vec<f64> {
+    let unit = UnitCashFlow::from_inputs(inputs);
+    let months = if unit.monthly_gp > 0.0 { ((unit.ltgp / unit.monthly_gp).ceil() as usize).clamp(1, max_months) } else { 1 };
+    let flows: Vec<f64> = (0..months).map(|m| unit.cash_in_month(m)).collect();
+    std::iter::once(unit.cfa - unit.cac).chain(plan::cumulative(&flows)).collect()
+}
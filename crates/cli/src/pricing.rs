@@ -0,0 +1,149 @@
+// A/B comparison of two pricing structures acquired at the same CAC.
+
+use clap::Args;
+
+use crate::evaluation::{self, money, ratio_text, Evaluation, Inputs};
+use crate::layout::wrapln;
+use crate::locale;
+use crate::period::Period;
+use crate::precision;
+use crate::scenario::non_negative_or_exit;
+use crate::units::Fraction;
+
+#[derive(Args, Debug)]
+pub struct ComparePricingArgs {
+    /// Cost to acquire a customer (CAC), shared by both structures
+    #[arg(long, value_parser = locale::number)]
+    pub cac: f64,
+
+    /// Label for pricing structure A
+    #[arg(long, default_value = "A")]
+    pub a_name: String,
+
+    /// Upfront cash collected under structure A (setup fee, prepayment)
+    #[arg(long, value_parser = locale::number, default_value_t = 0.0)]
+    pub a_cfa: f64,
+
+    /// Monthly gross profit per customer under structure A
+    #[arg(long, value_parser = locale::number)]
+    pub a_monthly_gp: f64,
+
+    /// Monthly churn under structure A, in percent (e.g., 4 = 4%)
+    #[arg(long, value_parser = locale::number)]
+    pub a_churn: f64,
+
+    /// Override structure A's LTGP instead of deriving it from churn
+    #[arg(long, value_parser = locale::number)]
+    pub a_ltgp: Option<f64>,
+
+    /// Label for pricing structure B
+    #[arg(long, default_value = "B")]
+    pub b_name: String,
+
+    /// Upfront cash collected under structure B (setup fee, prepayment)
+    #[arg(long, value_parser = locale::number, default_value_t = 0.0)]
+    pub b_cfa: f64,
+
+    /// Monthly gross profit per customer under structure B
+    #[arg(long, value_parser = locale::number)]
+    pub b_monthly_gp: f64,
+
+    /// Monthly churn under structure B, in percent (e.g., 4 = 4%)
+    #[arg(long, value_parser = locale::number)]
+    pub b_churn: f64,
+
+    /// Override structure B's LTGP instead of deriving it from churn
+    #[arg(long, value_parser = locale::number)]
+    pub b_ltgp: Option<f64>,
+
+    /// Consider CAC 'low' if CAC < threshold_fraction * LTGP
+    #[arg(long, value_parser = locale::number, default_value_t = 0.10)]
+    pub low_cac_fraction: f64,
+}
+
+/// One pricing structure: what it collects upfront, what it earns monthly, and how fast customers leave.
+#[derive(Debug, Clone)]
+pub struct PricingStructure {
+    pub name: String,
+    pub cfa: f64,
+    pub monthly_gp: f64,
+    /// Monthly churn (0.04 = 4%).
+    pub churn: Fraction,
+    pub ltgp_override: Option<f64>,
+}
+
+impl PricingStructure {
+    /// Expected customer lifetime in months under geometric churn.
+    pub fn lifetime_months(&self) -> f64 {
+        if self.churn.value() > 0.0 { 1.0 / self.churn.value() } else { f64::INFINITY }
+    }
+
+    /// Upfront collections plus monthly gross profit over the expected lifetime.
+    pub fn ltgp(&self) -> f64 {
+        self.ltgp_override.unwrap_or(self.cfa + self.monthly_gp * self.lifetime_months())
+    }
+
+    /// Expected cash position of one acquired customer after `months`, net of CAC and churn.
+    pub fn cash_after(&self, cac: f64, months: u32) -> f64 {
+        let survival = 1.0 - self.churn.value().clamp(0.0, 1.0);
+        let collected: f64 = (0..months).map(|m| self.monthly_gp * survival.powi(m as i32)).sum();
+        self.cfa - cac + collected
+    }
+}
+
+fn evaluate_structure(p: &PricingStructure, cac: f64, low_cac_fraction: f64) -> Evaluation {
+    evaluation::evaluate(&Inputs {
+        cac,
+        cfa: p.cfa,
+        ltgp: p.ltgp(),
+        early_gp_rate: p.monthly_gp,
+        period: Period::Months,
+        low_cac_fraction,
+        high_cfa_fraction: evaluation::DEFAULT_HIGH_CFA_FRACTION,
+        low_cac_max: None,
+        high_cfa_min: None,
+    })
+}
+
+pub fn run(args: &ComparePricingArgs) {
+    let a = PricingStructure { name: args.a_name.clone(), cfa: non_negative_or_exit("a-cfa", args.a_cfa), monthly_gp: non_negative_or_exit("a-monthly-gp", args.a_monthly_gp), churn: Fraction::from_percent(non_negative_or_exit("a-churn", args.a_churn)), ltgp_override: args.a_ltgp };
+    let b = PricingStructure { name: args.b_name.clone(), cfa: non_negative_or_exit("b-cfa", args.b_cfa), monthly_gp: non_negative_or_exit("b-monthly-gp", args.b_monthly_gp), churn: Fraction::from_percent(non_negative_or_exit("b-churn", args.b_churn)), ltgp_override: args.b_ltgp };
+    print_comparison(&a, &b, args.cac, args.low_cac_fraction);
+}
+
+pub fn print_comparison(a: &PricingStructure, b: &PricingStructure, cac: f64, low_cac_fraction: f64) {
+    let ea = evaluate_structure(a, cac, low_cac_fraction);
+    let eb = evaluate_structure(b, cac, low_cac_fraction);
+    let payback = |e: &Evaluation| e.payback.map(|m| format!("{} months", precision::fixed(m, 1))).unwrap_or_else(|| "never".to_string());
+
+    println!("\n=== Pricing Comparison (CAC {}) ===\n", money(cac));
+    println!("{:<28} {:>20} {:>20}", "", a.name, b.name);
+    println!("{:<28} {:>20} {:>20}", "Upfront cash (CFA)", money(a.cfa), money(b.cfa));
+    println!("{:<28} {:>20} {:>20}", "Monthly gross profit", money(a.monthly_gp), money(b.monthly_gp));
+    println!("{:<28} {:>20} {:>20}", "Monthly churn", format!("{}%", precision::fixed(a.churn.value() * 100.0, 1)), format!("{}%", precision::fixed(b.churn.value() * 100.0, 1)));
+    println!("{:<28} {:>20} {:>20}", "Expected lifetime", format!("{} months", precision::fixed(a.lifetime_months(), 1)), format!("{} months", precision::fixed(b.lifetime_months(), 1)));
+    println!("{:<28} {:>20} {:>20}", "LTGP", money(a.ltgp()), money(b.ltgp()));
+    println!("{:<28} {:>20} {:>20}", "LTGP:CAC", ratio_text(ea.ratio), ratio_text(eb.ratio));
+    println!("{:<28} {:>20} {:>20}", "Net upfront outlay", money(ea.net_outlay), money(eb.net_outlay));
+    println!("{:<28} {:>20} {:>20}", "Payback", payback(&ea), payback(&eb));
+    for months in [3, 12] {
+        println!("{:<28} {:>20} {:>20}", format!("Cash per customer @ {}mo", months), money(a.cash_after(cac, months)), money(b.cash_after(cac, months)));
+    }
+    println!("{:<28} {:>20} {:>20}", "Quadrant", ea.quadrant.name(), eb.quadrant.name());
+
+    // Cash dynamics: whoever is cash-positive sooner; lifetime: whoever has the higher ratio.
+    let cash_score = |e: &Evaluation| e.payback.unwrap_or(f64::INFINITY);
+    let cash_winner = if cash_score(&ea) < cash_score(&eb) || (cash_score(&ea) == cash_score(&eb) && a.cash_after(cac, 12) >= b.cash_after(cac, 12)) { a } else { b };
+    let lifetime_winner = if ea.ratio >= eb.ratio { a } else { b };
+
+    println!("\nVerdicts:");
+    wrapln!(" - {}: {}", a.name, ea.verdict);
+    wrapln!(" - {}: {}", b.name, eb.verdict);
+    println!("\nRecommendation:");
+    if cash_winner.name == lifetime_winner.name {
+        wrapln!(" - {} wins on both cash dynamics and lifetime economics.", cash_winner.name);
+    } else {
+        wrapln!(" - {} recovers acquisition cash faster; {} earns more over the customer lifetime.", cash_winner.name, lifetime_winner.name);
+        wrapln!(" - Prefer {} if cash is tight or you want to scale acquisition quickly; prefer {} if you can fund the slower payback.", cash_winner.name, lifetime_winner.name);
+    }
+}
@@ -0,0 +1,110 @@
+// Input data-quality grading: how each number was obtained, and how much the verdict can be trusted.
+
+use clap::ValueEnum;
+
+use crate::evaluation::{self, Field, Inputs};
+use crate::layout::wrapln;
+use crate::precision;
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    /// Taken from real transactions or reports
+    Measured,
+    /// Derived from a model, cohort projection, or benchmark
+    Modeled,
+    /// An educated guess
+    Guessed,
+}
+
+impl Source {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Source::Measured => "measured",
+            Source::Modeled => "modeled",
+            Source::Guessed => "guessed",
+        }
+    }
+
+    /// How far the true value could plausibly sit from the entered one.
+    fn error_band(&self) -> f64 {
+        match self {
+            Source::Measured => 0.05,
+            Source::Modeled => 0.15,
+            Source::Guessed => 0.30,
+        }
+    }
+}
+
+/// Where each input came from; None means the user didn't say.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Quality {
+    pub cac: Option<Source>,
+    pub cfa: Option<Source>,
+    pub ltgp: Option<Source>,
+    pub early_gp: Option<Source>,
+}
+
+impl Quality {
+    pub fn is_empty(&self) -> bool {
+        self.fields().iter().all(|(_, s)| s.is_none())
+    }
+
+    fn fields(&self) -> [(Field, Option<Source>); 4] {
+        [(Field::Cac, self.cac), (Field::Cfa, self.cfa), (Field::Ltgp, self.ltgp), (Field::EarlyGpRate, self.early_gp)]
+    }
+}
+
+/// A guessed or modeled input whose plausible error band flips the verdict.
+#[derive(Debug, Clone)]
+pub struct Hinge {
+    pub input: Field,
+    pub source: Source,
+    pub shift: f64,
+    pub verdict: &'static str,
+}
+
+/// Inputs whose error band, pushed either way, changes the verdict.
+pub fn hinges(inputs: &Inputs, quality: &Quality) -> Vec<Hinge> {
+    let base = evaluation::evaluate(inputs).verdict;
+    let mut out = Vec::new();
+    for (field, source) in quality.fields() {
+        let Some(source) = source.filter(|s| *s != Source::Measured) else { continue };
+        for shift in [-source.error_band(), source.error_band()] {
+            let mut shifted = inputs.clone();
+            field.set(&mut shifted, field.get(inputs) * (1.0 + shift));
+            let verdict = evaluation::evaluate(&shifted).verdict;
+            if verdict != base {
+                out.push(Hinge { input: field, source, shift, verdict });
+                break;
+            }
+        }
+    }
+    out
+}
+
+/// Overall confidence grade: High, Medium, or Low.
+pub fn grade(quality: &Quality, hinges: &[Hinge]) -> &'static str {
+    let guessed = quality.fields().iter().filter(|(_, s)| *s == Some(Source::Guessed)).count();
+    let unmeasured = quality.fields().iter().filter(|(_, s)| matches!(s, Some(Source::Modeled | Source::Guessed))).count();
+    if hinges.iter().any(|h| h.source == Source::Guessed) || guessed >= 2 {
+        "Low"
+    } else if !hinges.is_empty() || guessed == 1 || unmeasured >= 2 {
+        "Medium"
+    } else {
+        "High"
+    }
+}
+
+pub fn print_quality_report(inputs: &Inputs, quality: &Quality) {
+    let hinges = hinges(inputs, quality);
+    let sources = quality.fields().iter().map(|(name, s)| format!("{} {}", name.name(), s.map(|s| s.name()).unwrap_or("unspecified"))).collect::<Vec<_>>().join(", ");
+    println!("\nInput confidence:");
+    wrapln!(" - Sources: {}.", sources);
+    wrapln!(" - Confidence in the verdict: {}.", grade(quality, &hinges));
+    for h in &hinges {
+        wrapln!(" - If {} ({}) were {}% {}, the verdict would become: {}", h.input.name(), h.source.name(), precision::fixed(h.shift.abs() * 100.0, 0), if h.shift < 0.0 { "lower" } else { "higher" }, h.verdict);
+    }
+    for h in hinges.iter().filter(|h| h.source == Source::Guessed) {
+        println!("\nWarning: The verdict hinges on a guessed {}. Measure it before acting on this result.", h.input.name());
+    }
+}
@@ -0,0 +1,101 @@
+// QuickBooks Online import: the Profit and Loss report for the period, with every account under
+// Expenses and Other Expenses (sub-accounts included) as an expense line for
+// `accounting::report`.
+
+use clap::Args;
+use serde::Deserialize;
+
+use crate::accounting::{self, AccountingArgs, ExpenseLine};
+use crate::history::date_from_days;
+use crate::import::{self, Imported, Importer, OutputArgs};
+
+const EXPENSE_GROUPS: [&str; 2] = ["Expenses", "OtherExpenses"];
+
+#[derive(Args, Debug)]
+pub struct QuickBooksArgs {
+    /// Company (realm) ID (default: the QUICKBOOKS_REALM_ID environment variable)
+    #[arg(long)]
+    pub realm_id: Option<String>,
+
+    /// OAuth access token with the accounting scope (default: the QUICKBOOKS_ACCESS_TOKEN environment variable)
+    #[arg(long)]
+    pub access_token: Option<String>,
+
+    /// API base URL; https://sandbox-quickbooks.api.intuit.com for a sandbox company
+    #[arg(long, default_value = "https://quickbooks.api.intuit.com")]
+    pub api_url: String,
+
+    #[command(flatten)]
+    pub accounting: AccountingArgs,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Deserialize, Debug)]
+struct Report {
+    #[serde(rename = "Rows", default)]
+    rows: Rows,
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct Rows {
+    #[serde(rename = "Row", default)]
+    row: Vec<Row>,
+}
+
+/// A section (with a header, nested rows, and a summary) or a data row (with column values).
+#[derive(Deserialize, Debug)]
+struct Row {
+    #[serde(rename = "type")]
+    kind: Option<String>,
+    group: Option<String>,
+    #[serde(rename = "ColData", default)]
+    columns: Vec<Column>,
+    #[serde(rename = "Rows", default)]
+    rows: Rows,
+}
+
+#[derive(Deserialize, Debug)]
+struct Column {
+    #[serde(default)]
+    value: String,
+}
+
+/// Data rows under `rows`, descending into sub-account sections; the total is the last column.
+fn expense_lines(rows: &Rows, out: &mut Vec<ExpenseLine>) {
+    for row in &rows.row {
+        if row.kind.as_deref() == Some("Data") {
+            let (Some(name), Some(total)) = (row.columns.first(), row.columns.last()) else { continue };
+            out.push(ExpenseLine { account: name.value.clone(), amount: total.value.parse().unwrap_or(0.0) });
+        } else {
+            expense_lines(&row.rows, out);
+        }
+    }
+}
+
+impl Importer for QuickBooksArgs {
+    fn name(&self) -> &'static str {
+        "QuickBooks"
+    }
+
+    fn output(&self) -> &OutputArgs {
+        &self.output
+    }
+
+    fn import(&self) -> Result<Imported, String> {
+        let realm = import::credential(&self.realm_id, "realm-id", "QUICKBOOKS_REALM_ID")?;
+        let token = import::credential(&self.access_token, "access-token", "QUICKBOOKS_ACCESS_TOKEN")?;
+        let (since, until) = self.accounting.period.days()?;
+        let url = format!("{}/v3/company/{}/reports/ProfitAndLoss", self.api_url.trim_end_matches('/'), realm);
+        let bearer = format!("Bearer {}", token);
+        let query = [("start_date", date_from_days(since)), ("end_date", date_from_days(until)), ("minorversion", "65".to_string())];
+        let report: Report = import::get_with(&url, &[("Authorization", &bearer), ("Accept", "application/json")], &query)?;
+
+        let mut lines = Vec::new();
+        for section in report.rows.row.iter().filter(|r| r.group.as_deref().is_some_and(|g| EXPENSE_GROUPS.contains(&g))) {
+            expense_lines(&section.rows, &mut lines);
+        }
+        accounting::report(self.name(), &lines, &self.accounting)
+    }
+}
@@ -0,0 +1,153 @@
+// Radar chart against a benchmark: each spoke is one metric, scaled to the user's peer percentile,
+// so metrics in different units (and with lower-is-better payback) share one picture. The peer
+// median sits at the 50th percentile on every spoke, a regular polygon to compare the user's against.
+
+use crate::benchmark::{self, Benchmark};
+use crate::chart_data::ChartData;
+use crate::evaluation::{ratio_text, Evaluation};
+use crate::precision;
+use crate::theme;
+
+/// One spoke: the metric, the user's value as shown, and the user's peer percentile.
+#[derive(Debug, Clone)]
+pub struct Spoke {
+    pub metric: &'static str,
+    pub value: String,
+    pub percentile: f64,
+}
+
+const MEDIAN: f64 = 50.0;
+
+/// Spokes for the metrics both the user and the benchmark have; payback needs --early-gp-rate and
+/// margin needs --operating-margin plus a benchmark that lists it.
+pub fn spokes(e: &Evaluation, b: &Benchmark, operating_margin: Option<f64>) -> Vec<Spoke> {
+    let i = &e.inputs;
+    let mut out = vec![Spoke { metric: "LTGP:CAC", value: ratio_text(e.ratio), percentile: benchmark::percentile(e.ratio, &b.ratio) }];
+    if let Some(m) = e.cac_payback_months {
+        out.push(Spoke { metric: "Payback", value: format!("{} mo", precision::fixed(m, 1)), percentile: benchmark::percentile(m, &b.payback_months) });
+    }
+    let coverage = if i.cac > 0.0 { i.cfa / i.cac } else { 0.0 };
+    out.push(Spoke { metric: "CFA coverage", value: format!("{}%", precision::fixed(coverage * 100.0, 0)), percentile: benchmark::percentile(coverage, &b.cfa_coverage) });
+    if let (Some(margin), Some(points)) = (operating_margin, &b.operating_margin) {
+        out.push(Spoke { metric: "Margin", value: format!("{}%", precision::fixed(margin, 0)), percentile: benchmark::percentile(margin, points) });
+    }
+    out
+}
+
+/// 62 → "62nd".
+fn ordinal(pct: f64) -> String {
+    let n = pct.round() as u32;
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+/// Unit vector of spoke `k` of `n`, starting at the top and going clockwise (y grows downward).
+fn direction(k: usize, n: usize) -> (f64, f64) {
+    let angle = -std::f64::consts::FRAC_PI_2 + k as f64 * std::f64::consts::TAU / n as f64;
+    (angle.cos(), angle.sin())
+}
+
+const COLS: usize = 49;
+const ROWS: usize = 23;
+
+/// Grid cell for a point in chart units (-1..1 on both axes). Terminal cells are about twice as
+/// tall as wide, so the x radius is twice the y radius to keep the chart round.
+fn cell((x, y): (f64, f64)) -> (usize, usize) {
+    let (cx, cy) = ((COLS / 2) as f64, (ROWS / 2) as f64);
+    (((cy + y * cy).round() as usize).min(ROWS - 1), ((cx + x * cx).round() as usize).min(COLS - 1))
+}
+
+/// Draws a straight line, leaving earlier marks alone except the spokes underneath.
+fn line(grid: &mut [Vec<char>], from: (f64, f64), to: (f64, f64), c: char) {
+    for s in 0..=COLS {
+        let t = s as f64 / COLS as f64;
+        let (row, col) = cell((from.0 + (to.0 - from.0) * t, from.1 + (to.1 - from.1) * t));
+        if grid[row][col] == ' ' || grid[row][col] == '·' {
+            grid[row][col] = c;
+        }
+    }
+}
+
+/// Each spoke's percentile among the peers.
+pub fn chart_data(spokes: &[Spoke], data: &mut ChartData) {
+    for s in spokes {
+        data.push("radar", "percentile", s.metric, s.percentile);
+    }
+}
+
+pub fn print_radar(spokes: &[Spoke], b: &Benchmark) {
+    if spokes.len() < 3 {
+        println!("\nRadar chart needs at least three metrics; provide --early-gp-rate or --operating-margin.");
+        return;
+    }
+    let n = spokes.len();
+    let point = |k: usize, pct: f64| {
+        let (dx, dy) = direction(k, n);
+        (dx * pct / 100.0, dy * pct / 100.0)
+    };
+    let mut grid = vec![vec![' '; COLS]; ROWS];
+    // Lines only fill blanks and spokes, so your points and shape are drawn before the median's.
+    for k in 0..n {
+        line(&mut grid, (0.0, 0.0), point(k, 100.0), '·');
+    }
+    for (k, spoke) in spokes.iter().enumerate() {
+        let (row, col) = cell(point(k, spoke.percentile));
+        grid[row][col] = '●';
+        let (row, col) = cell(point(k, 100.0));
+        grid[row][col] = char::from_digit(k as u32 + 1, 10).unwrap_or('?');
+    }
+    for k in 0..n {
+        let next = (k + 1) % n;
+        line(&mut grid, point(k, spokes[k].percentile), point(next, spokes[next].percentile), '*');
+    }
+    for k in 0..n {
+        line(&mut grid, point(k, MEDIAN), point((k + 1) % n, MEDIAN), 'o');
+    }
+
+    println!("\nRadar vs {} peers (● * = you, o = peer median, numbered tips = 100th percentile):", b.name);
+    for cells in &grid {
+        println!("   {}", cells.iter().collect::<String>().trim_end());
+    }
+    for (k, spoke) in spokes.iter().enumerate() {
+        println!(" {}. {:<13} {:>9}  {} percentile", k + 1, spoke.metric, spoke.value, ordinal(spoke.percentile));
+    }
+}
+
+/// Standalone SVG of the radar chart.
+pub fn svg(spokes: &[Spoke], b: &Benchmark) -> String {
+    let (size, c, r) = (440.0, 220.0, 150.0);
+    let n = spokes.len();
+    let xy = |k: usize, pct: f64| {
+        let (dx, dy) = direction(k, n);
+        (c + dx * r * pct / 100.0, c + dy * r * pct / 100.0)
+    };
+    let polygon = |pct: &dyn Fn(usize) -> f64| (0..n).map(|k| {
+        let (x, y) = xy(k, pct(k));
+        format!("{},{}", precision::fixed(x, 1), precision::fixed(y, 1))
+    }).collect::<Vec<_>>().join(" ");
+
+    let theme = theme::active();
+    let (grid, guide, muted, you) = (&theme.grid, &theme.guide, &theme.muted, theme.hex("blue"));
+    let mut out = theme.svg_open(size, size + 30.0, 12);
+    out.push_str(&format!(r#"<text x="{c}" y="20" text-anchor="middle" font-size="14">Peer percentile vs {name}</text>"#, name = b.name));
+    for ring in [25.0, 50.0, 75.0, 100.0] {
+        out.push_str(&format!(r#"<polygon points="{p}" fill="none" stroke="{grid}"/>"#, p = polygon(&|_| ring)));
+    }
+    for (k, spoke) in spokes.iter().enumerate() {
+        let (x, y) = xy(k, 100.0);
+        out.push_str(&format!(r#"<line x1="{c}" y1="{c}" x2="{x:.1}" y2="{y:.1}" stroke="{grid}"/>"#));
+        let (lx, ly) = xy(k, 118.0);
+        out.push_str(&format!(r#"<text x="{lx:.1}" y="{ly:.1}" text-anchor="middle">{m} {v} ({p})</text>"#, m = spoke.metric, v = spoke.value, p = ordinal(spoke.percentile)));
+    }
+    out.push_str(&format!(r#"<polygon points="{p}" fill="none" stroke="{guide}" stroke-dasharray="4 3"/>"#, p = polygon(&|_| MEDIAN)));
+    out.push_str(&format!(r#"<polygon points="{p}" fill="{you}" fill-opacity="0.3" stroke="{you}" stroke-width="2"/>"#, p = polygon(&|k| spokes[k].percentile)));
+    out.push_str(&format!(r#"<text x="{c}" y="{y}" text-anchor="middle" fill="{muted}">Blue: you. Dashed: peer median.</text>"#, y = size + 15.0));
+    out.push_str("</svg>\n");
+    out
+}
@@ -0,0 +1,17 @@
+// The recommended-levers section of the report, from ltgp_core::recommend.
+
+pub use ltgp_core::recommend::*;
+
+use crate::evaluation::Evaluation;
+use crate::layout;
+
+pub fn print_recommendations(base: &Evaluation) {
+    let levers = levers(base);
+    if levers.is_empty() {
+        return;
+    }
+    println!("\nRecommended levers (most important first):");
+    for (n, l) in levers.iter().enumerate() {
+        println!("{}", layout::fit(&format!(" {}. {} → {}.", n + 1, l.action, l.effect)));
+    }
+}
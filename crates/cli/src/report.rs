@@ -0,0 +1,27 @@
+// The core report (see ltgp_core::report) for the terminal: severities in their theme colors, and
+// icons unless `--no-icons`.
+
+pub use ltgp_core::report::*;
+
+use crate::layout;
+use crate::severity::{self, Severity};
+
+struct Terminal;
+
+impl Style for Terminal {
+    fn icon(&self, level: Severity) -> &'static str {
+        layout::icon(level)
+    }
+
+    fn paint(&self, level: Severity, text: &str) -> String {
+        severity::paint(level, text)
+    }
+}
+
+pub fn render_report(r: &EvaluationResult) -> String {
+    ltgp_core::report::render_report(r, &Terminal)
+}
+
+pub fn render_compact_report(r: &EvaluationResult) -> String {
+    ltgp_core::report::render_compact_report(r, &Terminal)
+}
@@ -0,0 +1,137 @@
+// Retention check for the LTGP assumption: from a constant monthly churn rate or an observed
+// retention curve, project how many customers are still active each month and the gross profit
+// they bring in, then compare that projection with the LTGP the evaluation was given.
+
+use crate::chart_data::ChartData;
+use crate::evaluation::{money, monthly_gp, Inputs};
+use crate::precision;
+
+/// The projection stops once fewer than this share of customers remain, or at `MAX_MONTHS`.
+const MIN_SURVIVAL: f64 = 0.01;
+const MAX_MONTHS: usize = 120;
+/// A curve's last month-over-month retention carries on past its end, capped here.
+const MAX_RETENTION: f64 = 0.99;
+
+const WIDTH: usize = 60;
+const SURVIVAL_HEIGHT: usize = 8;
+const GP_HEIGHT: usize = 12;
+
+#[derive(Debug, Clone)]
+pub enum Model {
+    /// Share of customers lost each month (0.05 = 5%).
+    Churn(f64),
+    /// Share of the acquired customers active in each month since acquisition, first month first.
+    Curve(Vec<f64>),
+}
+
+impl Model {
+    /// Share still active in month `m` (0-based); the first month counts everyone.
+    fn survival(&self, m: usize) -> f64 {
+        match self {
+            Model::Churn(c) => (1.0 - c.clamp(0.0, 1.0)).powi(m as i32),
+            Model::Curve(points) => match points.get(m) {
+                Some(s) => s.clamp(0.0, 1.0),
+                None => {
+                    let last = points.last().copied().unwrap_or(1.0).clamp(0.0, 1.0);
+                    let before = if points.len() >= 2 { points[points.len() - 2] } else { 1.0 };
+                    let step = if before > 0.0 { (last / before).min(MAX_RETENTION) } else { 0.0 };
+                    last * step.powi((m + 1 - points.len()) as i32)
+                }
+            },
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Model::Churn(c) => format!("{}% monthly churn", precision::fixed(c * 100.0, 1)),
+            Model::Curve(points) => format!("retention curve over {} months, extended at its last month's rate", points.len()),
+        }
+    }
+}
+
+/// (survival, cumulative gross profit per acquired customer) by month.
+pub fn project(model: &Model, gp_per_month: f64) -> Vec<(f64, f64)> {
+    let mut out = Vec::new();
+    let mut cumulative = 0.0;
+    for m in 0..MAX_MONTHS {
+        let s = model.survival(m);
+        if m > 0 && s < MIN_SURVIVAL {
+            break;
+        }
+        cumulative += gp_per_month * s;
+        out.push((s, cumulative));
+    }
+    out
+}
+
+fn chart(values: &[f64], top: f64, height: usize, marker: char, line: Option<f64>) -> Vec<String> {
+    let n = values.len();
+    let col = |m: usize| if n > 1 { m * (WIDTH - 1) / (n - 1) } else { 0 };
+    let row = |v: f64| height - 1 - ((v / top) * (height - 1) as f64).round().clamp(0.0, (height - 1) as f64) as usize;
+    let mut grid = vec![vec![' '; WIDTH]; height];
+    if let Some(level) = line {
+        let r = row(level);
+        for cell in grid[r].iter_mut() {
+            *cell = '-';
+        }
+    }
+    for (m, v) in values.iter().enumerate() {
+        grid[row(*v)][col(m)] = marker;
+    }
+    grid.into_iter().map(|cells| cells.into_iter().collect()).collect()
+}
+
+/// Survival and cumulative gross profit per acquired customer by month (1-based).
+pub fn chart_data(inputs: &Inputs, model: &Model, data: &mut ChartData) {
+    let gp = monthly_gp(inputs);
+    if gp <= 0.0 {
+        return;
+    }
+    for (m, (survival, cumulative)) in project(model, gp).into_iter().enumerate() {
+        data.push_xy("retention", "survival", (m + 1) as f64, survival);
+        data.push_xy("retention", "cumulative_gross_profit", (m + 1) as f64, cumulative);
+    }
+}
+
+pub fn print_retention_plot(inputs: &Inputs, model: &Model) {
+    let gp = monthly_gp(inputs);
+    if gp <= 0.0 {
+        println!("\nRetention plot: no early gross profit to project. Provide --early-gp-rate.");
+        return;
+    }
+    let points = project(model, gp);
+    let implied = points.last().map(|p| p.1).unwrap_or_default();
+    let months = points.len();
+
+    println!("\nRetention projection ({}, {} gross profit per active customer per month):", model.describe(), money(gp));
+    println!("  Customers still active");
+    let survival: Vec<f64> = points.iter().map(|p| p.0).collect();
+    for (r, line) in chart(&survival, 1.0, SURVIVAL_HEIGHT, '•', None).iter().enumerate() {
+        let label = match r {
+            0 => "100%".to_string(),
+            _ if r == SURVIVAL_HEIGHT - 1 => "0%".to_string(),
+            _ => String::new(),
+        };
+        println!("{:>10} │{}", label, line);
+    }
+    println!("  Cumulative gross profit per customer (- = entered LTGP {})", money(inputs.ltgp));
+    let top = implied.max(inputs.ltgp).max(1.0) * 1.05;
+    let cumulative: Vec<f64> = points.iter().map(|p| p.1).collect();
+    for (r, line) in chart(&cumulative, top, GP_HEIGHT, '*', Some(inputs.ltgp)).iter().enumerate() {
+        let label = match r {
+            0 => money(top),
+            _ if r == GP_HEIGHT - 1 => "$0".to_string(),
+            _ => String::new(),
+        };
+        println!("{:>10} │{}", label, line);
+    }
+    println!("{:>10} └{}", "", "─".repeat(WIDTH));
+    println!("{:>10}  1{:>w$}  months", "", months, w = WIDTH - 1);
+
+    let gap = if inputs.ltgp > 0.0 { (implied / inputs.ltgp - 1.0) * 100.0 } else { 0.0 };
+    let reach = points.iter().position(|p| p.1 >= inputs.ltgp).map(|m| format!("reaches the entered LTGP in month {}", m + 1)).unwrap_or_else(|| "never reaches the entered LTGP".to_string());
+    println!(" Retention implies LTGP of {} over {} months ({}% vs entered) and {}.", money(implied), months, precision::signed(gap, 0), reach);
+    if gap < -20.0 {
+        println!(" The entered LTGP looks optimistic for this retention; check the lifetime assumption.");
+    }
+}
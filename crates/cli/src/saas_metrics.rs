@@ -0,0 +1,242 @@
+// Subscription analytics imports: ChartMogul, Baremetrics, and ProfitWell (Paddle) already track
+// ARPU, churn, and LTV, so instead of copying them off a dashboard this averages them over the last
+// few months and maps them into the calculator. The early gross profit rate is ARPU times the gross
+// margin, per month; LTGP is the tool's LTV times the margin, or ARPU times margin over monthly
+// churn when it reports no LTV (ProfitWell's churn is what its revenue retention leaves). None of
+// them knows upfront cash or CAC. ChartMogul and Baremetrics report money in cents, ProfitWell in
+// dollars.
+
+use clap::Args;
+use serde::Deserialize;
+
+use crate::evaluation::money;
+use crate::history::{date_from_days, today_days};
+use crate::import::{self, Imported, Importer, OutputArgs};
+use crate::locale;
+use crate::period::Period;
+use crate::precision;
+
+/// Days to reach back per month averaged.
+const DAYS_PER_MONTH: i64 = 31;
+
+#[derive(Args, Debug, Clone)]
+pub struct MetricsArgs {
+    /// Gross margin on subscription revenue, as a fraction (0.8 = 80%)
+    #[arg(long, value_parser = locale::number, default_value_t = 1.0)]
+    pub gross_margin: f64,
+
+    /// Average the metrics over this many most recent months
+    #[arg(long, default_value_t = 3)]
+    pub months: u32,
+
+    /// Customer acquisition cost to save alongside the imported inputs
+    #[arg(long, value_parser = locale::number)]
+    pub cac: Option<f64>,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct ChartMogulArgs {
+    /// ChartMogul API key (default: the CHARTMOGUL_API_KEY environment variable)
+    #[arg(long)]
+    pub api_key: Option<String>,
+
+    /// ChartMogul API base URL
+    #[arg(long, default_value = "https://api.chartmogul.com")]
+    pub api_url: String,
+
+    #[command(flatten)]
+    pub metrics: MetricsArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct ProfitWellArgs {
+    /// ProfitWell API token (default: the PROFITWELL_API_KEY environment variable)
+    #[arg(long)]
+    pub api_key: Option<String>,
+
+    /// ProfitWell API base URL
+    #[arg(long, default_value = "https://api.profitwell.com")]
+    pub api_url: String,
+
+    #[command(flatten)]
+    pub metrics: MetricsArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct BaremetricsArgs {
+    /// Baremetrics API key (default: the BAREMETRICS_API_KEY environment variable)
+    #[arg(long)]
+    pub api_key: Option<String>,
+
+    /// Baremetrics API base URL
+    #[arg(long, default_value = "https://api.baremetrics.com")]
+    pub api_url: String,
+
+    #[command(flatten)]
+    pub metrics: MetricsArgs,
+}
+
+/// One reporting period's figures, in dollars and percent.
+struct Entry {
+    arpu: f64,
+    churn_pct: Option<f64>,
+    ltv: Option<f64>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ChartMogulMetrics {
+    entries: Vec<ChartMogulEntry>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ChartMogulEntry {
+    arpa: f64,
+    #[serde(rename = "customer-churn-rate")]
+    customer_churn_rate: Option<f64>,
+    ltv: Option<f64>,
+}
+
+#[derive(Deserialize, Debug)]
+struct BaremetricsMetrics {
+    metrics: Vec<BaremetricsEntry>,
+}
+
+#[derive(Deserialize, Debug)]
+struct BaremetricsEntry {
+    arpu: f64,
+    user_churn: Option<f64>,
+    ltv: Option<f64>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ProfitWellMetrics {
+    data: ProfitWellData,
+}
+
+#[derive(Deserialize, Debug)]
+struct ProfitWellData {
+    #[serde(default)]
+    average_revenue_per_user: Vec<ProfitWellPoint>,
+    #[serde(default)]
+    revenue_retention: Vec<ProfitWellPoint>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ProfitWellPoint {
+    value: f64,
+}
+
+/// First and last dates of the window, as the APIs take them.
+fn window(months: u32) -> (String, String) {
+    let today = today_days();
+    (date_from_days(today - months.max(1) as i64 * DAYS_PER_MONTH), date_from_days(today))
+}
+
+fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, n) = values.fold((0.0, 0), |(sum, n), v| (sum + v, n + 1));
+    (n > 0).then(|| sum / n as f64)
+}
+
+/// Averages `entries` and prints how LTGP was reached; `churn_label` names the kind of churn reported.
+fn report(source: &str, churn_label: &str, entries: &[Entry], args: &MetricsArgs) -> Imported {
+    let margin = args.gross_margin;
+    let arpu = mean(entries.iter().map(|e| e.arpu).filter(|v| *v > 0.0));
+    let churn = mean(entries.iter().filter_map(|e| e.churn_pct));
+    let ltv = mean(entries.iter().filter_map(|e| e.ltv).filter(|v| *v > 0.0));
+
+    println!("\n=== {} import ===\n", source);
+    println!("Averaged {} reporting periods over the last {} months:", entries.len(), args.months);
+    println!("  {:<15}{}", "ARPU", arpu.map(money).unwrap_or_else(|| "not reported".to_string()));
+    println!("  {:<15}{}", churn_label, churn.map(|c| format!("{}% per month", precision::fixed(c, 2))).unwrap_or_else(|| "not reported".to_string()));
+    println!("  {:<15}{}", "LTV", ltv.map(money).unwrap_or_else(|| "not reported".to_string()));
+    let ltgp = match (ltv, arpu, churn) {
+        (Some(ltv), _, _) => {
+            println!("\nLTGP = {}'s LTV × {}% gross margin.", source, precision::fixed(margin * 100.0, 0));
+            Some(ltv * margin)
+        }
+        (None, Some(arpu), Some(churn)) if churn > 0.0 => {
+            println!("\nLTGP = ARPU × {}% gross margin ÷ {}% monthly churn.", precision::fixed(margin * 100.0, 0), precision::fixed(churn, 2));
+            Some(arpu * margin / (churn / 100.0))
+        }
+        (None, Some(_), Some(_)) => {
+            println!("\nChurn is zero or negative (net expansion), so LTGP can't be projected from it; set it yourself.");
+            None
+        }
+        _ => None,
+    };
+    if margin >= 1.0 {
+        println!("Gross margin is taken as 100%; pass --gross-margin to net out delivery costs.");
+    }
+    Imported { cac: args.cac, cfa: None, ltgp, early_gp_rate: arpu.map(|a| a * margin), period: Some(Period::Months), ..Imported::default() }
+}
+
+impl Importer for ChartMogulArgs {
+    fn name(&self) -> &'static str {
+        "ChartMogul"
+    }
+
+    fn output(&self) -> &OutputArgs {
+        &self.metrics.output
+    }
+
+    fn import(&self) -> Result<Imported, String> {
+        let key = import::credential(&self.api_key, "api-key", "CHARTMOGUL_API_KEY")?;
+        import::check_gross_margin(self.metrics.gross_margin)?;
+        let (start, end) = window(self.metrics.months);
+        let url = format!("{}/v1/metrics/all", self.api_url.trim_end_matches('/'));
+        let query = [("start-date", start), ("end-date", end), ("interval", "month".to_string())];
+        let metrics: ChartMogulMetrics = import::get(&url, &import::basic_auth(&key), &query)?;
+        let entries: Vec<Entry> = metrics.entries.iter().map(|e| Entry { arpu: e.arpa / 100.0, churn_pct: e.customer_churn_rate, ltv: e.ltv.map(|v| v / 100.0) }).collect();
+        Ok(report(self.name(), "Customer churn", &entries, &self.metrics))
+    }
+}
+
+impl Importer for BaremetricsArgs {
+    fn name(&self) -> &'static str {
+        "Baremetrics"
+    }
+
+    fn output(&self) -> &OutputArgs {
+        &self.metrics.output
+    }
+
+    fn import(&self) -> Result<Imported, String> {
+        let key = import::credential(&self.api_key, "api-key", "BAREMETRICS_API_KEY")?;
+        import::check_gross_margin(self.metrics.gross_margin)?;
+        let (start, end) = window(self.metrics.months);
+        let url = format!("{}/v1/metrics", self.api_url.trim_end_matches('/'));
+        let query = [("start_date", start), ("end_date", end)];
+        let metrics: BaremetricsMetrics = import::get(&url, &format!("Bearer {}", key), &query)?;
+        let entries: Vec<Entry> = metrics.metrics.iter().map(|e| Entry { arpu: e.arpu / 100.0, churn_pct: e.user_churn, ltv: e.ltv.map(|v| v / 100.0) }).collect();
+        Ok(report(self.name(), "Customer churn", &entries, &self.metrics))
+    }
+}
+
+impl Importer for ProfitWellArgs {
+    fn name(&self) -> &'static str {
+        "ProfitWell"
+    }
+
+    fn output(&self) -> &OutputArgs {
+        &self.metrics.output
+    }
+
+    /// Revenue churn is what revenue retention leaves: 97% retained is 3% churned.
+    fn import(&self) -> Result<Imported, String> {
+        let key = import::credential(&self.api_key, "api-key", "PROFITWELL_API_KEY")?;
+        import::check_gross_margin(self.metrics.gross_margin)?;
+        let url = format!("{}/v2/metrics/monthly/", self.api_url.trim_end_matches('/'));
+        let query = [("metrics", "average_revenue_per_user,revenue_retention".to_string())];
+        let metrics: ProfitWellMetrics = import::get(&url, &key, &query)?;
+        let recent = |points: &[ProfitWellPoint]| points[points.len().saturating_sub(self.metrics.months as usize)..].iter().map(|p| p.value).collect::<Vec<_>>();
+        let arpu = recent(&metrics.data.average_revenue_per_user);
+        let retention = recent(&metrics.data.revenue_retention);
+        // Retention may come as a fraction (0.97) or a percentage (97).
+        let churn = retention.iter().map(|r| 100.0 - if *r <= 2.0 { r * 100.0 } else { *r });
+        let entries: Vec<Entry> = arpu.iter().zip(churn.map(Some).chain(std::iter::repeat(None))).map(|(arpu, churn_pct)| Entry { arpu: *arpu, churn_pct, ltv: None }).collect();
+        Ok(report(self.name(), "Revenue churn", &entries, &self.metrics))
+    }
+}
@@ -0,0 +1,121 @@
+// Salesforce import: closed-won opportunities through a SOQL query over the REST API, mapped into
+// acquisition records for `crm::report` the same way HubSpot deals are. The first invoice is the
+// opportunity Amount unless `--cfa-field` names a field that holds it, and the source is the
+// LeadSource picklist unless `--source-field` says otherwise.
+
+use std::collections::HashMap;
+
+use clap::Args;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::crm::{self, Acquisition, CrmArgs};
+use crate::history::{date_from_days, days_from_date};
+use crate::import::{self, Imported, Importer, OutputArgs, PeriodArgs};
+
+const API_VERSION: &str = "v61.0";
+
+#[derive(Args, Debug)]
+pub struct SalesforceArgs {
+    /// Your org's instance URL, e.g. https://acme.my.salesforce.com (default: the SALESFORCE_INSTANCE_URL environment variable)
+    #[arg(long)]
+    pub instance_url: Option<String>,
+
+    /// OAuth access token with API access (default: the SALESFORCE_ACCESS_TOKEN environment variable)
+    #[arg(long)]
+    pub access_token: Option<String>,
+
+    /// Opportunity field holding the first invoice's cash (CFA)
+    #[arg(long, default_value = "Amount")]
+    pub cfa_field: String,
+
+    /// Opportunity field holding the lead source
+    #[arg(long, default_value = "LeadSource")]
+    pub source_field: String,
+
+    /// REST API version
+    #[arg(long, default_value = API_VERSION)]
+    pub api_version: String,
+
+    #[command(flatten)]
+    pub crm: CrmArgs,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct QueryResult {
+    records: Vec<HashMap<String, Value>>,
+    next_records_url: Option<String>,
+}
+
+fn acquisition(record: &HashMap<String, Value>, args: &SalesforceArgs) -> Option<Acquisition> {
+    let text = |field: &str| record.get(field).and_then(Value::as_str).filter(|v| !v.is_empty());
+    // CreatedDate is a datetime (`2026-03-14T09:30:00.000+0000`), CloseDate a date.
+    let day = |field: &str| text(field).and_then(|v| v.get(..10)).and_then(days_from_date);
+    Some(Acquisition {
+        source: text(&args.source_field).map(str::to_string),
+        first_invoice: record.get(&args.cfa_field).and_then(Value::as_f64),
+        created: day("CreatedDate")?,
+        closed: day("CloseDate")?,
+    })
+}
+
+/// Won opportunities closed in the period, following `nextRecordsUrl` until the query is done.
+fn fetch(args: &SalesforceArgs, instance: &str, token: &str) -> Result<Vec<HashMap<String, Value>>, String> {
+    let (since, until) = args.crm.period.days()?;
+    let soql = format!(
+        "SELECT CreatedDate, CloseDate, {}, {} FROM Opportunity WHERE IsWon = true AND CloseDate >= {} AND CloseDate <= {}",
+        args.cfa_field,
+        args.source_field,
+        date_from_days(since),
+        date_from_days(until)
+    );
+    let bearer = format!("Bearer {}", token);
+    let mut page: QueryResult = import::get(&format!("{}/services/data/{}/query", instance, args.api_version), &bearer, &[("q", soql)])?;
+    let mut all = std::mem::take(&mut page.records);
+    while let Some(next) = page.next_records_url.take() {
+        page = import::get(&format!("{}{}", instance, next), &bearer, &[])?;
+        all.append(&mut page.records);
+    }
+    Ok(all)
+}
+
+impl SalesforceArgs {
+    fn acquisitions(&self) -> Result<Vec<Acquisition>, String> {
+        let instance = import::credential(&self.instance_url, "instance-url", "SALESFORCE_INSTANCE_URL")?;
+        let token = import::credential(&self.access_token, "access-token", "SALESFORCE_ACCESS_TOKEN")?;
+        let records = fetch(self, instance.trim_end_matches('/'), &token)?;
+        Ok(records.iter().filter_map(|r| acquisition(r, self)).collect())
+    }
+
+    /// Opportunities won in `period` with the default fields, for imports that need the acquisitions.
+    pub fn acquisitions_in(period: &PeriodArgs) -> Result<Vec<Acquisition>, String> {
+        let args = SalesforceArgs {
+            instance_url: None,
+            access_token: None,
+            cfa_field: "Amount".to_string(),
+            source_field: "LeadSource".to_string(),
+            api_version: API_VERSION.to_string(),
+            crm: CrmArgs { period: period.clone(), spend: None },
+            output: OutputArgs { out: None, name: None },
+        };
+        args.acquisitions()
+    }
+}
+
+impl Importer for SalesforceArgs {
+    fn name(&self) -> &'static str {
+        "Salesforce"
+    }
+
+    fn output(&self) -> &OutputArgs {
+        &self.output
+    }
+
+    fn import(&self) -> Result<Imported, String> {
+        crm::report(self.name(), &self.acquisitions()?, &self.crm)
+    }
+}
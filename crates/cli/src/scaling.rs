@@ -0,0 +1,116 @@
+// Scale economics: how CAC responds as you push more volume through your channels.
+
+use crate::evaluation::money;
+use crate::layout::wrapln;
+use crate::locale::Locale;
+use crate::precision;
+
+/// CAC rises by `pct_per_step` percent for every `step` additional customers per month.
+#[derive(Debug, Clone, Copy)]
+pub struct CacElasticity {
+    pub pct_per_step: f64,
+    pub step: f64,
+}
+
+impl CacElasticity {
+    /// CAC after adding `extra_volume` customers per month on top of today's volume.
+    pub fn cac_at(&self, base_cac: f64, extra_volume: f64) -> f64 {
+        base_cac * (1.0 + self.pct_per_step / 100.0).powf(extra_volume / self.step)
+    }
+
+    /// Extra customers per month at which CAC reaches `cac_ceiling`.
+    /// Some(0.0) means the ceiling is already breached; None means CAC never reaches it.
+    pub fn volume_until(&self, base_cac: f64, cac_ceiling: f64) -> Option<f64> {
+        if base_cac >= cac_ceiling { return Some(0.0); }
+        if self.pct_per_step <= 0.0 || self.step <= 0.0 || base_cac <= 0.0 { return None; }
+        Some(self.step * (cac_ceiling / base_cac).ln() / (1.0 + self.pct_per_step / 100.0).ln())
+    }
+}
+
+pub fn print_elasticity_report(e: &CacElasticity, cac: f64, ltgp: f64, low_cac_thresh: f64, monthly_volume: Option<f64>) {
+    let total = |extra: f64| monthly_volume.map(|v| format!(" (≈ {} customers/month in total)", precision::fixed(v + extra, 0))).unwrap_or_default();
+
+    println!("\nScale check (CAC rises {}% per +{} customers/month):", precision::fixed(e.pct_per_step, 2), precision::fixed(e.step, 0));
+    match e.volume_until(cac, ltgp / 3.0) {
+        Some(v) if v <= 0.0 => wrapln!(" - LTGP:CAC is already at or below 3 at today's volume; adding volume only makes it worse."),
+        Some(v) => wrapln!(" - LTGP:CAC stays above 3 until about +{} customers/month{}, where CAC reaches {}.", precision::fixed(v, 0), total(v), money(ltgp / 3.0)),
+        None => wrapln!(" - CAC does not rise with volume, so LTGP:CAC holds at any scale."),
+    }
+    match e.volume_until(cac, low_cac_thresh) {
+        Some(v) if v <= 0.0 => wrapln!(" - CAC is already above the low-CAC cut of {}.", money(low_cac_thresh)),
+        Some(v) => wrapln!(" - CAC stays in the low band until about +{} customers/month{}.", precision::fixed(v, 0), total(v)),
+        None => wrapln!(" - CAC stays in the low band at any scale."),
+    }
+    wrapln!(" - At +{} customers/month, CAC would be about {}.", precision::fixed(e.step * 5.0, 0), money(e.cac_at(cac, e.step * 5.0)));
+}
+
+/// Diminishing-returns acquisition curve: customers = scale × spend^exponent.
+#[derive(Debug, Clone, Copy)]
+pub struct SpendCurve {
+    pub scale: f64,
+    pub exponent: f64,
+}
+
+impl SpendCurve {
+    /// Least-squares fit in log-log space over (spend, customers) points.
+    pub fn fit(points: &[(f64, f64)]) -> Result<SpendCurve, String> {
+        let logs: Vec<(f64, f64)> = points.iter().filter(|(s, c)| *s > 0.0 && *c > 0.0).map(|(s, c)| (s.ln(), c.ln())).collect();
+        if logs.len() < 2 { return Err("need at least two history points with positive spend and customers".to_string()); }
+        let n = logs.len() as f64;
+        let mean_x = logs.iter().map(|p| p.0).sum::<f64>() / n;
+        let mean_y = logs.iter().map(|p| p.1).sum::<f64>() / n;
+        let sxx: f64 = logs.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+        let sxy: f64 = logs.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+        if sxx == 0.0 { return Err("history points need at least two distinct spend levels".to_string()); }
+        let exponent = sxy / sxx;
+        if exponent <= 0.0 { return Err("customers do not increase with spend in this history".to_string()); }
+        Ok(SpendCurve { scale: (mean_y - exponent * mean_x).exp(), exponent })
+    }
+
+    pub fn customers_at(&self, spend: f64) -> f64 {
+        self.scale * spend.powf(self.exponent)
+    }
+
+    /// Blended CAC at a spend level (spend ÷ customers).
+    pub fn average_cac(&self, spend: f64) -> f64 {
+        spend / self.customers_at(spend)
+    }
+
+    /// Cost of the next customer at a spend level (dSpend/dCustomers).
+    pub fn marginal_cac(&self, spend: f64) -> f64 {
+        self.average_cac(spend) / self.exponent
+    }
+}
+
+/// Reads `spend,customers` rows; blank lines, `#` comments, and a header row are skipped.
+pub fn read_spend_history(path: &str) -> Result<Vec<(f64, f64)>, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path, e))?;
+    let mut points = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') { continue; }
+        let fields: Vec<&str> = line.split(',').map(|f| f.trim()).collect();
+        let parsed = match fields.as_slice() {
+            [spend, customers] => Locale::EN.parse(spend).zip(Locale::EN.parse(customers)),
+            _ => None,
+        };
+        match parsed {
+            Some(point) => points.push(point),
+            None if i == 0 => continue,
+            None => return Err(format!("{}:{}: expected `spend,customers`", path, i + 1)),
+        }
+    }
+    Ok(points)
+}
+
+pub fn print_marginal_cac_report(curve: &SpendCurve, current_spend: f64, ltgp: f64) {
+    let average = curve.average_cac(current_spend);
+    let marginal = curve.marginal_cac(current_spend);
+    println!("\nMarginal CAC (fitted from spend history, customers ∝ spend^{}):", precision::fixed(curve.exponent, 2));
+    wrapln!(" - At {}/period of spend you get about {} customers.", money(current_spend), precision::fixed(curve.customers_at(current_spend), 1));
+    wrapln!(" - Average (blended) CAC: {}; the next customer costs about {}.", money(average), money(marginal));
+    wrapln!(" - Marginal LTGP:CAC: {} (blended: {}).", precision::fixed(ltgp / marginal, 2), precision::fixed(ltgp / average, 2));
+    if ltgp / marginal <= 3.0 && ltgp / average > 3.0 {
+        println!("\nWarning: Blended economics look fine, but the marginal customer is already underwater (marginal LTGP:CAC ≤ 3). Extra spend at this level destroys value.");
+    }
+}
@@ -0,0 +1,144 @@
+// Scenario flags: the base inputs every subcommand takes, from a scenario file (see
+// ltgp_core::scenario), individual flags, or both.
+//
+// Out-of-range inputs, such as a negative CFA, stop the run with an error naming each one.
+// `--clamp` opts in to moving them to the nearest valid value instead, with a warning on
+// stderr for every adjustment:
+//
+//   Warning: CFA -50 adjusted to 0 (--clamp)
+//
+// Inputs in range are then held to the config file's guard rails (see guard_rails), which
+// `--clamp` doesn't touch.
+
+use std::sync::OnceLock;
+
+use clap::Args;
+
+use ltgp_core::input;
+
+use crate::evaluation::Inputs;
+use crate::guard_rails;
+use crate::locale;
+use crate::period::Period;
+
+static CLAMP: OnceLock<bool> = OnceLock::new();
+
+/// Sets whether out-of-range inputs are clamped rather than rejected, for the rest of the run.
+pub fn set_clamp(on: bool) {
+    let _ = CLAMP.set(on);
+}
+
+/// `inputs` once in range, clamped (with a warning per adjustment) under `--clamp` and
+/// rejected otherwise; `context`, when not empty, says where the inputs came from.
+pub fn checked(mut inputs: Inputs, context: &str) -> Result<Inputs, String> {
+    let prefix = if context.is_empty() { String::new() } else { format!("{}: ", context) };
+    let clamp = *CLAMP.get_or_init(|| false);
+    if clamp {
+        for adjusted in input::clamp(&mut inputs) {
+            eprintln!("Warning: {}{} (--clamp)", prefix, adjusted);
+        }
+    }
+    let inputs = input::validate(inputs).map_err(|e| {
+        if clamp { format!("{}{}", prefix, e) } else { format!("{}{} (or pass --clamp to move out-of-range values to the nearest valid one)", prefix, e) }
+    })?;
+    guard_rails::check(&inputs).map_err(|e| format!("{}{}", prefix, e))?;
+    Ok(inputs)
+}
+
+/// A flag that can't be negative: as given when it isn't, moved to 0 with a warning under
+/// `--clamp`, and rejected otherwise.
+pub fn non_negative(flag: &str, value: f64) -> Result<f64, String> {
+    if !value.is_finite() {
+        return Err(format!("--{} must be a finite number, got {}", flag, value));
+    }
+    if value >= 0.0 {
+        return Ok(value);
+    }
+    if *CLAMP.get_or_init(|| false) {
+        eprintln!("Warning: --{} {} adjusted to 0 (--clamp)", flag, value);
+        Ok(0.0)
+    } else {
+        Err(format!("--{} can't be negative, got {} (or pass --clamp to move out-of-range values to the nearest valid one)", flag, value))
+    }
+}
+
+/// `non_negative`, exiting on an error.
+pub fn non_negative_or_exit(flag: &str, value: f64) -> f64 {
+    non_negative(flag, value).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(2);
+    })
+}
+
+pub use ltgp_core::scenario::*;
+
+/// Base inputs for subcommands: a scenario file, individual flags, or both (flags win).
+#[derive(Args, Debug, Clone)]
+pub struct ScenarioArgs {
+    /// Scenario file (TOML) to start from
+    #[arg(long)]
+    pub scenario: Option<String>,
+
+    /// Cost to acquire a customer (CAC) in dollars
+    #[arg(long, value_parser = locale::number)]
+    pub cac: Option<f64>,
+
+    /// Upfront cash from the customer (CFA) in dollars
+    #[arg(long, value_parser = locale::number)]
+    pub cfa: Option<f64>,
+
+    /// Lifetime Gross Profit (LTGP) in dollars
+    #[arg(long, value_parser = locale::number)]
+    pub ltgp: Option<f64>,
+
+    /// Early gross profit per period
+    #[arg(long, value_parser = locale::number)]
+    pub early_gp_rate: Option<f64>,
+
+    /// Period unit for the early gross profit rate
+    #[arg(long, value_enum, ignore_case = true)]
+    pub period: Option<Period>,
+
+    /// Consider CAC 'low' if CAC < threshold_fraction * LTGP
+    #[arg(long, value_parser = locale::number)]
+    pub low_cac_fraction: Option<f64>,
+
+    /// Consider CFA 'high' if CFA ≥ threshold_fraction * CAC
+    #[arg(long, value_parser = locale::number)]
+    pub high_cfa_fraction: Option<f64>,
+
+    /// Consider CAC 'low' at or below this many dollars (overrides --low-cac-fraction)
+    #[arg(long, value_parser = locale::number)]
+    pub low_cac_max: Option<f64>,
+
+    /// Consider CFA 'high' at or above this many dollars (overrides --high-cfa-fraction)
+    #[arg(long, value_parser = locale::number)]
+    pub high_cfa_min: Option<f64>,
+}
+
+impl ScenarioArgs {
+    pub fn resolve(&self) -> Result<Scenario, String> {
+        let mut s = match &self.scenario {
+            Some(path) => Scenario::load(path)?,
+            None => Scenario::new(self.cac.ok_or("provide --cac or --scenario")?, self.ltgp.ok_or("provide --ltgp or --scenario")?),
+        };
+        if let Some(v) = self.cac { s.cac = v; }
+        if let Some(v) = self.cfa { s.cfa = v; }
+        if let Some(v) = self.ltgp { s.ltgp = v; }
+        if let Some(v) = self.early_gp_rate { s.early_gp_rate = v; }
+        if let Some(v) = self.period { s.period = v; }
+        if let Some(v) = self.low_cac_fraction { s.low_cac_fraction = v; }
+        if let Some(v) = self.high_cfa_fraction { s.high_cfa_fraction = v; }
+        if self.low_cac_max.is_some() { s.low_cac_max = self.low_cac_max; }
+        if self.high_cfa_min.is_some() { s.high_cfa_min = self.high_cfa_min; }
+        Ok(s)
+    }
+
+    /// Resolves the inputs or exits with the error, for use at the top of a subcommand.
+    pub fn inputs_or_exit(&self) -> Inputs {
+        self.resolve().and_then(|s| checked(s.raw_inputs(), "")).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(2);
+        })
+    }
+}
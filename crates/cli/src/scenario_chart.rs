@@ -0,0 +1,95 @@
+// Grouped bar chart for scenario comparisons: LTGP:CAC, payback, and net outlay, one group per
+// metric with a bar per scenario, so the tradeoffs between options read as one picture. Each
+// group has its own scale since the metrics don't share units.
+
+use crate::chart_data::ChartData;
+use crate::evaluation::{money, Evaluation};
+use crate::precision;
+use crate::rules::COLORS;
+use crate::theme;
+
+const BAR_WIDTH: usize = 36;
+
+struct Metric {
+    key: &'static str,
+    title: &'static str,
+    value: fn(&Evaluation) -> Option<f64>,
+    show: fn(f64) -> String,
+}
+
+const METRICS: [Metric; 3] = [
+    Metric { key: "ltgp_cac", title: "LTGP:CAC (higher is better)", value: |e| Some(e.ratio).filter(|r| r.is_finite()), show: |v| precision::fixed(v, 2) },
+    Metric { key: "payback_days", title: "Payback in days (lower is better)", value: |e| e.payback_days(), show: |v| precision::fixed(v, 1) },
+    Metric { key: "net_outlay", title: "Net upfront outlay (lower is better)", value: |e| Some(e.net_outlay), show: money },
+];
+
+/// Scenario `n`'s bar color, a rule color name.
+fn color(n: usize) -> &'static str {
+    ["blue", "yellow", "magenta", "cyan", "green", "red"][n % COLORS.len()]
+}
+
+/// Each scenario's value per metric; metrics a scenario has no value for are left out.
+pub fn chart_data(scenarios: &[(&str, &Evaluation)], data: &mut ChartData) {
+    for metric in &METRICS {
+        for (name, e) in scenarios {
+            if let Some(v) = (metric.value)(e) {
+                data.push("scenario_chart", name, metric.key, v);
+            }
+        }
+    }
+}
+
+pub fn print_chart(scenarios: &[(&str, &Evaluation)]) {
+    let name_width = scenarios.iter().map(|(name, _)| name.chars().count()).max().unwrap_or(0).min(24);
+    println!("\nScenario chart:");
+    for metric in &METRICS {
+        let values: Vec<Option<f64>> = scenarios.iter().map(|(_, e)| (metric.value)(e)).collect();
+        let top = values.iter().flatten().copied().fold(0.0, f64::max);
+        println!("  {}", metric.title);
+        for (n, ((name, _), value)) in scenarios.iter().zip(&values).enumerate() {
+            let name: String = name.chars().take(name_width).collect();
+            match value {
+                Some(v) => {
+                    let len = if top > 0.0 { ((v.max(0.0) / top) * BAR_WIDTH as f64).round() as usize } else { 0 };
+                    let bar = theme::paint(color(n), &"█".repeat(len));
+                    println!("    {:<nw$}  {}{} {}", name, bar, " ".repeat(BAR_WIDTH - len), (metric.show)(*v), nw = name_width);
+                }
+                None => println!("    {:<nw$}  {:<bw$} n/a", name, "", nw = name_width, bw = BAR_WIDTH),
+            }
+        }
+    }
+}
+
+/// Standalone SVG: the three metric groups side by side, a colored bar per scenario, and a legend.
+pub fn svg(scenarios: &[(&str, &Evaluation)]) -> String {
+    let (group_w, height, top, left, gap) = (200.0, 220.0, 30.0, 20.0, 30.0);
+    let bar_w = (group_w - 20.0) / scenarios.len().max(1) as f64;
+    let legend_top = top + height + 50.0;
+    let width = left + METRICS.len() as f64 * (group_w + gap);
+    let theme = theme::active();
+    let axis = &theme.axis;
+    let mut out = theme.svg_open(width, legend_top + scenarios.len() as f64 * 18.0 + 10.0, 11);
+    for (g, metric) in METRICS.iter().enumerate() {
+        let x0 = left + g as f64 * (group_w + gap);
+        let values: Vec<Option<f64>> = scenarios.iter().map(|(_, e)| (metric.value)(e)).collect();
+        let top_value = values.iter().flatten().copied().fold(0.0, f64::max);
+        for (n, value) in values.iter().enumerate() {
+            let x = x0 + 10.0 + n as f64 * bar_w;
+            let label = value.map(metric.show).unwrap_or_else(|| "n/a".to_string());
+            let h = match value {
+                Some(v) if top_value > 0.0 => v.max(0.0) / top_value * height,
+                _ => 0.0,
+            };
+            out.push_str(&format!(r#"<rect x="{x:.1}" y="{y:.1}" width="{w:.1}" height="{h:.1}" fill="{fill}"><title>{name}: {label}</title></rect>"#, y = top + height - h, w = bar_w - 4.0, fill = theme.hex(color(n)), name = scenarios[n].0));
+            out.push_str(&format!(r#"<text x="{tx:.1}" y="{ty:.1}" text-anchor="middle">{label}</text>"#, tx = x + (bar_w - 4.0) / 2.0, ty = top + height - h - 4.0));
+        }
+        out.push_str(&format!(r#"<line x1="{x0}" x2="{x1}" y1="{b}" y2="{b}" stroke="{axis}"/>"#, x1 = x0 + group_w, b = top + height));
+        out.push_str(&format!(r#"<text x="{tx}" y="{ty}" text-anchor="middle">{title}</text>"#, tx = x0 + group_w / 2.0, ty = top + height + 18.0, title = metric.title));
+    }
+    for (n, (name, _)) in scenarios.iter().enumerate() {
+        let y = legend_top + n as f64 * 18.0;
+        out.push_str(&format!(r#"<rect x="{left}" y="{y}" width="12" height="12" fill="{fill}"/><text x="{tx}" y="{ty}">{name}</text>"#, fill = theme.hex(color(n)), tx = left + 18.0, ty = y + 10.0));
+    }
+    out.push_str("</svg>\n");
+    out
+}
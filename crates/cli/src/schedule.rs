@@ -0,0 +1,216 @@
+// Scheduled runs: a five-field cron expression (minute, hour, day of month, month, day of week,
+// in UTC) and the evaluation it repeats, set up in the config file:
+//
+//   [schedule]
+//   name = "Weekly unit economics"
+//   scenario = "base.toml"                  # inputs the imports don't supply
+//   imports = [["stripe"], ["hubspot", "--spend", "12000"]]
+//   save_run = "runs.csv"                   # history, for the change since the last run
+//
+// Each run imports afresh, in order, with later imports overriding earlier ones and both
+// overriding the scenario, then evaluates and sends the result wherever the run is configured
+// to: Airtable, `--notify`, and `--webhook`.
+
+use clap::Parser;
+use serde::Deserialize;
+
+use crate::airtable;
+use crate::audit;
+use crate::evaluation;
+use crate::format::{Formatter, Summary};
+use crate::history::{self, civil_from_days};
+use crate::import::Source;
+use crate::notify;
+use crate::report::EvaluationResult;
+use crate::scenario::{self, ScenarioArgs};
+use crate::webhook;
+
+/// How far ahead to look for the next run before calling a schedule one that never fires.
+const HORIZON_MINUTES: i64 = 5 * 366 * 24 * 60;
+
+const MONTHS: [&str; 12] = ["JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC"];
+const WEEKDAYS: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// A cron expression, as the set of values each field matches.
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    text: String,
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    /// Whether day of month and day of week were `*`: when both are restricted, either matching
+    /// is enough, as in standard cron.
+    any_day: bool,
+    any_weekday: bool,
+}
+
+/// One field's value: a number in `min..=max`, or a name from `names` (numbered from `min`).
+fn value(s: &str, min: u32, max: u32, names: &[&str]) -> Result<u32, String> {
+    let n = match names.iter().position(|n| n.eq_ignore_ascii_case(s)) {
+        Some(i) => i as u32 + min,
+        None => s.parse::<u32>().map_err(|_| format!("`{}` is not a number or name", s))?,
+    };
+    if n < min || n > max {
+        return Err(format!("{} is outside {}-{}", n, min, max));
+    }
+    Ok(n)
+}
+
+/// A field of `*`, values, `a-b` ranges, and `/step`s, separated by commas, as a bit set.
+fn field(s: &str, min: u32, max: u32, names: &[&str]) -> Result<u64, String> {
+    let mut bits = 0;
+    for part in s.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((r, step)) => (r, step.parse::<u32>().ok().filter(|s| *s > 0).ok_or_else(|| format!("bad step in `{}`", part))?),
+            None => (part, 1),
+        };
+        let (from, to) = match range.split_once('-') {
+            _ if range == "*" => (min, max),
+            Some((a, b)) => (value(a, min, max, names)?, value(b, min, max, names)?),
+            // `5/15` runs from 5 to the end of the field.
+            None if part.contains('/') => (value(range, min, max, names)?, max),
+            None => {
+                let v = value(range, min, max, names)?;
+                (v, v)
+            }
+        };
+        for v in (from..=to).step_by(step as usize) {
+            bits |= 1 << v;
+        }
+    }
+    Ok(bits)
+}
+
+impl Schedule {
+    /// Parses `minute hour day-of-month month day-of-week`, or @hourly, @daily, @weekly, @monthly.
+    pub fn parse(s: &str) -> Result<Schedule, String> {
+        let expanded = match s.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * SUN",
+            "@monthly" => "0 0 1 * *",
+            other => other,
+        };
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields.as_slice() else {
+            return Err(format!("expected five fields (minute hour day-of-month month day-of-week), got `{}`", s));
+        };
+        let context = |name: &'static str| move |e: String| format!("`{}`: {}: {}", s, name, e);
+        // Sunday is 0 or 7.
+        let weekdays = field(weekday, 0, 7, &WEEKDAYS).map_err(context("day of week"))?;
+        let schedule = Schedule {
+            text: s.trim().to_string(),
+            minutes: field(minute, 0, 59, &[]).map_err(context("minute"))?,
+            hours: field(hour, 0, 23, &[]).map_err(context("hour"))?,
+            days: field(day, 1, 31, &[]).map_err(context("day of month"))?,
+            months: field(month, 1, 12, &MONTHS).map_err(context("month"))?,
+            weekdays: (weekdays | weekdays >> 7) & 0x7f,
+            any_day: *day == "*",
+            any_weekday: *weekday == "*",
+        };
+        schedule.next_after(0).map(|_| schedule.clone()).ok_or_else(|| format!("`{}` never runs", s))
+    }
+
+    fn matches(&self, minute: i64) -> bool {
+        let days = minute.div_euclid(24 * 60);
+        let (_, month, day) = civil_from_days(days);
+        let weekday = (days + 4).rem_euclid(7);
+        let on = |bits: u64, v: i64| bits >> v & 1 == 1;
+        let day_ok = match (self.any_day, self.any_weekday) {
+            (false, false) => on(self.days, day) || on(self.weekdays, weekday),
+            _ => on(self.days, day) && on(self.weekdays, weekday),
+        };
+        day_ok && on(self.months, month) && on(self.hours, minute.div_euclid(60).rem_euclid(24)) && on(self.minutes, minute.rem_euclid(60))
+    }
+
+    /// The first matching minute (since the Unix epoch) after `minute`.
+    pub fn next_after(&self, minute: i64) -> Option<i64> {
+        (minute + 1..=minute + HORIZON_MINUTES).find(|m| self.matches(*m))
+    }
+}
+
+impl std::fmt::Display for Schedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// The evaluation a schedule repeats.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Job {
+    /// Name sent with results (default: the scenario's).
+    pub name: Option<String>,
+    /// Scenario file with the inputs the imports don't supply.
+    pub scenario: Option<String>,
+    /// `import` subcommands to run, as their arguments, e.g. `["stripe", "--since", "2026-01-01"]`.
+    #[serde(default)]
+    pub imports: Vec<Vec<String>>,
+    /// History file each run is appended to, as with `--save-run`.
+    pub save_run: Option<String>,
+}
+
+/// An `import` command line from the config.
+#[derive(Parser, Debug)]
+#[command(no_binary_name = true)]
+struct ImportLine {
+    #[command(subcommand)]
+    source: Source,
+}
+
+impl Job {
+    fn imports(&self) -> Result<Vec<ImportLine>, String> {
+        self.imports.iter().map(|words| ImportLine::try_parse_from(words).map_err(|e| format!("[schedule] imports {:?}: {}", words, e.to_string().lines().next().unwrap_or_default().trim_start_matches("error: ")))).collect()
+    }
+
+    /// Checks the imports parse, so a typo fails at startup rather than at the first run.
+    pub fn check(&self) -> Result<(), String> {
+        self.imports().map(|_| ())
+    }
+
+    /// Imports, evaluates, and sends the result; prints the summary line.
+    pub fn run(&self) -> Result<(), String> {
+        let mut inputs = ScenarioArgs {
+            scenario: self.scenario.clone(),
+            cac: None,
+            cfa: None,
+            ltgp: None,
+            early_gp_rate: None,
+            period: None,
+            low_cac_fraction: None,
+            high_cfa_fraction: None,
+            low_cac_max: None,
+            high_cfa_min: None,
+        };
+        for line in self.imports()? {
+            let importer = line.source.importer();
+            let imported = importer.import().map_err(|e| format!("{} import: {}", importer.name(), e))?;
+            inputs.cac = imported.cac.or(inputs.cac);
+            inputs.cfa = imported.cfa.or(inputs.cfa);
+            inputs.ltgp = imported.ltgp.or(inputs.ltgp);
+            inputs.early_gp_rate = imported.early_gp_rate.or(inputs.early_gp_rate);
+            inputs.period = imported.period.or(inputs.period);
+        }
+        let scenario = inputs.resolve().map_err(|e| format!("{} (set it in the [schedule] scenario or import it)", e))?;
+        let name = self.name.clone().or(scenario.name.clone()).unwrap_or_default();
+        let eval = evaluation::evaluate(&scenario::checked(scenario.raw_inputs(), "")?);
+        let result = EvaluationResult::from(&eval);
+
+        let previous = self.save_run.as_deref().and_then(|path| history::read(path).ok()).and_then(|runs| runs.last().cloned());
+        if let Some(path) = &self.save_run {
+            history::append(path, &result)?;
+        }
+        println!("{}", Summary.render(&result));
+        audit::record(&[(&name, &result)])?;
+        airtable::push(vec![airtable::record(&name, &result)])?;
+        notify::evaluation(&name, &result, previous.as_ref())?;
+        webhook::evaluation(&name, &result)
+    }
+}
+
+/// Minutes since the Unix epoch as `YYYY-MM-DD HH:MM UTC`.
+pub fn timestamp(minute: i64) -> String {
+    format!("{} {:02}:{:02} UTC", history::date_from_days(minute.div_euclid(24 * 60)), minute.div_euclid(60).rem_euclid(24), minute.rem_euclid(60))
+}
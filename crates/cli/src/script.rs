@@ -0,0 +1,81 @@
+// Scripting hook: a user-provided Rhai script sees the computed result and can add derived
+// metrics or replace the verdict guidance, so niche businesses can extend the analysis locally.
+//
+// The script gets a `result` map (cac, cfa, ltgp, early_gp_rate, period, ratio, net_outlay,
+// payback_days, cac_payback_months, quadrant, verdict; unknown paybacks are ()) and returns a map:
+//
+//   let margin = result.ltgp - result.cac;
+//   #{ metrics: #{ "Lifetime margin": margin }, guidance: "Good: ..." }
+//
+// Both keys are optional.
+
+use rhai::{Dynamic, Engine, Map, Scope};
+
+use crate::evaluation::Evaluation;
+use crate::layout::wrapln;
+use crate::precision;
+
+#[derive(Debug, Clone, Default)]
+pub struct ScriptOutput {
+    /// Derived metrics, sorted by name.
+    pub metrics: Vec<(String, String)>,
+    /// Replacement for the verdict line, if the script supplied one.
+    pub guidance: Option<String>,
+}
+
+fn optional(v: Option<f64>) -> Dynamic {
+    v.map(Dynamic::from_float).unwrap_or(Dynamic::UNIT)
+}
+
+fn result_map(e: &Evaluation) -> Map {
+    let i = &e.inputs;
+    let mut m = Map::new();
+    m.insert("cac".into(), Dynamic::from_float(i.cac));
+    m.insert("cfa".into(), Dynamic::from_float(i.cfa));
+    m.insert("ltgp".into(), Dynamic::from_float(i.ltgp));
+    m.insert("early_gp_rate".into(), Dynamic::from_float(i.early_gp_rate));
+    m.insert("period".into(), i.period.to_string().into());
+    m.insert("ratio".into(), Dynamic::from_float(e.ratio));
+    m.insert("net_outlay".into(), Dynamic::from_float(e.net_outlay));
+    m.insert("payback_days".into(), optional(e.payback_days()));
+    m.insert("cac_payback_months".into(), optional(e.cac_payback_months));
+    m.insert("quadrant".into(), e.quadrant.name().into());
+    m.insert("verdict".into(), e.verdict.into());
+    m
+}
+
+fn display(v: &Dynamic) -> String {
+    match v.as_float() {
+        Ok(f) => precision::fixed(f, 2),
+        Err(_) => v.to_string(),
+    }
+}
+
+/// Runs the script at `path` against the evaluation.
+pub fn run(path: &str, e: &Evaluation) -> Result<ScriptOutput, String> {
+    let source = std::fs::read_to_string(path).map_err(|err| format!("could not read {}: {}", path, err))?;
+    let engine = Engine::new();
+    let mut scope = Scope::new();
+    scope.push("result", result_map(e));
+    let returned: Dynamic = engine.eval_with_scope(&mut scope, &source).map_err(|err| format!("{}: {}", path, err))?;
+    if returned.is_unit() {
+        return Ok(ScriptOutput::default());
+    }
+    let map = returned.try_cast::<Map>().ok_or_else(|| format!("{}: the script must return a map like #{{ metrics: #{{}}, guidance: \"\" }}", path))?;
+    let metrics = match map.get("metrics") {
+        Some(m) => m.clone().try_cast::<Map>().ok_or_else(|| format!("{}: `metrics` must be a map", path))?.iter().map(|(k, v)| (k.to_string(), display(v))).collect(),
+        None => Vec::new(),
+    };
+    let guidance = map.get("guidance").filter(|g| !g.is_unit()).map(|g| g.to_string());
+    Ok(ScriptOutput { metrics, guidance })
+}
+
+pub fn print_script_metrics(path: &str, out: &ScriptOutput) {
+    if out.metrics.is_empty() {
+        return;
+    }
+    println!("\nCustom metrics ({}):", path);
+    for (name, value) in &out.metrics {
+        wrapln!(" - {}: {}", name, value);
+    }
+}
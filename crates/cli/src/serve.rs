@@ -0,0 +1,120 @@
+// Serve: keep running, as a REST API for other tools (`--listen`, see `api`; `--openapi` prints
+// its OpenAPI document), a gRPC service (`--grpc`, see `grpc`), a recurring monitor
+// (`--schedule`), or any mix of them. `serve --schedule "0 9 * * MON"` runs the `[schedule]` job from the
+// config file each Monday at 09:00 UTC; a failed run is reported and the next one still happens.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use clap::{ArgGroup, Args};
+
+#[cfg(feature = "server")]
+use crate::api;
+use crate::config::Config;
+#[cfg(feature = "server")]
+use crate::grpc;
+use crate::schedule::{self, Job, Schedule};
+
+#[derive(Args, Debug)]
+#[command(group(ArgGroup::new("mode").required(true).multiple(true).args(["listen", "grpc", "schedule", "openapi"])))]
+pub struct ServeArgs {
+    /// Serve the REST API on this address, e.g. 127.0.0.1:8080
+    #[arg(long)]
+    pub listen: Option<String>,
+
+    /// Serve the gRPC service in proto/ltgp.proto on this address, e.g. 127.0.0.1:50051
+    #[arg(long)]
+    pub grpc: Option<String>,
+
+    /// When to run, as a cron expression in UTC: minute hour day-of-month month day-of-week (e.g. "0 9 * * MON"), or @hourly, @daily, @weekly, @monthly
+    #[arg(long, value_parser = Schedule::parse)]
+    pub schedule: Option<Schedule>,
+
+    /// Also run once at startup, before waiting for the first scheduled time
+    #[arg(long, default_value_t = false, requires = "schedule")]
+    pub now: bool,
+
+    /// Print the API's OpenAPI 3 document (JSON) and exit
+    #[arg(long, default_value_t = false, conflicts_with_all = ["listen", "grpc", "schedule"])]
+    pub openapi: bool,
+}
+
+fn now_seconds() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default() as i64
+}
+
+fn run_job(job: &Job) {
+    if let Err(e) = job.run() {
+        eprintln!("Error: {}", e);
+    }
+}
+
+/// Runs `job` at every time `schedule` matches, forever.
+fn monitor(schedule: &Schedule, job: &Job, now: bool) {
+    if now {
+        run_job(job);
+    }
+    loop {
+        let now = now_seconds();
+        let next = schedule.next_after(now / 60).expect("schedule checked to fire when parsed");
+        println!("Next run ({}) at {}.", schedule, schedule::timestamp(next));
+        std::thread::sleep(Duration::from_secs((next * 60 - now).max(0) as u64));
+        run_job(job);
+    }
+}
+
+/// Exits with an error for a server mode this build was made without.
+#[cfg(not(feature = "server"))]
+pub fn unavailable(what: &str) -> ! {
+    eprintln!("Error: {} needs a build with the `server` feature", what);
+    std::process::exit(2);
+}
+
+/// Runs the servers asked for until one stops.
+#[cfg(feature = "server")]
+fn listen(args: &ServeArgs) -> Result<(), String> {
+    let runtime = tokio::runtime::Runtime::new().map_err(|e| format!("could not start the server: {}", e))?;
+    runtime.block_on(async {
+        let mut servers = tokio::task::JoinSet::new();
+        if let Some(addr) = &args.listen {
+            servers.spawn(api::serve(addr.clone()));
+        }
+        if let Some(addr) = &args.grpc {
+            servers.spawn(grpc::serve(addr.clone()));
+        }
+        match servers.join_next().await {
+            Some(stopped) => stopped.map_err(|e| format!("server failed: {}", e))?,
+            None => Ok(()),
+        }
+    })
+}
+
+pub fn run(args: &ServeArgs, config: &Config) {
+    if args.openapi {
+        #[cfg(feature = "server")]
+        return println!("{}", api::openapi());
+        #[cfg(not(feature = "server"))]
+        unavailable("serve --openapi");
+    }
+    if let Some(schedule) = args.schedule.clone() {
+        let Some(job) = config.schedule.clone() else {
+            eprintln!("Error: serve --schedule needs a [schedule] section in the config file saying what to evaluate");
+            std::process::exit(2);
+        };
+        if let Err(e) = job.check() {
+            eprintln!("Error: {}", e);
+            std::process::exit(2);
+        }
+        let now = args.now;
+        if args.listen.is_none() && args.grpc.is_none() {
+            return monitor(&schedule, &job, now);
+        }
+        std::thread::spawn(move || monitor(&schedule, &job, now));
+    }
+    #[cfg(not(feature = "server"))]
+    unavailable("serve --listen/--grpc");
+    #[cfg(feature = "server")]
+    if let Err(e) = listen(args) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
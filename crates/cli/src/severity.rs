@@ -0,0 +1,12 @@
+// Severity levels (see ltgp_core::severity) in their theme color on the terminal.
+
+pub use ltgp_core::severity::*;
+
+use colored::Colorize;
+
+use crate::theme;
+
+/// `text` in the level's color, bold; plain when stdout isn't a terminal.
+pub fn paint(severity: Severity, text: &str) -> String {
+    theme::paint(severity.color(), text).bold().to_string()
+}
@@ -0,0 +1,173 @@
+// Google Sheets: read inputs from a range and append results as rows, since a shared Sheet is
+// where most small teams keep these numbers. A sheet is given as `URL!RANGE`, e.g.
+// `https://docs.google.com/spreadsheets/d/1AbC…/edit!Inputs!A2:F20`; the URL can also be the bare
+// spreadsheet ID.
+//
+//   input rows:   name, cac, cfa, ltgp, early_gp_rate, period     (blank cells are left unset)
+//   result rows:  date, name, cac, cfa, ltgp, early_gp_rate, period, ratio, quadrant, verdict,
+//                 severity, payback_days
+//
+// Auth is an OAuth access token in GOOGLE_SHEETS_ACCESS_TOKEN, or a service account key file in
+// GOOGLE_APPLICATION_CREDENTIALS, signed into a JWT and exchanged for a token; share the Sheet
+// with the service account's email.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::clock;
+use crate::import;
+use crate::period::Period;
+use crate::report::EvaluationResult;
+use crate::sweep::verdict_label;
+use crate::uncertainty::Estimate;
+
+const API_URL: &str = "https://sheets.googleapis.com/v4/spreadsheets";
+const SCOPE: &str = "https://www.googleapis.com/auth/spreadsheets";
+
+/// A range in a spreadsheet.
+#[derive(Debug, Clone)]
+pub struct Sheet {
+    pub id: String,
+    pub range: String,
+}
+
+impl Sheet {
+    /// Parses `URL!RANGE`; the range keeps any further `!` (`Inputs!A2:F20`).
+    pub fn parse(s: &str) -> Result<Sheet, String> {
+        let (url, range) = s.split_once('!').filter(|(_, r)| !r.is_empty()).ok_or_else(|| format!("expected URL!RANGE, e.g. https://docs.google.com/spreadsheets/d/ID/edit!A2:F20, got `{}`", s))?;
+        let id = match url.split_once("/d/") {
+            Some((_, rest)) => rest.split(['/', '?', '#']).next().unwrap_or_default(),
+            None => url,
+        };
+        if id.is_empty() {
+            return Err(format!("no spreadsheet ID in `{}`", url));
+        }
+        Ok(Sheet { id: id.to_string(), range: range.to_string() })
+    }
+
+    fn url(&self, suffix: &str) -> String {
+        format!("{}/{}/values/{}{}", API_URL, self.id, import::path_segment(&self.range), suffix)
+    }
+}
+
+/// One row of inputs; None where the cell is blank.
+#[derive(Debug, Clone, Default)]
+pub struct InputRow {
+    pub name: Option<String>,
+    pub cac: Option<Estimate>,
+    pub cfa: Option<Estimate>,
+    pub ltgp: Option<Estimate>,
+    pub early_gp_rate: Option<f64>,
+    pub period: Option<Period>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ValueRange {
+    #[serde(default)]
+    values: Vec<Vec<Value>>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ServiceAccount {
+    client_email: String,
+    private_key: String,
+    token_uri: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct Token {
+    access_token: String,
+}
+
+/// A service account's key signed into a JWT and exchanged for an access token.
+fn service_account_token(path: &str) -> Result<String, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path, e))?;
+    let key: ServiceAccount = serde_json::from_str(&text).map_err(|e| format!("{}: {}", path, e))?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+    let claims = json!({ "iss": key.client_email, "scope": SCOPE, "aud": key.token_uri, "iat": now, "exp": now + 3600 });
+    let unsigned = format!("{}.{}", URL_SAFE_NO_PAD.encode(r#"{"alg":"RS256","typ":"JWT"}"#), URL_SAFE_NO_PAD.encode(claims.to_string()));
+
+    let pem: String = key.private_key.lines().filter(|l| !l.starts_with("-----")).collect();
+    let der = STANDARD.decode(pem.trim()).map_err(|e| format!("{}: private_key: {}", path, e))?;
+    let pair = ring::signature::RsaKeyPair::from_pkcs8(&der).map_err(|e| format!("{}: private_key: {}", path, e))?;
+    let mut signature = vec![0; pair.public().modulus_len()];
+    pair.sign(&ring::signature::RSA_PKCS1_SHA256, &ring::rand::SystemRandom::new(), unsigned.as_bytes(), &mut signature).map_err(|e| format!("could not sign the token request: {}", e))?;
+    let assertion = format!("{}.{}", unsigned, URL_SAFE_NO_PAD.encode(signature));
+
+    let reply = ureq::post(&key.token_uri)
+        .send_form([("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"), ("assertion", assertion.as_str())])
+        .and_then(|mut r| r.body_mut().read_to_string())
+        .map_err(|e| format!("could not fetch {}: {}", key.token_uri, e))?;
+    let token: Token = serde_json::from_str(&reply).map_err(|e| format!("{}: {}", key.token_uri, e))?;
+    Ok(token.access_token)
+}
+
+fn authorization() -> Result<String, String> {
+    let token = match (std::env::var("GOOGLE_SHEETS_ACCESS_TOKEN"), std::env::var("GOOGLE_APPLICATION_CREDENTIALS")) {
+        (Ok(token), _) => token,
+        (_, Ok(path)) => service_account_token(&path)?,
+        _ => return Err("set GOOGLE_SHEETS_ACCESS_TOKEN to an OAuth access token or GOOGLE_APPLICATION_CREDENTIALS to a service account key file".to_string()),
+    };
+    Ok(format!("Bearer {}", token))
+}
+
+/// A cell as text; numbers come back as numbers when the Sheet formats them.
+fn text(cell: Option<&Value>) -> Option<String> {
+    let s = match cell? {
+        Value::String(s) => s.trim().to_string(),
+        Value::Number(n) => n.to_string(),
+        _ => return None,
+    };
+    (!s.is_empty()).then_some(s)
+}
+
+/// Input rows in the range; a header row (no number where CAC goes) at the top is skipped.
+pub fn read(sheet: &Sheet) -> Result<Vec<InputRow>, String> {
+    let reply: ValueRange = import::get(&sheet.url(""), &authorization()?, &[])?;
+    let mut rows = Vec::new();
+    for (i, cells) in reply.values.iter().enumerate() {
+        let cell = |k: usize| text(cells.get(k));
+        let estimate = |k: usize, what: &str| cell(k).map(|s| s.parse::<Estimate>().map_err(|e| format!("{} row {}: {}: {}", sheet.range, i + 1, what, e))).transpose();
+        let cac = match estimate(1, "CAC") {
+            Err(_) if i == 0 => continue,
+            cac => cac?,
+        };
+        let early_gp_rate = estimate(4, "early gross profit rate")?.map(|e| e.mean);
+        let period = cell(5).map(|p| p.parse::<Period>().map_err(|e| format!("{} row {}: {}", sheet.range, i + 1, e))).transpose()?;
+        rows.push(InputRow { name: cell(0), cac, cfa: estimate(2, "CFA")?, ltgp: estimate(3, "LTGP")?, early_gp_rate, period });
+    }
+    if rows.is_empty() {
+        return Err(format!("{}: no input rows", sheet.range));
+    }
+    Ok(rows)
+}
+
+/// The result row for one evaluation.
+pub fn result_row(name: &str, r: &EvaluationResult) -> Vec<Value> {
+    let i = &r.inputs;
+    vec![
+        json!(clock::today()),
+        json!(name),
+        json!(i.cac),
+        json!(i.cfa),
+        json!(i.ltgp),
+        json!(i.early_gp_rate),
+        json!(i.period),
+        json!(r.ratio),
+        json!(r.quadrant),
+        json!(verdict_label(&r.verdict)),
+        json!(r.severity.level),
+        json!(r.payback_days),
+    ]
+}
+
+/// Appends `rows` after the last row of the table in the range.
+pub fn append(sheet: &Sheet, rows: Vec<Vec<Value>>) -> Result<(), String> {
+    let url = sheet.url(":append?valueInputOption=USER_ENTERED&insertDataOption=INSERT_ROWS");
+    let _: Value = import::post(&url, &[("Authorization", &authorization()?)], &json!({ "values": rows }).to_string())?;
+    Ok(())
+}
@@ -0,0 +1,178 @@
+// Shopify import: pages through order history for e-commerce economics. CFA is the cash of a
+// customer's first order; LTGP follows a repeat-purchase model, where each order is followed by
+// another with the observed repeat purchase rate, so a customer places 1 ÷ (1 − rate) orders
+// worth AOV × contribution margin each; the early gross profit rate is one order's contribution
+// per average gap between a customer's orders. Order values exclude tax and are net of refunds.
+
+use std::collections::HashMap;
+
+use clap::Args;
+use serde::Deserialize;
+
+use crate::evaluation::money;
+use crate::history::days_from_date;
+use crate::import::{self, Imported, Importer, OutputArgs};
+use crate::locale;
+use crate::period::{Period, DAYS_PER_MONTH};
+use crate::precision;
+
+const PAGE_SIZE: usize = 250;
+
+#[derive(Args, Debug)]
+pub struct ShopifyArgs {
+    /// Store name (`acme`) or domain (`acme.myshopify.com`)
+    #[arg(long)]
+    pub shop: String,
+
+    /// Admin API access token with read_orders (default: the SHOPIFY_ACCESS_TOKEN environment variable)
+    #[arg(long)]
+    pub access_token: Option<String>,
+
+    /// Product cost (COGS) as a fraction of order value
+    #[arg(long, value_parser = locale::number, default_value_t = 0.0)]
+    pub product_cost: f64,
+
+    /// Shipping, packing, and payment cost per order, in dollars
+    #[arg(long, value_parser = locale::number, default_value_t = 0.0)]
+    pub fulfillment_cost: f64,
+
+    /// Only use orders placed on or after this date (YYYY-MM-DD)
+    #[arg(long)]
+    pub since: Option<String>,
+
+    /// Customer acquisition cost to save alongside the imported inputs
+    #[arg(long, value_parser = locale::number)]
+    pub cac: Option<f64>,
+
+    /// Admin API version
+    #[arg(long, default_value = "2024-10")]
+    pub api_version: String,
+
+    /// API base URL, instead of the store's own (e.g. a proxy or mock server)
+    #[arg(long)]
+    pub api_url: Option<String>,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Deserialize, Debug)]
+struct Orders {
+    orders: Vec<Order>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Order {
+    id: u64,
+    created_at: String,
+    current_total_price: String,
+    #[serde(default)]
+    current_total_tax: Option<String>,
+    cancelled_at: Option<String>,
+    customer: Option<Customer>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Customer {
+    id: u64,
+}
+
+impl Order {
+    /// Order value without tax, net of refunds.
+    fn value(&self) -> f64 {
+        let amount = |s: &str| s.parse::<f64>().unwrap_or(0.0);
+        amount(&self.current_total_price) - self.current_total_tax.as_deref().map(amount).unwrap_or(0.0)
+    }
+}
+
+/// Every order since `since`, oldest id first, paging with `since_id`.
+fn fetch(args: &ShopifyArgs, token: &str) -> Result<Vec<Order>, String> {
+    let base = match &args.api_url {
+        Some(url) => url.trim_end_matches('/').to_string(),
+        None if args.shop.contains('.') => format!("https://{}", args.shop),
+        None => format!("https://{}.myshopify.com", args.shop),
+    };
+    let url = format!("{}/admin/api/{}/orders.json", base, args.api_version);
+    let mut all: Vec<Order> = Vec::new();
+    loop {
+        let mut query = vec![("status", "any".to_string()), ("limit", PAGE_SIZE.to_string()), ("fields", "id,created_at,current_total_price,current_total_tax,cancelled_at,customer".to_string())];
+        if let Some(date) = &args.since {
+            query.push(("created_at_min", date.clone()));
+        }
+        query.push(("since_id", all.last().map(|o| o.id).unwrap_or(0).to_string()));
+        let page: Orders = import::get_with(&url, &[("X-Shopify-Access-Token", token)], &query)?;
+        let full = page.orders.len() >= PAGE_SIZE;
+        all.extend(page.orders);
+        if !full {
+            return Ok(all);
+        }
+    }
+}
+
+impl Importer for ShopifyArgs {
+    fn name(&self) -> &'static str {
+        "Shopify"
+    }
+
+    fn output(&self) -> &OutputArgs {
+        &self.output
+    }
+
+    fn import(&self) -> Result<Imported, String> {
+        let token = import::credential(&self.access_token, "access-token", "SHOPIFY_ACCESS_TOKEN")?;
+        if !(0.0..=1.0).contains(&self.product_cost) {
+            return Err("--product-cost must be between 0 and 1".to_string());
+        }
+        if let Some(date) = &self.since && days_from_date(date).is_none() {
+            return Err(format!("--since: expected YYYY-MM-DD, got `{}`", date));
+        }
+        let orders = fetch(self, &token)?;
+        let orders: Vec<&Order> = orders.iter().filter(|o| o.cancelled_at.is_none() && o.value() > 0.0).collect();
+        if orders.is_empty() {
+            return Err("no paid orders to work from".to_string());
+        }
+
+        // Each known customer's orders as (day, value), oldest first.
+        let mut by_customer: HashMap<u64, Vec<(i64, f64)>> = HashMap::new();
+        for o in &orders {
+            if let (Some(c), Some(day)) = (&o.customer, o.created_at.get(..10).and_then(days_from_date)) {
+                by_customer.entry(c.id).or_default().push((day, o.value()));
+            }
+        }
+        for history in by_customer.values_mut() {
+            history.sort_by_key(|(day, _)| *day);
+        }
+
+        let revenue: f64 = orders.iter().map(|o| o.value()).sum();
+        let aov = revenue / orders.len() as f64;
+        let contribution = aov * (1.0 - self.product_cost) - self.fulfillment_cost;
+        let customers = by_customer.len();
+        let repeaters = by_customer.values().filter(|h| h.len() > 1).count();
+        let repeat_rate = if customers > 0 { repeaters as f64 / customers as f64 } else { 0.0 };
+        let first_order = (customers > 0).then(|| by_customer.values().map(|h| h[0].1).sum::<f64>() / customers as f64);
+        let gaps: Vec<f64> = by_customer.values().flat_map(|h| h.windows(2).map(|w| (w[1].0 - w[0].0) as f64)).collect();
+        let gap_days = (!gaps.is_empty()).then(|| gaps.iter().sum::<f64>() / gaps.len() as f64);
+        let expected_orders = 1.0 / (1.0 - repeat_rate.min(0.99));
+
+        println!("\n=== Shopify import ===\n");
+        println!("{} paid orders from {} customers ({} guest orders left out of the repeat figures).", orders.len(), customers, orders.iter().filter(|o| o.customer.is_none()).count());
+        println!("  {:<22}{}", "AOV", money(aov));
+        println!("  {:<22}{} ({}%)", "Contribution per order", money(contribution), precision::fixed(if aov > 0.0 { contribution / aov * 100.0 } else { 0.0 }, 1));
+        println!("  {:<22}{}% of customers order again", "Repeat purchase rate", precision::fixed(repeat_rate * 100.0, 1));
+        println!("  {:<22}{}", "Days between orders", gap_days.map(|d| precision::fixed(d, 0)).unwrap_or_else(|| "no repeat orders".to_string()));
+        println!("\nLTGP = {} contribution × {} expected orders (1 ÷ (1 − {}% repeat rate)).", money(contribution), precision::fixed(expected_orders, 2), precision::fixed(repeat_rate * 100.0, 1));
+        println!("Recent customers haven't had time to reorder, so the repeat rate and LTGP lean low.");
+        if self.product_cost == 0.0 && self.fulfillment_cost == 0.0 {
+            println!("No product or fulfillment cost given; pass --product-cost and --fulfillment-cost for contribution margin.");
+        }
+
+        Ok(Imported {
+            cac: self.cac,
+            cfa: first_order,
+            ltgp: Some((contribution * expected_orders).max(0.0)),
+            early_gp_rate: gap_days.filter(|d| *d > 0.0).map(|d| contribution.max(0.0) / (d / DAYS_PER_MONTH)),
+            period: Some(Period::Months),
+            ..Imported::default()
+        })
+    }
+}
@@ -0,0 +1,72 @@
+// Canned stress tests: apply shocks to the inputs and check whether the economics still clear.
+
+use clap::ValueEnum;
+
+use crate::evaluation::{self, ratio_text, Field, Inputs};
+use crate::layout::wrapln;
+use crate::precision;
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StressPreset {
+    /// CAC +50%, LTGP −25%, CFA −50%, one at a time and combined
+    Standard,
+    /// CAC +25%, LTGP −10%, CFA −25%
+    Mild,
+    /// CAC +100%, LTGP −50%, CFA −100%
+    Severe,
+}
+
+/// One shock: multiply `field` by `factor`.
+#[derive(Debug, Clone, Copy)]
+pub struct Shock {
+    pub field: Field,
+    pub factor: f64,
+}
+
+impl StressPreset {
+    pub fn shocks(&self) -> Vec<Shock> {
+        let (cac, ltgp, cfa) = match self {
+            StressPreset::Standard => (1.50, 0.75, 0.50),
+            StressPreset::Mild => (1.25, 0.90, 0.75),
+            StressPreset::Severe => (2.00, 0.50, 0.00),
+        };
+        vec![Shock { field: Field::Cac, factor: cac }, Shock { field: Field::Ltgp, factor: ltgp }, Shock { field: Field::Cfa, factor: cfa }]
+    }
+}
+
+fn describe(shocks: &[Shock]) -> String {
+    shocks.iter().map(|s| format!("{} {}%", s.field.name(), precision::signed((s.factor - 1.0) * 100.0, 0))).collect::<Vec<_>>().join(", ")
+}
+
+fn apply(inputs: &Inputs, shocks: &[Shock]) -> Inputs {
+    let mut shocked = inputs.clone();
+    for s in shocks {
+        s.field.set(&mut shocked, s.field.get(inputs) * s.factor);
+    }
+    shocked
+}
+
+pub fn print_stress_report(inputs: &Inputs, preset: StressPreset) {
+    let shocks = preset.shocks();
+    let base = evaluation::evaluate(inputs);
+    let mut cases: Vec<(String, Vec<Shock>)> = shocks.iter().map(|s| (describe(&[*s]), vec![*s])).collect();
+    cases.push((format!("All combined ({})", describe(&shocks)), shocks.clone()));
+
+    println!("\nStress test ({}):", preset.to_possible_value().map(|v| v.get_name().to_string()).unwrap_or_default());
+    let mut failures = 0;
+    for (label, case) in &cases {
+        let e = evaluation::evaluate(&apply(inputs, case));
+        let clears = e.ratio > 3.0;
+        if !clears { failures += 1; }
+        let payback = e.payback_days().map(|d| format!(", payback {} days", precision::fixed(d, 1))).unwrap_or_default();
+        wrapln!(" - {}: LTGP:CAC {}, {}{} → {}", label, ratio_text(e.ratio), e.quadrant.name(), payback, if clears { "PASS" } else { "FAIL" });
+    }
+
+    if failures == 0 {
+        println!("\nUnder stress: The business still clears LTGP:CAC > 3 in every scenario.");
+    } else if base.ratio > 3.0 {
+        println!("\nUnder stress: {} of {} scenarios fall to LTGP:CAC ≤ 3. Today's margin of safety is thin; build a cash buffer before scaling.", failures, cases.len());
+    } else {
+        println!("\nUnder stress: The business already fails the 3:1 bar before any shock.");
+    }
+}
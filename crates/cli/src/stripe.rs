@@ -0,0 +1,272 @@
+// Stripe import: pages through charges, refunds, and subscriptions and turns real billing into
+// calculator inputs. CFA is what a customer pays, net of refunds, in the days after their first
+// charge; LTGP is each customer's net collections to date times the gross margin; the early
+// gross profit rate is the average active subscription, monthly, times the gross margin. Customers
+// are grouped into cohorts by the month of their first charge so the gross profit behind the
+// averages is visible. Amounts are taken as two-decimal currencies (cents).
+
+use std::collections::{BTreeMap, HashMap};
+
+use clap::Args;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::evaluation::money;
+use crate::history::{date_from_days, days_from_date};
+use crate::import::{self, Imported, Importer, OutputArgs};
+use crate::locale;
+use crate::period::{Period, DAYS_PER_MONTH};
+use crate::precision;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+#[derive(Args, Debug)]
+pub struct StripeArgs {
+    /// Stripe secret or restricted key with read access (default: the STRIPE_API_KEY environment variable)
+    #[arg(long)]
+    pub api_key: Option<String>,
+
+    /// Gross margin on Stripe revenue, as a fraction (0.8 = 80%)
+    #[arg(long, value_parser = locale::number, default_value_t = 1.0)]
+    pub gross_margin: f64,
+
+    /// Collections within this many days of a customer's first charge count as upfront cash (CFA)
+    #[arg(long, default_value_t = 30)]
+    pub upfront_days: u32,
+
+    /// Only use charges and refunds on or after this date (YYYY-MM-DD)
+    #[arg(long)]
+    pub since: Option<String>,
+
+    /// Currency to import; charges in other currencies are skipped
+    #[arg(long, default_value = "usd")]
+    pub currency: String,
+
+    /// Customer acquisition cost to save alongside the imported inputs (Stripe doesn't know it)
+    #[arg(long, value_parser = locale::number)]
+    pub cac: Option<f64>,
+
+    /// Stripe API base URL, e.g. a stripe-mock server for trying the import out
+    #[arg(long, default_value = "https://api.stripe.com")]
+    pub api_url: String,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Deserialize, Debug)]
+struct Page<T> {
+    data: Vec<T>,
+    has_more: bool,
+}
+
+#[derive(Deserialize, Debug)]
+struct Charge {
+    id: String,
+    amount: i64,
+    currency: String,
+    customer: Option<String>,
+    created: i64,
+    status: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct Refund {
+    id: String,
+    amount: i64,
+    currency: String,
+    charge: Option<String>,
+    created: i64,
+    status: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Subscription {
+    id: String,
+    customer: String,
+    items: Page<Item>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Item {
+    price: Price,
+    quantity: Option<u64>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Price {
+    unit_amount: Option<i64>,
+    currency: String,
+    recurring: Option<Recurring>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Recurring {
+    interval: String,
+    interval_count: u32,
+}
+
+impl Item {
+    /// Recurring revenue per month, in major units; one-off prices count as nothing.
+    fn monthly(&self, currency: &str) -> f64 {
+        let Some(recurring) = &self.price.recurring else { return 0.0 };
+        if !self.price.currency.eq_ignore_ascii_case(currency) {
+            return 0.0;
+        }
+        let per_interval = self.price.unit_amount.unwrap_or(0) as f64 / 100.0 * self.quantity.unwrap_or(1) as f64;
+        let months = match recurring.interval.as_str() {
+            "day" => 1.0 / DAYS_PER_MONTH,
+            "week" => 7.0 / DAYS_PER_MONTH,
+            "year" => 12.0,
+            _ => 1.0,
+        } * recurring.interval_count.max(1) as f64;
+        per_interval / months
+    }
+}
+
+/// Every object in a Stripe list endpoint, following `starting_after` until `has_more` is false.
+fn list<T: DeserializeOwned>(args: &StripeArgs, key: &str, path: &str, query: &[(&str, String)], id: fn(&T) -> &str) -> Result<Vec<T>, String> {
+    let url = format!("{}{}", args.api_url.trim_end_matches('/'), path);
+    let mut all: Vec<T> = Vec::new();
+    loop {
+        let mut params = query.to_vec();
+        params.push(("limit", "100".to_string()));
+        if let Some(last) = all.last() {
+            params.push(("starting_after", id(last).to_string()));
+        }
+        let page: Page<T> = import::get(&url, &format!("Bearer {}", key), &params)?;
+        let more = page.has_more && !page.data.is_empty();
+        all.extend(page.data);
+        if !more {
+            return Ok(all);
+        }
+    }
+}
+
+/// One cohort of customers by first-charge month.
+struct Cohort {
+    customers: usize,
+    upfront: f64,
+    collected: f64,
+    subscribed: usize,
+}
+
+struct Derived {
+    imported: Imported,
+    cohorts: BTreeMap<String, Cohort>,
+    customers: usize,
+    skipped: usize,
+}
+
+fn derive(args: &StripeArgs, charges: &[Charge], refunds: &[Refund], subscriptions: &[Subscription]) -> Derived {
+    let currency = args.currency.to_lowercase();
+    let mut skipped = 0;
+    let mut owner: HashMap<&str, &str> = HashMap::new();
+    // (customer, time, amount) for every collection and refund, in major units.
+    let mut flows: Vec<(&str, i64, f64)> = Vec::new();
+    for c in charges.iter().filter(|c| c.status == "succeeded") {
+        match &c.customer {
+            Some(customer) if c.currency == currency => {
+                owner.insert(&c.id, customer);
+                flows.push((customer, c.created, c.amount as f64 / 100.0));
+            }
+            _ => skipped += 1,
+        }
+    }
+    for r in refunds.iter().filter(|r| r.status.as_deref().is_none_or(|s| s == "succeeded") && r.currency == currency) {
+        if let Some(customer) = r.charge.as_deref().and_then(|id| owner.get(id)) {
+            flows.push((customer, r.created, -(r.amount as f64) / 100.0));
+        }
+    }
+
+    let first: HashMap<&str, i64> = flows.iter().filter(|f| f.2 > 0.0).fold(HashMap::new(), |mut first, (customer, t, _)| {
+        first.entry(*customer).and_modify(|f: &mut i64| *f = (*f).min(*t)).or_insert(*t);
+        first
+    });
+    let mut upfront: HashMap<&str, f64> = HashMap::new();
+    let mut collected: HashMap<&str, f64> = HashMap::new();
+    for (customer, t, amount) in &flows {
+        let Some(start) = first.get(customer) else { continue };
+        *collected.entry(customer).or_default() += amount;
+        if t - start <= args.upfront_days as i64 * SECONDS_PER_DAY {
+            *upfront.entry(customer).or_default() += amount;
+        }
+    }
+    let mut monthly: HashMap<&str, f64> = HashMap::new();
+    for s in subscriptions {
+        *monthly.entry(&s.customer).or_default() += s.items.data.iter().map(|i| i.monthly(&currency)).sum::<f64>();
+    }
+    monthly.retain(|_, m| *m > 0.0);
+
+    let mut cohorts: BTreeMap<String, Cohort> = BTreeMap::new();
+    for (customer, start) in &first {
+        let month = date_from_days(start.div_euclid(SECONDS_PER_DAY))[..7].to_string();
+        let cohort = cohorts.entry(month).or_insert(Cohort { customers: 0, upfront: 0.0, collected: 0.0, subscribed: 0 });
+        cohort.customers += 1;
+        cohort.upfront += upfront.get(customer).copied().unwrap_or_default();
+        cohort.collected += collected.get(customer).copied().unwrap_or_default();
+        cohort.subscribed += monthly.contains_key(customer) as usize;
+    }
+
+    let customers = first.len();
+    let average = |total: f64, n: usize| (n > 0).then(|| total / n as f64);
+    let margin = args.gross_margin;
+    let imported = Imported {
+        cac: args.cac,
+        cfa: average(upfront.values().sum(), customers).map(|v| v.max(0.0)),
+        ltgp: average(collected.values().sum(), customers).map(|v| v.max(0.0) * margin),
+        early_gp_rate: average(monthly.values().sum(), monthly.len()).map(|v| v * margin),
+        period: Some(Period::Months),
+        ..Imported::default()
+    };
+    Derived { imported, cohorts, customers, skipped }
+}
+
+fn print_report(args: &StripeArgs, d: &Derived, charges: usize, refunds: usize, subscriptions: usize) {
+    println!("\n=== Stripe import ===\n");
+    println!("Fetched {} charges, {} refunds, and {} active subscriptions; {} customers paid in {}.", charges, refunds, subscriptions, d.customers, args.currency.to_uppercase());
+    if d.skipped > 0 {
+        println!("Skipped {} successful charges in other currencies or without a customer.", d.skipped);
+    }
+    if d.cohorts.is_empty() {
+        return;
+    }
+    let margin = args.gross_margin;
+    println!("\nCohorts by first charge ({}-day upfront window, {}% gross margin):", args.upfront_days, precision::fixed(margin * 100.0, 0));
+    println!("  {:<8}  {:>9}  {:>12}  {:>14}  {:>10}", "Month", "Customers", "Avg upfront", "Avg GP to date", "Subscribed");
+    for (month, c) in &d.cohorts {
+        let n = c.customers.max(1) as f64;
+        println!("  {:<8}  {:>9}  {:>12}  {:>14}  {:>10}", month, c.customers, money(c.upfront / n), money(c.collected / n * margin), c.subscribed);
+    }
+    println!("\nLTGP is gross profit collected so far; customers still subscribed will add to it, so treat it as a floor.");
+    if margin >= 1.0 {
+        println!("Gross margin is taken as 100%; pass --gross-margin to net out delivery costs.");
+    }
+}
+
+impl Importer for StripeArgs {
+    fn name(&self) -> &'static str {
+        "Stripe"
+    }
+
+    fn output(&self) -> &OutputArgs {
+        &self.output
+    }
+
+    fn import(&self) -> Result<Imported, String> {
+        let key = import::credential(&self.api_key, "api-key", "STRIPE_API_KEY")?;
+        import::check_gross_margin(self.gross_margin)?;
+        let mut since = Vec::new();
+        if let Some(date) = &self.since {
+            let days = days_from_date(date).ok_or_else(|| format!("--since: expected YYYY-MM-DD, got `{}`", date))?;
+            since.push(("created[gte]", (days * SECONDS_PER_DAY).to_string()));
+        }
+        let charges: Vec<Charge> = list(self, &key, "/v1/charges", &since, |c: &Charge| &c.id)?;
+        let refunds: Vec<Refund> = list(self, &key, "/v1/refunds", &since, |r: &Refund| &r.id)?;
+        let subscriptions: Vec<Subscription> = list(self, &key, "/v1/subscriptions", &[("status", "active".to_string())], |s: &Subscription| &s.id)?;
+
+        let derived = derive(self, &charges, &refunds, &subscriptions);
+        print_report(self, &derived, charges.len(), refunds.len(), subscriptions.len());
+        Ok(derived.imported)
+    }
+}
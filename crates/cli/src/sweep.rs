@@ -0,0 +1,116 @@
+// Parameter sweep: re-evaluate across a range of one input to show where the boundaries sit, or
+// across two inputs at once as a heatmap of verdict zones.
+
+use clap::Args;
+
+use crate::chart_data::{self, ChartData};
+use crate::evaluation::{self, ratio_text, Field};
+use crate::heatmap;
+use crate::layout::icon;
+use crate::locale;
+use crate::precision;
+use crate::scenario::ScenarioArgs;
+use crate::severity::Severity;
+
+#[derive(Args, Debug)]
+pub struct SweepArgs {
+    #[command(flatten)]
+    pub base: ScenarioArgs,
+
+    /// Input to vary
+    #[arg(long, value_enum)]
+    pub var: Field,
+
+    /// First value of the sweep
+    #[arg(long, value_parser = locale::number)]
+    pub from: f64,
+
+    /// Last value of the sweep (inclusive)
+    #[arg(long, value_parser = locale::number)]
+    pub to: f64,
+
+    /// Increment between values
+    #[arg(long, value_parser = locale::number)]
+    pub step: f64,
+
+    /// Second input to vary, for a two-way grid drawn as a heatmap of verdict zones
+    #[arg(long, value_enum, requires_all = ["from2", "to2", "step2"])]
+    pub var2: Option<Field>,
+
+    /// First value of the second input
+    #[arg(long, value_parser = locale::number)]
+    pub from2: Option<f64>,
+
+    /// Last value of the second input (inclusive)
+    #[arg(long, value_parser = locale::number)]
+    pub to2: Option<f64>,
+
+    /// Increment between values of the second input
+    #[arg(long, value_parser = locale::number)]
+    pub step2: Option<f64>,
+
+    /// Also write the two-way heatmap as an SVG image to this path
+    #[arg(long, requires = "var2")]
+    pub heatmap_svg: Option<String>,
+
+    /// Write the two-way heatmap's cells to a CSV file (`chart,series,x,y`, one series per verdict)
+    #[arg(long, requires = "var2")]
+    pub chart_data: Option<String>,
+}
+
+/// Values from `from` to `to` inclusive, tolerant of floating-point drift at the end.
+pub fn steps(from: f64, to: f64, step: f64) -> Vec<f64> {
+    if step == 0.0 || (to - from) * step < 0.0 { return vec![from]; }
+    let n = ((to - from) / step + 1e-9).floor() as usize;
+    (0..=n).map(|i| from + step * i as f64).collect()
+}
+
+pub use crate::evaluation::verdict_label;
+
+pub fn run(args: &SweepArgs) {
+    let base = args.base.inputs_or_exit();
+    if args.step == 0.0 {
+        eprintln!("Error: --step must not be zero");
+        std::process::exit(2);
+    }
+    if let (Some(var2), Some(from2), Some(to2), Some(step2)) = (args.var2, args.from2, args.to2, args.step2) {
+        if step2 == 0.0 {
+            eprintln!("Error: --step2 must not be zero");
+            std::process::exit(2);
+        }
+        let grid = heatmap::Grid::new(&base, (args.var, steps(args.from, args.to, args.step)), (var2, steps(from2, to2, step2)));
+        grid.print();
+        if let Some(path) = &args.heatmap_svg {
+            match std::fs::write(path, grid.svg()) {
+                Ok(()) => println!("\nHeatmap written to {}.", path),
+                Err(e) => {
+                    eprintln!("Error: could not write {}: {}", path, e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        let mut data = ChartData::new();
+        grid.chart_data(&mut data);
+        chart_data::save(&data, args.chart_data.as_deref());
+        return;
+    }
+
+    println!("\n=== Sweep: {} from {} to {} (step {}) ===\n", args.var.name(), args.from, args.to, args.step);
+    println!("{:>12}  {:>9}  {:<24}  {:>16}  Verdict", args.var.name(), "LTGP:CAC", "Quadrant", "Payback");
+    let mut previous: Option<(evaluation::Quadrant, &'static str)> = None;
+    for value in steps(args.from, args.to, args.step) {
+        let mut inputs = base.clone();
+        args.var.set(&mut inputs, value);
+        let e = evaluation::evaluate(&inputs);
+        let payback = e.payback.map(|p| format!("{} {}", precision::fixed(p, 1), inputs.period)).unwrap_or_else(|| "-".to_string());
+        let marker = match previous {
+            Some((q, _)) if q != e.quadrant => "  ← quadrant changes",
+            Some((_, v)) if v != e.verdict => "  ← verdict changes",
+            _ => "",
+        };
+        let special = e.degenerate().map(|d| format!("  {}{}", icon(Severity::Watch), d.headline())).unwrap_or_default();
+        let line = format!("{:>12}  {:>9}  {:<24}  {:>16}  {:<14}{}{}", precision::fixed(value, 2), ratio_text(e.ratio), e.quadrant.name(), payback, verdict_label(e.verdict), marker, special);
+        println!("{}", line.trim_end());
+        previous = Some((e.quadrant, e.verdict));
+    }
+}
@@ -0,0 +1,30 @@
+// The active theme's colors (see ltgp_core::theme) as terminal colors.
+
+pub use ltgp_core::theme::*;
+
+use colored::{Color, ColoredString, Colorize};
+
+/// Terminal color for a rule color name in the active theme.
+pub fn color(color: &str) -> Color {
+    let theme = active();
+    match theme.terminal() {
+        Terminal::Named => Color::from(color),
+        Terminal::Bright => match Color::from(color) {
+            Color::Green => Color::BrightGreen,
+            Color::Cyan => Color::BrightCyan,
+            Color::Blue => Color::BrightBlue,
+            Color::Yellow => Color::BrightYellow,
+            Color::Magenta => Color::BrightMagenta,
+            _ => Color::BrightRed,
+        },
+        Terminal::Truecolor => {
+            let (r, g, b) = rgb(theme.hex(color));
+            Color::TrueColor { r, g, b }
+        }
+    }
+}
+
+/// `text` in a rule color; plain when stdout isn't a terminal.
+pub fn paint(color: &str, text: &str) -> ColoredString {
+    text.color(self::color(color))
+}
@@ -0,0 +1,134 @@
+// Acquisition-to-payback timeline: when CAC is spent, when the customer actually signs (after the
+// sales cycle), when their upfront cash (CFA) lands, and when the cumulative cash position gets
+// back to zero. The payback number alone hides how long the cash is out; this shows it on a dated
+// axis, in the terminal or as an SVG.
+
+use colored::Colorize;
+
+use crate::chart_data::ChartData;
+use crate::evaluation::{money, Inputs};
+use crate::history::{date_from_days, today_days};
+use crate::precision;
+use crate::theme;
+
+const WIDTH: usize = 60;
+
+pub struct Timeline {
+    /// Days since the Unix epoch on which CAC is spent (day 0 of the timeline).
+    pub start: i64,
+    pub cac: f64,
+    pub cfa: f64,
+    /// Day the customer is acquired, after the sales cycle.
+    pub acquired: f64,
+    /// Day the upfront cash arrives; None without CFA.
+    pub cfa_day: Option<f64>,
+    /// First day the cumulative cash position is back to zero; None if it never is.
+    pub breakeven: Option<f64>,
+    /// Day the last of the lifetime gross profit comes in; None without early gross profit.
+    pub gp_ends: Option<f64>,
+}
+
+impl Timeline {
+    /// `start` defaults to today; gross profit starts accruing at acquisition and stops at LTGP.
+    pub fn new(i: &Inputs, sales_cycle_days: f64, cfa_delay_days: f64, start: Option<i64>) -> Timeline {
+        let acquired = sales_cycle_days.max(0.0);
+        let cfa_day = (i.cfa > 0.0).then_some(acquired + cfa_delay_days.max(0.0));
+        let per_day = i.early_gp_rate / i.period.days();
+        // Day gross profit alone covers `owed`, if it ever does.
+        let covered = |owed: f64| if owed <= 0.0 { Some(acquired) } else if per_day > 0.0 && owed <= i.ltgp { Some(acquired + owed / per_day) } else { None };
+        let breakeven = match cfa_day {
+            None => covered(i.cac),
+            Some(day) => match covered(i.cac) {
+                Some(t) if t <= day => Some(t),
+                _ => covered(i.cac - i.cfa).map(|t| t.max(day)),
+            },
+        };
+        let gp_ends = (per_day > 0.0).then(|| acquired + i.ltgp.max(0.0) / per_day);
+        Timeline { start: start.unwrap_or_else(today_days), cac: i.cac, cfa: i.cfa, acquired, cfa_day, breakeven, gp_ends }
+    }
+
+    fn date(&self, day: f64) -> String {
+        date_from_days(self.start + day.ceil() as i64)
+    }
+
+    /// (mark, description, day) for each event, in time order.
+    fn events(&self) -> Vec<(char, String, f64)> {
+        let mut events = vec![('S', format!("CAC of {} spent", money(self.cac)), 0.0), ('A', "Customer acquired (end of sales cycle)".to_string(), self.acquired)];
+        if let Some(day) = self.cfa_day {
+            events.push(('C', format!("Upfront cash of {} received", money(self.cfa)), day));
+        }
+        match (self.breakeven, self.gp_ends) {
+            (Some(day), _) => events.push(('B', "Breakeven: cumulative cash back to zero".to_string(), day)),
+            (None, Some(day)) => events.push(('E', "Lifetime gross profit all collected, still short".to_string(), day)),
+            (None, None) => {}
+        }
+        events.sort_by(|a, b| a.2.total_cmp(&b.2));
+        events
+    }
+
+    /// Last day shown: a little past the latest event.
+    fn span(&self) -> f64 {
+        self.events().iter().map(|e| e.2).fold(0.0, f64::max).max(1.0) * 1.1
+    }
+}
+
+/// Each event's day since the spend, keyed by its date.
+pub fn chart_data(t: &Timeline, data: &mut ChartData) {
+    for (mark, _, day) in t.events() {
+        let series = match mark {
+            'S' => "spend",
+            'A' => "acquired",
+            'C' => "cfa_received",
+            'B' => "breakeven",
+            _ => "gross_profit_exhausted",
+        };
+        data.push("timeline", series, t.date(day), day.ceil());
+    }
+}
+
+pub fn print_timeline(t: &Timeline) {
+    let span = t.span();
+    let col = |day: f64| ((day / span) * (WIDTH - 1) as f64).round() as usize;
+    let exposed = col(t.breakeven.unwrap_or(span));
+    let mut line: Vec<String> = (0..WIDTH).map(|c| if c < exposed { theme::paint("red", "━").to_string() } else { "─".to_string() }).collect();
+    for (mark, _, day) in t.events() {
+        line[col(day).min(WIDTH - 1)] = mark.to_string().bold().to_string();
+    }
+
+    println!("\nAcquisition-to-payback timeline (day 0 = {}; ━ = cash out):", t.date(0.0));
+    println!("  {}", line.concat());
+    println!("  day 0{:>w$}", format!("day {}", precision::fixed(span, 0)), w = WIDTH - 5);
+    for (mark, text, day) in t.events() {
+        println!(" {}  day {:>5}  {}  {}", mark, precision::fixed(day.ceil(), 0), t.date(day), text);
+    }
+    match t.breakeven {
+        Some(day) => println!(" Cash is out for {} days, {} of them before the customer signs; up to {} per customer is at risk until then.", precision::fixed(day.ceil(), 0), precision::fixed(t.acquired.ceil(), 0), money(t.cac)),
+        None => println!(" The cash never comes back: upfront cash and lifetime gross profit don't cover the {} spent.", money(t.cac)),
+    }
+}
+
+/// Standalone SVG of the timeline: the cash-out stretch in red, each event marked and dated.
+pub fn svg(t: &Timeline) -> String {
+    let (left, width, axis_y) = (40.0, 600.0, 90.0);
+    let span = t.span();
+    let x = |day: f64| left + day / span * width;
+    let theme = theme::active();
+    let (guide, muted, out_color) = (&theme.guide, &theme.muted, theme.hex("red"));
+    let mut out = theme.svg_open(left + width + 60.0, 170.0, 11);
+    out.push_str(&format!(r#"<line x1="{left}" x2="{r}" y1="{axis_y}" y2="{axis_y}" stroke="{guide}" stroke-width="2"/>"#, r = left + width));
+    out.push_str(&format!(r#"<line x1="{left}" x2="{bx:.1}" y1="{axis_y}" y2="{axis_y}" stroke="{out_color}" stroke-width="6"><title>cash out</title></line>"#, bx = x(t.breakeven.unwrap_or(span))));
+    for (n, (mark, text, day)) in t.events().iter().enumerate() {
+        // Alternate labels above and below the axis so close events don't collide.
+        let (ty, dy) = if n % 2 == 0 { (axis_y - 30.0, axis_y - 16.0) } else { (axis_y + 28.0, axis_y + 42.0) };
+        let color = theme.hex(if *mark == 'B' { "green" } else { "blue" });
+        // Labels near either end hang inward so they stay on the canvas.
+        let anchor = if x(*day) < left + 100.0 { "start" } else if x(*day) > left + width - 100.0 { "end" } else { "middle" };
+        out.push_str(&format!(r#"<circle cx="{cx:.1}" cy="{axis_y}" r="6" fill="{color}"><title>{text}</title></circle>"#, cx = x(*day)));
+        out.push_str(&format!(r#"<text x="{cx:.1}" y="{ty}" text-anchor="{anchor}">{text}</text><text x="{cx:.1}" y="{dy}" text-anchor="{anchor}" fill="{muted}">day {d:.0} · {date}</text>"#, cx = x(*day), d = day.ceil(), date = t.date(*day)));
+    }
+    if t.breakeven.is_none() {
+        out.push_str(&format!(r#"<text x="{r}" y="{ty}" text-anchor="end" fill="{out_color}">never breaks even</text>"#, r = left + width, ty = axis_y + 70.0));
+    }
+    out.push_str("</svg>\n");
+    out
+}
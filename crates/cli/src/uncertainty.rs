@@ -0,0 +1,159 @@
+// Histograms of the Monte Carlo simulation of uncertain inputs: the simulated ratio and payback
+// (the 3:1 bar marked) in the terminal or SVG.
+
+use crate::chart_data::ChartData;
+use crate::evaluation::{self, money, Inputs};
+use crate::layout::wrapln;
+use crate::precision;
+use crate::theme;
+
+pub use crate::simulation::{percentile, simulate, Estimate, Sample, Uncertainty};
+
+const BINS: usize = 12;
+const BAR_WIDTH: usize = 40;
+/// The ratio the histograms mark; mass below it is the risk the report warns about.
+const RATIO_BAR: f64 = 3.0;
+
+/// Bins over the 1st–99th percentile of `sorted` as (first edge, width, count), shifted so `mark`
+/// falls on an edge when it is in range. Values outside are counted in the end bins.
+fn bins(sorted: &[f64], mark: Option<f64>) -> (f64, f64, usize) {
+    let mut lo = percentile(sorted, 0.01);
+    let mut hi = percentile(sorted, 0.99);
+    if hi - lo < 1e-9 {
+        hi = lo + (lo.abs() * 0.1).max(1.0);
+        lo -= (lo.abs() * 0.1).max(1.0);
+    }
+    let width = (hi - lo) / BINS as f64;
+    if let Some(m) = mark && m > lo && m < hi {
+        lo = m - ((m - lo) / width).ceil() * width;
+    }
+    let n = (((hi - lo) / width).ceil() as usize).clamp(1, BINS + 1);
+    (lo, width, n)
+}
+
+fn counts(sorted: &[f64], lo: f64, width: f64, n: usize) -> Vec<usize> {
+    let mut counts = vec![0; n];
+    for v in sorted {
+        let k = ((v - lo) / width).floor().clamp(0.0, (n - 1) as f64) as usize;
+        counts[k] += 1;
+    }
+    counts
+}
+
+/// Horizontal bars from low to high; bins below `mark` are shaded and a rule marks the cut.
+fn print_histogram(title: &str, sorted: &[f64], mark: Option<(f64, &str)>, decimals: usize) {
+    let (lo, width, n) = bins(sorted, mark.map(|m| m.0));
+    let counts = counts(sorted, lo, width, n);
+    let most = counts.iter().copied().max().unwrap_or(1).max(1);
+    println!("
+{} ({} simulations; 1st–99th percentile, outliers in the end bins):", title, sorted.len());
+    let mut marked = false;
+    for (k, count) in counts.iter().enumerate() {
+        let start = lo + k as f64 * width;
+        if let Some((m, label)) = mark && !marked && start >= m - width * 1e-6 {
+            let below = sorted.iter().filter(|v| **v <= m).count() as f64 / sorted.len() as f64;
+            println!(" {:>19} ├{} {} ({}% of runs below)", "", "╌".repeat(BAR_WIDTH), label, precision::fixed(below * 100.0, 1));
+            marked = true;
+        }
+        let shade = if mark.is_some_and(|(m, _)| start < m) { '░' } else { '█' };
+        let bar: String = std::iter::repeat_n(shade, (count * BAR_WIDTH).div_ceil(most).min(BAR_WIDTH)).collect();
+        println!(" {:>8.d$} – {:<8.d$} │{:<w$} {:>5}%", start, start + width, bar, precision::fixed(*count as f64 / sorted.len() as f64 * 100.0, 1), d = decimals, w = BAR_WIDTH);
+    }
+}
+
+/// One panel of vertical bars for the SVG; bins below `mark` are red, the rest `fill` (a rule color).
+fn histogram_panel(out: &mut String, origin: (f64, f64), title: &str, sorted: &[f64], mark: Option<f64>, fill: &str) {
+    let (left, top) = origin;
+    let (w, h) = (300.0, 180.0);
+    let (lo, width, n) = bins(sorted, mark);
+    let counts = counts(sorted, lo, width, n);
+    let most = counts.iter().copied().max().unwrap_or(1).max(1) as f64;
+    let bar_w = w / n as f64;
+    let x = |v: f64| left + (v - lo) / (width * n as f64) * w;
+    let theme = theme::active();
+    let (axis, ink) = (&theme.axis, &theme.text);
+    out.push_str(&format!(r#"<text x="{left}" y="{ty}" font-size="12">{title}</text>"#, ty = top - 6.0));
+    for (k, count) in counts.iter().enumerate() {
+        let start = lo + k as f64 * width;
+        let bh = *count as f64 / most * h;
+        let color = theme.hex(if mark.is_some_and(|m| start < m) { "red" } else { fill });
+        out.push_str(&format!(r#"<rect x="{bx:.1}" y="{by:.1}" width="{bw:.1}" height="{bh:.1}" fill="{color}"><title>{start:.2} – {end:.2}: {count}</title></rect>"#, bx = left + k as f64 * bar_w + 1.0, by = top + h - bh, bw = bar_w - 2.0, end = start + width));
+    }
+    out.push_str(&format!(r#"<line x1="{left}" x2="{r}" y1="{b}" y2="{b}" stroke="{axis}"/>"#, r = left + w, b = top + h));
+    out.push_str(&format!(r#"<text x="{left}" y="{ty}">{lo:.1}</text><text x="{r}" y="{ty}" text-anchor="end">{hi:.1}</text>"#, ty = top + h + 14.0, r = left + w, hi = lo + width * n as f64));
+    if let Some(m) = mark && m > lo && m < lo + width * n as f64 {
+        let below = sorted.iter().filter(|v| **v <= m).count() as f64 / sorted.len() as f64;
+        out.push_str(&format!(r#"<line x1="{mx:.1}" x2="{mx:.1}" y1="{top}" y2="{b}" stroke="{ink}" stroke-dasharray="4 3"/>"#, mx = x(m), b = top + h));
+        out.push_str(&format!(r#"<text x="{tx:.1}" y="{ty}">{m}:1 ({pct:.1}% below)</text>"#, tx = x(m) + 4.0, ty = top + 12.0, pct = below * 100.0));
+    }
+}
+
+/// Run counts per bin (x = bin start) for both histograms, binned as drawn.
+pub fn chart_data(samples: &[Sample], data: &mut ChartData) {
+    let mut ratios: Vec<f64> = samples.iter().map(|s| s.ratio).filter(|r| r.is_finite()).collect();
+    ratios.sort_by(f64::total_cmp);
+    let mut paybacks: Vec<f64> = samples.iter().filter_map(|s| s.payback_days).collect();
+    paybacks.sort_by(f64::total_cmp);
+    for (chart, sorted, mark) in [("ratio_histogram", &ratios, Some(RATIO_BAR)), ("payback_histogram", &paybacks, None)] {
+        if sorted.is_empty() {
+            continue;
+        }
+        let (lo, width, n) = bins(sorted, mark);
+        for (k, count) in counts(sorted, lo, width, n).into_iter().enumerate() {
+            data.push_xy(chart, "runs", lo + k as f64 * width, count as f64);
+        }
+    }
+}
+
+/// Standalone SVG of the ratio and payback histograms side by side.
+pub fn histogram_svg(samples: &[Sample]) -> String {
+    let mut ratios: Vec<f64> = samples.iter().map(|s| s.ratio).filter(|r| r.is_finite()).collect();
+    ratios.sort_by(f64::total_cmp);
+    let mut paybacks: Vec<f64> = samples.iter().filter_map(|s| s.payback_days).collect();
+    paybacks.sort_by(f64::total_cmp);
+    let mut out = theme::active().svg_open(700.0, 240.0, 11);
+    if !ratios.is_empty() {
+        histogram_panel(&mut out, (30.0, 30.0), "LTGP:CAC", &ratios, Some(RATIO_BAR), "green");
+    }
+    if !paybacks.is_empty() {
+        histogram_panel(&mut out, (380.0, 30.0), "Payback (days)", &paybacks, None, "blue");
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+/// Prints the simulation summary and histograms, and returns the samples for `histogram_svg`.
+pub fn print_uncertainty_report(inputs: &Inputs, unc: &Uncertainty, runs: usize, seed: u64) -> Vec<Sample> {
+    let base = evaluation::evaluate(inputs);
+    let samples = simulate(inputs, unc, runs, seed);
+    let mut ratios: Vec<f64> = samples.iter().map(|s| s.ratio).collect();
+    ratios.sort_by(f64::total_cmp);
+    let below = ratios.iter().filter(|r| **r <= 3.0).count() as f64 / runs as f64;
+    let same_verdict = samples.iter().filter(|s| s.verdict == base.verdict).count() as f64 / runs as f64;
+
+    // First-order (delta method) approximation for the ratio, shown next to the simulation.
+    let rel = |sd: f64, mean: f64| if mean != 0.0 { sd / mean } else { 0.0 };
+    let analytic_sd = base.ratio * (rel(unc.ltgp_sd, inputs.ltgp).powi(2) + rel(unc.cac_sd, inputs.cac).powi(2)).sqrt();
+
+    println!("\nUncertainty ({} simulations, CAC ± {}, CFA ± {}, LTGP ± {}):", runs, money(unc.cac_sd), money(unc.cfa_sd), money(unc.ltgp_sd));
+    wrapln!(" - LTGP:CAC: {} (90% interval {} – {}; analytic ± {}).", precision::fixed(percentile(&ratios, 0.5), 2), precision::fixed(percentile(&ratios, 0.05), 2), precision::fixed(percentile(&ratios, 0.95), 2), precision::fixed(analytic_sd, 2));
+    wrapln!(" - Chance LTGP:CAC is 3 or below: {}%.", precision::fixed(below * 100.0, 1));
+    let mut paybacks: Vec<f64> = samples.iter().filter_map(|s| s.payback_days).collect();
+    if !paybacks.is_empty() {
+        paybacks.sort_by(f64::total_cmp);
+        wrapln!(" - Payback: {} days (90% interval {} – {} days).", precision::fixed(percentile(&paybacks, 0.5), 1), precision::fixed(percentile(&paybacks, 0.05), 1), precision::fixed(percentile(&paybacks, 0.95), 1));
+    }
+    wrapln!(" - The verdict above holds in {}% of simulations.", precision::fixed(same_verdict * 100.0, 1));
+    if below > 0.1 && base.ratio > 3.0 {
+        println!("\nWarning: The point estimate clears 3:1, but there is a {}% chance the true ratio does not.", precision::fixed(below * 100.0, 0));
+    }
+
+    let finite: Vec<f64> = ratios.iter().copied().filter(|r| r.is_finite()).collect();
+    if !finite.is_empty() {
+        print_histogram("LTGP:CAC distribution", &finite, Some((RATIO_BAR, "3:1")), 2);
+    }
+    if !paybacks.is_empty() {
+        print_histogram("Payback distribution (days)", &paybacks, None, 1);
+    }
+    samples
+}
@@ -0,0 +1,106 @@
+// Cash-flow waterfall for one customer: CAC out, CFA in, gross profit in month by month, ending at
+// lifetime net gross profit. Rendered in the terminal or as a standalone SVG file.
+
+use crate::chart_data::ChartData;
+use crate::evaluation::{money, Inputs};
+use crate::plan::UnitCashFlow;
+use crate::theme;
+
+/// Gross profit bars shown individually before the rest are grouped into one.
+const MAX_GP_STEPS: usize = 8;
+const BAR_WIDTH: usize = 48;
+
+#[derive(Debug, Clone)]
+pub struct Step {
+    pub label: String,
+    /// Signed change in cash; for the closing total, the total itself.
+    pub amount: f64,
+    pub total: bool,
+}
+
+pub fn steps(inputs: &Inputs) -> Vec<Step> {
+    let unit = UnitCashFlow::from_inputs(inputs);
+    let mut out = vec![Step { label: "CAC".to_string(), amount: -unit.cac, total: false }];
+    if unit.cfa > 0.0 {
+        out.push(Step { label: "CFA".to_string(), amount: unit.cfa, total: false });
+    }
+    let months = if unit.monthly_gp > 0.0 { (unit.ltgp / unit.monthly_gp).ceil() as usize } else { 0 };
+    for m in 0..months.min(MAX_GP_STEPS) {
+        out.push(Step { label: format!("GP month {}", m + 1), amount: unit.gp_in_month(m), total: false });
+    }
+    if months > MAX_GP_STEPS {
+        let rest: f64 = (MAX_GP_STEPS..months).map(|m| unit.gp_in_month(m)).sum();
+        out.push(Step { label: format!("GP months {}–{}", MAX_GP_STEPS + 1, months), amount: rest, total: false });
+    }
+    let net = out.iter().map(|s| s.amount).sum();
+    out.push(Step { label: "Lifetime net".to_string(), amount: net, total: true });
+    out
+}
+
+/// (start, end) of each bar in cash terms.
+fn spans(steps: &[Step]) -> Vec<(f64, f64)> {
+    let mut running = 0.0;
+    steps.iter().map(|s| {
+        if s.total {
+            (0.0, s.amount)
+        } else {
+            let start = running;
+            running += s.amount;
+            (start, running)
+        }
+    }).collect()
+}
+
+fn range(spans: &[(f64, f64)]) -> (f64, f64) {
+    let lo = spans.iter().flat_map(|(a, b)| [*a, *b]).fold(0.0, f64::min);
+    let hi = spans.iter().flat_map(|(a, b)| [*a, *b]).fold(0.0, f64::max);
+    (lo, if hi > lo { hi } else { lo + 1.0 })
+}
+
+/// Each bar's signed amount, labeled as in the chart.
+pub fn chart_data(inputs: &Inputs, data: &mut ChartData) {
+    for s in steps(inputs) {
+        data.push("waterfall", "amount", &s.label, s.amount);
+    }
+}
+
+pub fn print_waterfall(inputs: &Inputs) {
+    let steps = steps(inputs);
+    let spans = spans(&steps);
+    let (lo, hi) = range(&spans);
+    let col = |v: f64| (((v - lo) / (hi - lo)) * BAR_WIDTH as f64).round() as usize;
+    let zero = col(0.0);
+
+    println!("\nCash-flow waterfall per customer (▒ = cash out, █ = cash in, ▓ = lifetime net):");
+    for (s, (a, b)) in steps.iter().zip(&spans) {
+        let (from, to) = (col(a.min(*b)), col(a.max(*b)).max(col(a.min(*b)) + 1));
+        let fill = if s.total { '▓' } else if s.amount < 0.0 { '▒' } else { '█' };
+        let row: String = (0..=BAR_WIDTH).map(|c| if c >= from && c < to { fill } else if c == zero { '│' } else { ' ' }).collect();
+        println!(" {:<16} {} {:>11}", s.label, row, money(s.amount));
+    }
+}
+
+/// Standalone SVG of the waterfall.
+pub fn svg(inputs: &Inputs) -> String {
+    let steps = steps(inputs);
+    let spans = spans(&steps);
+    let (lo, hi) = range(&spans);
+    let (bar_w, gap, height, top, left) = (56.0, 16.0, 320.0, 30.0, 60.0);
+    let y = |v: f64| top + (hi - v) / (hi - lo) * height;
+    let width = left + steps.len() as f64 * (bar_w + gap) + gap;
+
+    let theme = theme::active();
+    let axis = &theme.axis;
+    let mut out = theme.svg_open(width, top + height + 50.0, 11);
+    out.push_str(&format!(r#"<line x1="{l}" x2="{r}" y1="{y0:.1}" y2="{y0:.1}" stroke="{axis}"/>"#, l = left - 10.0, r = width, y0 = y(0.0)));
+    for (n, (s, (a, b))) in steps.iter().zip(&spans).enumerate() {
+        let x = left + gap + n as f64 * (bar_w + gap);
+        let color = theme.hex(if s.total { "blue" } else if s.amount < 0.0 { "red" } else { "green" });
+        let (y_top, y_bottom) = (y(a.max(*b)), y(a.min(*b)));
+        out.push_str(&format!(r#"<rect x="{x}" y="{yt:.1}" width="{bar_w}" height="{hh:.1}" fill="{color}"/>"#, yt = y_top, hh = (y_bottom - y_top).max(1.0)));
+        out.push_str(&format!(r#"<text x="{cx}" y="{ty:.1}" text-anchor="middle">{v}</text>"#, cx = x + bar_w / 2.0, ty = y_top - 4.0, v = money(s.amount)));
+        out.push_str(&format!(r#"<text x="{cx}" y="{ly}" text-anchor="middle">{label}</text>"#, cx = x + bar_w / 2.0, ly = top + height + 20.0, label = s.label));
+    }
+    out.push_str("</svg>\n");
+    out
+}
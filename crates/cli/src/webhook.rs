@@ -0,0 +1,76 @@
+// Webhook: the JSON result of each run or batch POSTed to `--webhook URL`, so warehouse loaders,
+// alerting, and BI tools can subscribe to evaluations without an integration of their own. The
+// body is signed with HMAC-SHA256 under the secret in LTGP_WEBHOOK_SECRET:
+//
+//   X-Ltgp-Timestamp: 1760000000
+//   X-Ltgp-Signature: sha256=<hex HMAC of "<timestamp>.<body>">
+//
+// Receivers recompute the HMAC over the timestamp, a `.`, and the raw body, compare it in constant
+// time, and drop old timestamps so a captured request can't be replayed.
+//
+//   {"event": "evaluation", "sent_at": 1760000000, "name": "acme", "result": {…}}
+//   {"event": "batch", "sent_at": 1760000000, "results": [{"id": "acme", "result": {…}}, …]}
+//
+// `result` is the object `--format json` prints.
+
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use ring::hmac;
+use serde_json::{json, Value};
+
+use crate::report::{self, EvaluationResult};
+
+const SECRET_VAR: &str = "LTGP_WEBHOOK_SECRET";
+
+struct Target {
+    url: String,
+    key: hmac::Key,
+}
+
+static TARGET: OnceLock<Option<Target>> = OnceLock::new();
+
+/// Sets the URL for the rest of the run; one given without a secret is an error.
+pub fn install(url: Option<String>) -> Result<(), String> {
+    let target = match url {
+        Some(url) => {
+            let secret = std::env::var(SECRET_VAR).map_err(|_| format!("set {} to the secret --webhook signs with", SECRET_VAR))?;
+            Some(Target { url, key: hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes()) })
+        }
+        None => None,
+    };
+    TARGET.set(target).ok().expect("webhook installed once, at startup");
+    Ok(())
+}
+
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default()
+}
+
+/// POSTs `payload` with `sent_at` set and the signature headers.
+fn send(mut payload: Value) -> Result<(), String> {
+    let Some(target) = TARGET.get().and_then(Option::as_ref) else { return Ok(()) };
+    let timestamp = now();
+    payload["sent_at"] = json!(timestamp);
+    let body = payload.to_string();
+    let tag = hmac::sign(&target.key, format!("{}.{}", timestamp, body).as_bytes());
+    let signature: String = tag.as_ref().iter().map(|b| format!("{:02x}", b)).collect();
+    ureq::post(&target.url)
+        .content_type("application/json")
+        .header("X-Ltgp-Timestamp", timestamp.to_string())
+        .header("X-Ltgp-Signature", format!("sha256={}", signature))
+        .send(body)
+        .map(|_| ())
+        .map_err(|e| format!("could not post to {}: {}", target.url, e))
+}
+
+/// Sends one run; `name` is left null when empty.
+pub fn evaluation(name: &str, r: &EvaluationResult) -> Result<(), String> {
+    send(json!({ "event": "evaluation", "name": (!name.is_empty()).then_some(name), "result": report::json_value(r) }))
+}
+
+/// Sends every row of a batch in one request.
+pub fn batch(results: &[(String, EvaluationResult)]) -> Result<(), String> {
+    let results: Vec<Value> = results.iter().map(|(id, r)| json!({ "id": id, "result": report::json_value(r) })).collect();
+    send(json!({ "event": "batch", "results": results }))
+}
@@ -0,0 +1,92 @@
+// Xero import: the Profit and Loss report for the period, with every account in the expense
+// sections ("Less Operating Expenses", "Non-operating Expenses") as an expense line for
+// `accounting::report`, the same as QuickBooks.
+
+use clap::Args;
+use serde::Deserialize;
+
+use crate::accounting::{self, AccountingArgs, ExpenseLine};
+use crate::history::date_from_days;
+use crate::import::{self, Imported, Importer, OutputArgs};
+
+#[derive(Args, Debug)]
+pub struct XeroArgs {
+    /// Organisation (tenant) ID (default: the XERO_TENANT_ID environment variable)
+    #[arg(long)]
+    pub tenant_id: Option<String>,
+
+    /// OAuth access token with accounting.reports.read (default: the XERO_ACCESS_TOKEN environment variable)
+    #[arg(long)]
+    pub access_token: Option<String>,
+
+    /// API base URL, e.g. a mock server for trying the import out
+    #[arg(long, default_value = "https://api.xero.com")]
+    pub api_url: String,
+
+    #[command(flatten)]
+    pub accounting: AccountingArgs,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct Reports {
+    reports: Vec<Report>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct Report {
+    #[serde(default)]
+    rows: Vec<Row>,
+}
+
+/// A section (with a title and nested rows) or an account row (name and amount cells).
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct Row {
+    row_type: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    cells: Vec<Cell>,
+    #[serde(default)]
+    rows: Vec<Row>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct Cell {
+    #[serde(default)]
+    value: String,
+}
+
+impl Importer for XeroArgs {
+    fn name(&self) -> &'static str {
+        "Xero"
+    }
+
+    fn output(&self) -> &OutputArgs {
+        &self.output
+    }
+
+    fn import(&self) -> Result<Imported, String> {
+        let tenant = import::credential(&self.tenant_id, "tenant-id", "XERO_TENANT_ID")?;
+        let token = import::credential(&self.access_token, "access-token", "XERO_ACCESS_TOKEN")?;
+        let (since, until) = self.accounting.period.days()?;
+        let url = format!("{}/api.xro/2.0/Reports/ProfitAndLoss", self.api_url.trim_end_matches('/'));
+        let bearer = format!("Bearer {}", token);
+        let headers = [("Authorization", bearer.as_str()), ("xero-tenant-id", tenant.as_str()), ("Accept", "application/json")];
+        let reply: Reports = import::get_with(&url, &headers, &[("fromDate", date_from_days(since)), ("toDate", date_from_days(until))])?;
+
+        let mut lines = Vec::new();
+        let sections = reply.reports.iter().flat_map(|r| &r.rows).filter(|r| r.row_type == "Section" && r.title.to_lowercase().contains("expense"));
+        for row in sections.flat_map(|s| &s.rows).filter(|r| r.row_type == "Row") {
+            let (Some(name), Some(total)) = (row.cells.first(), row.cells.last()) else { continue };
+            lines.push(ExpenseLine { account: name.value.clone(), amount: total.value.parse().unwrap_or(0.0) });
+        }
+        accounting::report(self.name(), &lines, &self.accounting)
+    }
+}
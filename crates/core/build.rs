@@ -0,0 +1,6 @@
+// The Node.js module needs napi's linker setup.
+
+fn main() {
+    #[cfg(feature = "node")]
+    napi_build::setup();
+}
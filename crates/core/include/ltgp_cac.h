@@ -0,0 +1,112 @@
+/* Crow's Nest LTGP:CAC calculator: C interface. Generated by cbindgen from src/ffi.rs; do not edit. */
+
+#ifndef LTGP_CAC_H
+#define LTGP_CAC_H
+
+#include <stdbool.h>
+#include <stdint.h>
+
+#define LTGP_OK 0
+
+// A required pointer was NULL.
+#define LTGP_ERR_NULL -1
+
+// `period` was not valid UTF-8, or not days, weeks, months, or years.
+#define LTGP_ERR_PERIOD -2
+
+// An input was out of range, such as a negative CAC or a low-CAC fraction above 1.
+#define LTGP_ERR_INVALID -3
+
+typedef enum LtgpSeverity {
+  LTGP_SEVERITY_HEALTHY,
+  LTGP_SEVERITY_WATCH,
+  LTGP_SEVERITY_AT_RISK,
+  LTGP_SEVERITY_CRITICAL,
+} LtgpSeverity;
+
+// The inputs to one evaluation; `ltgp_inputs_default` fills in the scenario file's defaults.
+typedef struct LtgpInputs {
+  double cac;
+  double cfa;
+  double ltgp;
+  double early_gp_rate;
+  // "days", "weeks", "months", or "years"; NULL for days.
+  const char *period;
+  double low_cac_fraction;
+  double high_cfa_fraction;
+  // NAN to use `low_cac_fraction`.
+  double low_cac_max;
+  // NAN to use `high_cfa_fraction`.
+  double high_cfa_min;
+} LtgpInputs;
+
+// One evaluation; free it with `ltgp_result_free`.
+typedef struct LtgpResult {
+  double net_outlay;
+  // LTGP:CAC; INFINITY for a free customer.
+  double ratio;
+  bool low_cac;
+  bool high_cfa;
+  double low_cac_threshold;
+  double high_cfa_threshold;
+  enum LtgpSeverity severity;
+  // Payback in the inputs' period; NAN without early gross profit.
+  double payback;
+  double payback_days;
+  double cac_payback_months;
+  char *quadrant;
+  char *verdict;
+} LtgpResult;
+
+
+
+
+
+
+
+
+
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// CAC and LTGP with every other input at its default.
+struct LtgpInputs ltgp_inputs_default(double cac, double ltgp);
+
+// Evaluates `inputs` into `out`; returns `LTGP_OK` or an `LTGP_ERR_` code, leaving `out` untouched.
+//
+// # Safety
+// `inputs` must be NULL or point to a valid `LtgpInputs`; `out` must be NULL or writable.
+int32_t ltgp_evaluate(const struct LtgpInputs *inputs, struct LtgpResult *out);
+
+// Frees a result's strings and sets them to NULL; safe to call twice.
+//
+// # Safety
+// `result` must be NULL or a result filled in by `ltgp_evaluate`.
+void ltgp_result_free(struct LtgpResult *result);
+
+// The evaluation as the JSON `--format json` prints; NULL on bad inputs. Free with `ltgp_string_free`.
+//
+// # Safety
+// As for `ltgp_evaluate`.
+char *ltgp_evaluate_json(const struct LtgpInputs *inputs);
+
+// The CLI's plain-text report; NULL on bad inputs. Free with `ltgp_string_free`.
+//
+// # Safety
+// As for `ltgp_evaluate`.
+char *ltgp_report(const struct LtgpInputs *inputs);
+
+// Frees a string from `ltgp_evaluate_json` or `ltgp_report`; NULL is ignored.
+//
+// # Safety
+// `s` must be NULL or a string this library returned, not yet freed.
+void ltgp_string_free(char *s);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* LTGP_CAC_H */
@@ -0,0 +1,129 @@
+// Per-channel acquisition with capacity ceilings and saturation.
+//
+// Each channel follows an exponential saturation curve: the first customers cost the entered
+// CAC, and the marginal CAC climbs as c / (1 - v / capacity), so a channel can never deliver
+// more than its capacity no matter how much is spent on it.
+
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct Channel {
+    pub name: String,
+    pub cac: f64,
+    /// Maximum customers per month the channel can ever deliver; None means unlimited.
+    pub capacity: Option<f64>,
+}
+
+impl FromStr for Channel {
+    type Err = String;
+
+    /// Parses `name:cac[:capacity]`, e.g. `google:350:400`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(':').map(|p| p.trim()).collect();
+        let (name, cac, capacity) = match parts.as_slice() {
+            [name, cac] => (name, cac, None),
+            [name, cac, capacity] => (name, cac, Some(capacity)),
+            _ => return Err(format!("expected name:cac[:capacity], got `{}`", s)),
+        };
+        let cac = crate::evaluation::parse_money_like(cac).filter(|c| *c > 0.0).ok_or_else(|| format!("invalid CAC in `{}`", s))?;
+        let capacity = match capacity {
+            Some(c) => Some(crate::evaluation::parse_money_like(c).filter(|c| *c > 0.0).ok_or_else(|| format!("invalid capacity in `{}`", s))?),
+            None => None,
+        };
+        Ok(Channel { name: name.to_string(), cac, capacity })
+    }
+}
+
+impl Channel {
+    /// Monthly spend needed to acquire `volume` customers from this channel.
+    pub fn spend_for(&self, volume: f64) -> f64 {
+        match self.capacity {
+            Some(cap) if volume >= cap => f64::INFINITY,
+            Some(cap) => -self.cac * cap * (1.0 - volume / cap).ln(),
+            None => self.cac * volume,
+        }
+    }
+
+    /// Volume at which this channel's marginal CAC reaches `marginal`.
+    fn volume_at_marginal(&self, marginal: f64) -> f64 {
+        if marginal <= self.cac { return 0.0; }
+        match self.capacity {
+            Some(cap) => cap * (1.0 - self.cac / marginal),
+            None => f64::INFINITY,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Allocation {
+    pub volumes: Vec<f64>,
+    pub spend: f64,
+    pub marginal_cac: f64,
+}
+
+impl Allocation {
+    pub fn total_volume(&self) -> f64 {
+        self.volumes.iter().sum()
+    }
+
+    pub fn blended_cac(&self) -> f64 {
+        let total = self.total_volume();
+        if total > 0.0 { self.spend / total } else { 0.0 }
+    }
+}
+
+/// Combined monthly capacity across channels; None if any channel is unlimited.
+pub fn total_capacity(channels: &[Channel]) -> Option<f64> {
+    channels.iter().map(|c| c.capacity).sum()
+}
+
+/// Cheapest way to buy `target` customers per month: fill channels until their marginal CACs are equal.
+/// Returns None when the target exceeds the combined capacity.
+pub fn allocate(channels: &[Channel], target: f64) -> Option<Allocation> {
+    if channels.is_empty() { return None; }
+    if let Some(cap) = total_capacity(channels) && target >= cap { return None; }
+    let unlimited_floor = channels.iter().filter(|c| c.capacity.is_none()).map(|c| c.cac).fold(f64::INFINITY, f64::min);
+    let capped_volume = |marginal: f64| -> f64 { channels.iter().filter(|c| c.capacity.is_some()).map(|c| c.volume_at_marginal(marginal)).sum() };
+
+    // Marginal CAC the capped channels have to reach on their own; unlimited channels stop it rising past their CAC.
+    let mut lo = channels.iter().map(|c| c.cac).fold(f64::INFINITY, f64::min);
+    let mut hi = lo.max(1.0);
+    while capped_volume(hi) < target && hi < unlimited_floor { hi *= 2.0; }
+    let marginal = if capped_volume(unlimited_floor.min(hi)) < target && unlimited_floor.is_finite() {
+        unlimited_floor
+    } else {
+        for _ in 0..100 {
+            let mid = (lo + hi) / 2.0;
+            if capped_volume(mid) < target { lo = mid; } else { hi = mid; }
+        }
+        hi
+    };
+
+    let mut volumes: Vec<f64> = channels.iter().map(|c| if c.capacity.is_some() { c.volume_at_marginal(marginal) } else { 0.0 }).collect();
+    let remainder = (target - volumes.iter().sum::<f64>()).max(0.0);
+    if remainder > 0.0 && let Some(i) = channels.iter().position(|c| c.capacity.is_none() && c.cac == unlimited_floor) {
+        volumes[i] = remainder;
+    }
+    let spend = channels.iter().zip(&volumes).map(|(c, v)| c.spend_for(*v)).sum();
+    Some(Allocation { volumes, spend, marginal_cac: marginal })
+}
+
+/// Largest monthly volume whose marginal customer still clears `max_marginal_cac`.
+pub fn max_volume_within(channels: &[Channel], max_marginal_cac: f64) -> f64 {
+    channels.iter().map(|c| c.volume_at_marginal(max_marginal_cac)).sum()
+}
+
+/// Most customers per month a monthly `spend` can buy across channels (cheapest mix first).
+pub fn volume_for_spend(channels: &[Channel], spend: f64) -> f64 {
+    if spend <= 0.0 || channels.is_empty() { return 0.0; }
+    let mut lo = 0.0;
+    let mut hi = total_capacity(channels).unwrap_or_else(|| spend / channels.iter().map(|c| c.cac).fold(f64::INFINITY, f64::min));
+    for _ in 0..100 {
+        let mid = (lo + hi) / 2.0;
+        match allocate(channels, mid) {
+            Some(a) if a.spend <= spend => lo = mid,
+            _ => hi = mid,
+        }
+    }
+    lo
+}
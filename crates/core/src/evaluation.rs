@@ -0,0 +1,370 @@
+// Core unit-economics evaluation: CAC/CFA classification, quadrant, verdict, and payback.
+
+use serde::{Deserialize, Serialize};
+
+use crate::locale;
+use crate::period::Period;
+use crate::precision;
+use crate::rules;
+use crate::tree::{self, Leaf};
+use crate::units::{Fraction, Money, Rate};
+
+/// The inputs a single evaluation runs on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Inputs {
+    pub cac: f64,
+    pub cfa: f64,
+    pub ltgp: f64,
+    pub early_gp_rate: f64,
+    pub period: Period,
+    pub low_cac_fraction: f64,
+    /// Consider CFA 'high' if CFA ≥ this fraction of CAC.
+    pub high_cfa_fraction: f64,
+    /// Absolute low-CAC cut in dollars; overrides `low_cac_fraction` when set.
+    pub low_cac_max: Option<f64>,
+    /// Absolute high-CFA cut in dollars; overrides `high_cfa_fraction` when set.
+    pub high_cfa_min: Option<f64>,
+}
+
+/// The inputs as unit-checked quantities, for the evaluation math (see `units`).
+#[derive(Debug, Clone, Copy)]
+pub struct Amounts {
+    pub cac: Money,
+    pub cfa: Money,
+    pub ltgp: Money,
+    pub early_gp: Rate,
+    /// Capped at 1.
+    pub low_cac_fraction: Fraction,
+    pub high_cfa_fraction: Fraction,
+    pub low_cac_max: Option<Money>,
+    pub high_cfa_min: Option<Money>,
+}
+
+impl Inputs {
+    pub fn amounts(&self) -> Amounts {
+        Amounts {
+            cac: Money::dollars(self.cac),
+            cfa: Money::dollars(self.cfa),
+            ltgp: Money::dollars(self.ltgp),
+            early_gp: Rate::new(Money::dollars(self.early_gp_rate), self.period),
+            low_cac_fraction: Fraction::new(self.low_cac_fraction.clamp(0.0, 1.0)),
+            high_cfa_fraction: Fraction::new(self.high_cfa_fraction.max(0.0)),
+            low_cac_max: self.low_cac_max.map(Money::dollars),
+            high_cfa_min: self.high_cfa_min.map(Money::dollars),
+        }
+    }
+}
+
+/// Default low-CAC boundary: CAC at most a tenth of LTGP.
+pub const DEFAULT_LOW_CAC_FRACTION: f64 = 0.10;
+
+/// Default high-CFA boundary: the customer pays at least half of CAC upfront.
+pub const DEFAULT_HIGH_CFA_FRACTION: f64 = 0.5;
+
+/// LTGP:CAC above this is rare enough that a unit mix-up is more likely than the business.
+pub const IMPLAUSIBLE_RATIO: f64 = 50.0;
+
+/// A numeric input that can be varied on its own (sweeps, attribution, sensitivity checks).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Field {
+    Cac,
+    Cfa,
+    Ltgp,
+    EarlyGpRate,
+    LowCacFraction,
+}
+
+impl Field {
+    pub const ALL: [Field; 5] = [Field::Cac, Field::Cfa, Field::Ltgp, Field::EarlyGpRate, Field::LowCacFraction];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Field::Cac => "CAC",
+            Field::Cfa => "CFA",
+            Field::Ltgp => "LTGP",
+            Field::EarlyGpRate => "early GP rate",
+            Field::LowCacFraction => "low-CAC threshold",
+        }
+    }
+
+    pub fn get(&self, i: &Inputs) -> f64 {
+        match self {
+            Field::Cac => i.cac,
+            Field::Cfa => i.cfa,
+            Field::Ltgp => i.ltgp,
+            Field::EarlyGpRate => i.early_gp_rate,
+            Field::LowCacFraction => i.low_cac_fraction,
+        }
+    }
+
+    pub fn set(&self, i: &mut Inputs, value: f64) {
+        match self {
+            Field::Cac => i.cac = value,
+            Field::Cfa => i.cfa = value,
+            Field::Ltgp => i.ltgp = value,
+            Field::EarlyGpRate => i.early_gp_rate = value,
+            Field::LowCacFraction => i.low_cac_fraction = value,
+        }
+    }
+}
+
+/// The decision-tree leaf an evaluation lands in; one of the four quadrants in the default tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quadrant(&'static Leaf);
+
+/// Placeholder until `evaluate` has walked the tree.
+static UNCLASSIFIED: Leaf = Leaf { label: String::new(), description: String::new() };
+
+impl Quadrant {
+    pub fn name(&self) -> &'static str {
+        &self.0.label
+    }
+
+    pub fn description(&self) -> &'static str {
+        &self.0.description
+    }
+}
+
+/// Which side of its quadrant boundary CAC or CFA falls on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum Class {
+    Low,
+    High,
+}
+
+impl Class {
+    fn of(high: bool) -> Class {
+        if high { Class::High } else { Class::Low }
+    }
+
+    pub fn cac_label(&self) -> &'static str {
+        match self {
+            Class::Low => "Low CAC (cheap to acquire a customer)",
+            Class::High => "High CAC (expensive to acquire a customer)",
+        }
+    }
+
+    pub fn cfa_label(&self) -> &'static str {
+        match self {
+            Class::High => "High CFA (customer covers much of your cost upfront)",
+            Class::Low => "Low CFA (customer covers little upfront)",
+        }
+    }
+
+    /// `Low` or `High`, for short labels.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Class::Low => "Low",
+            Class::High => "High",
+        }
+    }
+}
+
+/// Inputs at an edge where LTGP:CAC stops meaning what it usually does; the report explains
+/// these instead of printing an infinite or meaningless ratio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum Degenerate {
+    /// CAC and LTGP are both $0: there is no return to measure, so the ratio counts as 0.
+    NoEconomics,
+    /// CAC is $0: customers cost nothing to acquire, so the ratio is unbounded.
+    FreeAcquisition,
+    /// LTGP is $0: customers cost money and return nothing.
+    NoProfit,
+    /// CFA exceeds CAC: the customer funds their own acquisition.
+    SelfFunded,
+}
+
+impl Degenerate {
+    /// The case `i` falls into, if any; the first that applies wins.
+    pub fn of(i: &Inputs) -> Option<Degenerate> {
+        if i.cac <= 0.0 && i.ltgp <= 0.0 {
+            Some(Degenerate::NoEconomics)
+        } else if i.cac <= 0.0 {
+            Some(Degenerate::FreeAcquisition)
+        } else if i.ltgp <= 0.0 {
+            Some(Degenerate::NoProfit)
+        } else if i.cfa > i.cac {
+            Some(Degenerate::SelfFunded)
+        } else {
+            None
+        }
+    }
+
+    /// no-economics, free-acquisition, no-profit, or self-funded.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Degenerate::NoEconomics => "no-economics",
+            Degenerate::FreeAcquisition => "free-acquisition",
+            Degenerate::NoProfit => "no-profit",
+            Degenerate::SelfFunded => "self-funded",
+        }
+    }
+
+    /// A few words for short reports.
+    pub fn headline(&self) -> &'static str {
+        match self {
+            Degenerate::NoEconomics => "no CAC or LTGP to compare",
+            Degenerate::FreeAcquisition => "free acquisition, unbounded LTGP:CAC",
+            Degenerate::NoProfit => "no lifetime gross profit",
+            Degenerate::SelfFunded => "customer funds their own acquisition",
+        }
+    }
+
+    /// What the case means for these inputs, and what to check.
+    pub fn explanation(&self, i: &Inputs) -> String {
+        match self {
+            Degenerate::NoEconomics => "CAC and LTGP are both $0, so there is no return to measure and LTGP:CAC counts as 0. Enter what a customer costs to acquire and what they earn you to get a meaningful verdict.".to_string(),
+            Degenerate::FreeAcquisition => format!("CAC is $0, so each customer's {} of gross profit costs nothing to acquire and LTGP:CAC has no upper bound. Make sure CAC counts all sales and marketing spend, salaries and tools included.", money(i.ltgp)),
+            Degenerate::NoProfit => format!("LTGP is $0, so each customer costs {} to acquire and returns nothing: LTGP:CAC is 0 however CAC changes. Check that LTGP is lifetime gross profit, not a per-period figure.", money(i.cac)),
+            Degenerate::SelfFunded => format!("CFA ({}) exceeds CAC ({}): the customer funds their own acquisition with {} to spare, so net outlay is $0 and payback is immediate.", money(i.cfa), money(i.cac), money(i.cfa - i.cac)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Evaluation {
+    pub inputs: Inputs,
+    pub low_cac_thresh: f64,
+    pub high_cfa_thresh: f64,
+    pub net_outlay: f64,
+    pub ratio: f64,
+    pub low_cac: bool,
+    pub high_cfa: bool,
+    pub quadrant: Quadrant,
+    pub verdict: &'static str,
+    /// Payback in `inputs.period` units; None when there is no early gross profit.
+    pub payback: Option<f64>,
+    /// Industry-standard CAC payback: full CAC ÷ monthly gross profit, ignoring CFA.
+    pub cac_payback_months: Option<f64>,
+}
+
+impl Evaluation {
+    pub fn cac_class(&self) -> Class {
+        Class::of(!self.low_cac)
+    }
+
+    pub fn cfa_class(&self) -> Class {
+        Class::of(self.high_cfa)
+    }
+
+    pub fn cac_label(&self) -> &'static str {
+        self.cac_class().cac_label()
+    }
+
+    pub fn cfa_label(&self) -> &'static str {
+        self.cfa_class().cfa_label()
+    }
+
+    pub fn payback_days(&self) -> Option<f64> {
+        self.payback.map(|value| self.inputs.period.to_days(value))
+    }
+
+    /// The degenerate case these inputs fall into, if any.
+    pub fn degenerate(&self) -> Option<Degenerate> {
+        Degenerate::of(&self.inputs)
+    }
+
+    /// Caveats about numbers that evaluate but are implausible enough to suggest an input mistake,
+    /// with the likely mix-up; degenerate cases are reported by `degenerate` instead.
+    pub fn warnings(&self) -> Vec<String> {
+        let i = &self.inputs;
+        let mut warnings = Vec::new();
+        if self.ratio.is_finite() && self.ratio > IMPLAUSIBLE_RATIO {
+            warnings.push(format!("LTGP:CAC of {}:1 is far above what real businesses see (3–5:1 is strong). Check that LTGP is lifetime gross profit rather than revenue, and that CAC counts all sales and marketing spend, salaries and tools included.", ratio_text(self.ratio)));
+        }
+        if let Some(days) = self.payback_days()
+            && days > 0.0
+            && days < 1.0
+        {
+            let mut warning = format!("Payback in under a day ({} hours) is unusual. Check that {} is gross profit per {}", precision::fixed(days * 24.0, 1), money(i.early_gp_rate), i.period.unit());
+            // A monthly figure entered per day or week is the usual mix-up.
+            let likely = match i.period {
+                Period::Days | Period::Weeks => Some(Period::Months),
+                Period::Months => Some(Period::Years),
+                Period::Years => None,
+            };
+            match likely {
+                Some(longer) => warning.push_str(&format!(": if it's earned per {}, pass --period {} (payback {} {}).", longer.unit(), longer, precision::fixed(self.payback.unwrap_or_default(), 2), longer)),
+                None => warning.push('.'),
+            }
+            warnings.push(warning);
+        }
+        if i.ltgp > 0.0 && i.cfa > i.ltgp {
+            warnings.push(format!("CFA ({}) exceeds LTGP ({}): the customer pays more upfront than they bring in gross profit over their whole lifetime. Check that LTGP covers the full lifetime rather than one period, and that it is gross profit while CFA is cash collected.", money(i.cfa), money(i.ltgp)));
+        }
+        warnings
+    }
+}
+
+/// A dollar figure as typed, in the run's `--locale`: the currency symbol is ignored, and thousands
+/// separators only where they group digits.
+pub fn parse_money_like(s: &str) -> Option<f64> {
+    locale::active().parse(s)
+}
+
+/// The short form of a verdict: the part before the colon (`Good`, `Fragile`, …).
+pub fn verdict_label(verdict: &str) -> &str {
+    verdict.split(':').next().unwrap_or(verdict)
+}
+
+/// LTGP:CAC to two places (or `--precision`), or "unbounded" for a free customer, so no report
+/// prints `inf`.
+pub fn ratio_text(ratio: f64) -> String {
+    if ratio.is_finite() { precision::fixed(ratio, 2) } else { "unbounded".to_string() }
+}
+
+/// Formats a dollar amount with the sign in front of the currency symbol (e.g., -$314.75).
+pub fn money(v: f64) -> String {
+    if v < 0.0 { format!("-${}", precision::fixed(-v, 2)) } else { format!("${}", precision::fixed(v, 2)) }
+}
+
+/// Early gross profit converted to a per-month figure (average calendar months).
+pub fn monthly_gp(i: &Inputs) -> f64 {
+    i.amounts().early_gp.per(Period::Months).value()
+}
+
+pub fn evaluate(inputs: &Inputs) -> Evaluation {
+    let a = inputs.amounts();
+    let low_cac_thresh = a.low_cac_max.unwrap_or(a.low_cac_fraction.of(a.ltgp));
+    let high_cfa_thresh = a.high_cfa_min.unwrap_or(a.high_cfa_fraction.of(a.cac));
+
+    // Net cash you actually spend (CAC minus what the client covers upfront)
+    let net_outlay = (a.cac - a.cfa).or_zero();
+
+    // Lifetime return ratio: unbounded for a free customer, 0 when there is nothing to compare
+    let ratio = a.ltgp.ratio_to(a.cac).unwrap_or(if a.ltgp.is_positive() { f64::INFINITY } else { 0.0 });
+
+    let low_cac = a.cac <= low_cac_thresh;
+    let high_cfa = a.cfa >= high_cfa_thresh;
+
+    // Payback period estimate, in the early GP rate's period
+    let payback = a.early_gp.periods_to_earn(net_outlay);
+    let cac_payback_months = a.cac.ratio_to(a.early_gp.per(Period::Months));
+
+    let mut e = Evaluation {
+        inputs: inputs.clone(),
+        low_cac_thresh: low_cac_thresh.value(),
+        high_cfa_thresh: high_cfa_thresh.value(),
+        net_outlay: net_outlay.value(),
+        ratio,
+        low_cac,
+        high_cfa,
+        quadrant: Quadrant(&UNCLASSIFIED),
+        verdict: "",
+        payback,
+        cac_payback_months,
+    };
+
+    // Quadrant placement from the active decision tree (the CAC/CFA quadrants unless --tree replaced it)
+    e.quadrant = Quadrant(tree::active().classify(&e));
+
+    // Verdict from the active rules (the built-in policy unless --rules replaced it)
+    e.verdict = rules::active().verdict(&e).unwrap_or("Unclassified: No verdict rule matched these numbers.");
+    e
+}
@@ -0,0 +1,217 @@
+// C bindings: the evaluation as `extern "C"` functions, so other languages and legacy systems can
+// embed the calculator as a shared library. The header, include/ltgp_cac.h, is generated from this
+// file with cbindgen; regenerate it from crates/core after changing anything here:
+//
+//   cargo build --release -p ltgp-core --features ffi   (target/release/libltgp_core.so)
+//   cbindgen --config cbindgen.toml --output include/ltgp_cac.h
+//
+//   LtgpInputs in = ltgp_inputs_default(500, 2500);
+//   in.cfa = 200;
+//   LtgpResult r;
+//   if (ltgp_evaluate(&in, &r) == LTGP_OK) { printf("%.2f %s\n", r.ratio, r.verdict); ltgp_result_free(&r); }
+//
+// Optional numbers are NAN when unset or unknown. Strings the library returns are freed with the
+// matching `_free` function, never `free`.
+
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+use crate::evaluation::{self, Inputs};
+use crate::period::Period;
+use crate::report::{self, EvaluationResult};
+use crate::scenario::Scenario;
+use crate::severity::Severity;
+
+pub const LTGP_OK: i32 = 0;
+/// A required pointer was NULL.
+pub const LTGP_ERR_NULL: i32 = -1;
+/// `period` was not valid UTF-8, or not days, weeks, months, or years.
+pub const LTGP_ERR_PERIOD: i32 = -2;
+/// An input was out of range, such as a negative CAC or a low-CAC fraction above 1.
+pub const LTGP_ERR_INVALID: i32 = -3;
+
+/// The inputs to one evaluation; `ltgp_inputs_default` fills in the scenario file's defaults.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct LtgpInputs {
+    pub cac: f64,
+    pub cfa: f64,
+    pub ltgp: f64,
+    pub early_gp_rate: f64,
+    /// "days", "weeks", "months", or "years"; NULL for days.
+    pub period: *const c_char,
+    pub low_cac_fraction: f64,
+    pub high_cfa_fraction: f64,
+    /// NAN to use `low_cac_fraction`.
+    pub low_cac_max: f64,
+    /// NAN to use `high_cfa_fraction`.
+    pub high_cfa_min: f64,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LtgpSeverity {
+    Healthy,
+    Watch,
+    AtRisk,
+    Critical,
+}
+
+/// One evaluation; free it with `ltgp_result_free`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct LtgpResult {
+    pub net_outlay: f64,
+    /// LTGP:CAC; INFINITY for a free customer.
+    pub ratio: f64,
+    pub low_cac: bool,
+    pub high_cfa: bool,
+    pub low_cac_threshold: f64,
+    pub high_cfa_threshold: f64,
+    pub severity: LtgpSeverity,
+    /// Payback in the inputs' period; NAN without early gross profit.
+    pub payback: f64,
+    pub payback_days: f64,
+    pub cac_payback_months: f64,
+    pub quadrant: *mut c_char,
+    pub verdict: *mut c_char,
+}
+
+fn optional(v: f64) -> Option<f64> {
+    (!v.is_nan()).then_some(v)
+}
+
+fn owned(s: &str) -> *mut c_char {
+    // Labels come from the rules and tree files; an interior NUL is cut off there.
+    CString::new(s.split('\0').next().unwrap_or_default()).expect("no interior NUL").into_raw()
+}
+
+/// # Safety
+/// `inputs` must be NULL or point to a valid `LtgpInputs` whose `period` is NULL or a C string.
+unsafe fn inputs(inputs: *const LtgpInputs) -> Result<Inputs, i32> {
+    let i = unsafe { inputs.as_ref() }.ok_or(LTGP_ERR_NULL)?;
+    let period = if i.period.is_null() {
+        Period::Days
+    } else {
+        unsafe { CStr::from_ptr(i.period) }.to_str().ok().and_then(|p| p.parse().ok()).ok_or(LTGP_ERR_PERIOD)?
+    };
+    let mut s = Scenario::new(i.cac, i.ltgp);
+    s.cfa = i.cfa;
+    s.early_gp_rate = i.early_gp_rate;
+    s.period = period;
+    s.low_cac_fraction = i.low_cac_fraction;
+    s.high_cfa_fraction = i.high_cfa_fraction;
+    s.low_cac_max = optional(i.low_cac_max);
+    s.high_cfa_min = optional(i.high_cfa_min);
+    s.inputs().map_err(|_| LTGP_ERR_INVALID)
+}
+
+/// CAC and LTGP with every other input at its default.
+#[unsafe(no_mangle)]
+pub extern "C" fn ltgp_inputs_default(cac: f64, ltgp: f64) -> LtgpInputs {
+    let s = Scenario::new(cac, ltgp);
+    LtgpInputs {
+        cac,
+        cfa: s.cfa,
+        ltgp,
+        early_gp_rate: s.early_gp_rate,
+        period: ptr::null(),
+        low_cac_fraction: s.low_cac_fraction,
+        high_cfa_fraction: s.high_cfa_fraction,
+        low_cac_max: f64::NAN,
+        high_cfa_min: f64::NAN,
+    }
+}
+
+/// Evaluates `inputs` into `out`; returns `LTGP_OK` or an `LTGP_ERR_` code, leaving `out` untouched.
+///
+/// # Safety
+/// `inputs` must be NULL or point to a valid `LtgpInputs`; `out` must be NULL or writable.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ltgp_evaluate(inputs: *const LtgpInputs, out: *mut LtgpResult) -> i32 {
+    if out.is_null() {
+        return LTGP_ERR_NULL;
+    }
+    let e = match unsafe { self::inputs(inputs) } {
+        Ok(i) => evaluation::evaluate(&i),
+        Err(code) => return code,
+    };
+    let severity = match Severity::of_verdict(e.verdict) {
+        Severity::Healthy => LtgpSeverity::Healthy,
+        Severity::Watch => LtgpSeverity::Watch,
+        Severity::AtRisk => LtgpSeverity::AtRisk,
+        Severity::Critical => LtgpSeverity::Critical,
+    };
+    let result = LtgpResult {
+        net_outlay: e.net_outlay,
+        ratio: e.ratio,
+        low_cac: e.low_cac,
+        high_cfa: e.high_cfa,
+        low_cac_threshold: e.low_cac_thresh,
+        high_cfa_threshold: e.high_cfa_thresh,
+        severity,
+        payback: e.payback.unwrap_or(f64::NAN),
+        payback_days: e.payback_days().unwrap_or(f64::NAN),
+        cac_payback_months: e.cac_payback_months.unwrap_or(f64::NAN),
+        quadrant: owned(e.quadrant.name()),
+        verdict: owned(e.verdict),
+    };
+    unsafe { out.write(result) };
+    LTGP_OK
+}
+
+/// Frees a result's strings and sets them to NULL; safe to call twice.
+///
+/// # Safety
+/// `result` must be NULL or a result filled in by `ltgp_evaluate`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ltgp_result_free(result: *mut LtgpResult) {
+    let Some(r) = (unsafe { result.as_mut() }) else { return };
+    for s in [&mut r.quadrant, &mut r.verdict] {
+        if !s.is_null() {
+            drop(unsafe { CString::from_raw(*s) });
+            *s = ptr::null_mut();
+        }
+    }
+}
+
+/// The evaluation as the JSON `--format json` prints; NULL on bad inputs. Free with `ltgp_string_free`.
+///
+/// # Safety
+/// As for `ltgp_evaluate`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ltgp_evaluate_json(inputs: *const LtgpInputs) -> *mut c_char {
+    match unsafe { self::inputs(inputs) } {
+        Ok(i) => {
+            let e = evaluation::evaluate(&i);
+            owned(&report::json_value(&EvaluationResult::from(&e)).to_string())
+        }
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// The CLI's plain-text report; NULL on bad inputs. Free with `ltgp_string_free`.
+///
+/// # Safety
+/// As for `ltgp_evaluate`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ltgp_report(inputs: *const LtgpInputs) -> *mut c_char {
+    match unsafe { self::inputs(inputs) } {
+        Ok(i) => {
+            let e = evaluation::evaluate(&i);
+            owned(&report::render_report(&EvaluationResult::from(&e), &report::Plain))
+        }
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Frees a string from `ltgp_evaluate_json` or `ltgp_report`; NULL is ignored.
+///
+/// # Safety
+/// `s` must be NULL or a string this library returned, not yet freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ltgp_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(unsafe { CString::from_raw(s) });
+    }
+}
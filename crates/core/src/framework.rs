@@ -0,0 +1,41 @@
+// Decision frameworks: named pairs of verdict rules and decision tree, so operators can pick
+// the playbook they follow instead of writing their own rules and tree.
+
+
+use crate::rules::{self, Ruleset};
+use crate::tree::{self, Tree};
+
+const CLASSIC_RULES: &str = include_str!("classic_verdict_rules.yaml");
+const CLASSIC_TREE: &str = include_str!("classic_tree.yaml");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Framework {
+    /// Client-financed acquisition: CAC/CFA quadrants and LTGP:CAC above 3 (the default)
+    Hormozi,
+    /// LTV:CAC of at least 3:1 with CAC payback within 12 months
+    ClassicLtvCac,
+    /// Your own --rules and/or --tree files
+    Custom,
+}
+
+/// Installs the framework's rules and tree; `--rules` and `--tree` files replace either part.
+pub fn install(framework: Framework, rules_path: Option<&str>, tree_path: Option<&str>) -> Result<(), String> {
+    if framework == Framework::Custom && rules_path.is_none() && tree_path.is_none() {
+        return Err("--framework custom needs --rules, --tree, or both".to_string());
+    }
+    let ruleset = match (rules_path, framework) {
+        (Some(path), _) => Some(Ruleset::load(path)?),
+        (None, Framework::ClassicLtvCac) => Some(Ruleset::parse(CLASSIC_RULES).expect("bundled classic_verdict_rules.yaml is valid")),
+        (None, _) => None,
+    };
+    let decision_tree = match (tree_path, framework) {
+        (Some(path), _) => Some(Tree::load(path)?),
+        (None, Framework::ClassicLtvCac) => Some(Tree::parse(CLASSIC_TREE).expect("bundled classic_tree.yaml is valid")),
+        (None, _) => None,
+    };
+    // Anything not installed here falls back to the bundled Hormozi rules and quadrants.
+    if let Some(r) = ruleset { rules::install(r); }
+    if let Some(t) = decision_tree { tree::install(t); }
+    Ok(())
+}
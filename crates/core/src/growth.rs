@@ -0,0 +1,68 @@
+// Reinvestment growth simulation: each month, collected CFA and gross profit are put back into
+// acquisition, starting from a pool of starting cash.
+
+use serde::Serialize;
+
+use crate::channel::{self, Channel};
+use crate::evaluation::Inputs;
+use crate::plan::UnitCashFlow;
+
+/// Simulation policy shared by the growth tools.
+#[derive(Debug, Clone)]
+pub struct GrowthPolicy {
+    pub months: usize,
+    pub starting_cash: f64,
+    /// Fraction of available cash spent on acquisition each month (0..=1).
+    pub reinvest: f64,
+    pub max_monthly_spend: Option<f64>,
+    pub channels: Vec<Channel>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MonthRow {
+    pub month: usize,
+    pub acquired: f64,
+    pub active: f64,
+    pub spend: f64,
+    /// Gross profit from existing customers plus CFA and first-month gross profit from new ones.
+    pub cash_in: f64,
+    pub ending_cash: f64,
+}
+
+/// Customers bought with `spend`: through the channel mix if one is given, otherwise at flat CAC.
+fn customers_for(spend: f64, cac: f64, channels: &[Channel]) -> f64 {
+    if !channels.is_empty() { channel::volume_for_spend(channels, spend) } else if cac > 0.0 { spend / cac } else { 0.0 }
+}
+
+#[tracing::instrument(level = "debug", skip_all, fields(months = policy.months, reinvest = policy.reinvest))]
+pub fn simulate(inputs: &Inputs, policy: &GrowthPolicy) -> Vec<MonthRow> {
+    let unit = UnitCashFlow::from_inputs(inputs);
+    let lifetime = if unit.monthly_gp > 0.0 { (unit.ltgp / unit.monthly_gp).ceil() as usize } else { 0 };
+    let mut cohorts: Vec<f64> = Vec::with_capacity(policy.months);
+    let mut cash = policy.starting_cash;
+    let mut rows = Vec::with_capacity(policy.months);
+
+    for month in 0..policy.months {
+        let collections: f64 = cohorts.iter().enumerate().map(|(start, n)| n * unit.gp_in_month(month - start)).sum();
+        let available = (cash + collections).max(0.0);
+        let mut spend = available * policy.reinvest;
+        if let Some(cap) = policy.max_monthly_spend { spend = spend.min(cap); }
+        let acquired = customers_for(spend, inputs.cac, &policy.channels);
+        // With a channel mix, the capacity ceiling may leave part of the budget unspent.
+        if !policy.channels.is_empty() { spend = channel::allocate(&policy.channels, acquired).map(|a| a.spend).unwrap_or(spend); }
+        let new_cash = acquired * (unit.cfa + unit.gp_in_month(0));
+        cash = cash + collections - spend + new_cash;
+        cohorts.push(acquired);
+
+        let active = cohorts.iter().enumerate().filter(|(start, _)| month - start < lifetime).map(|(_, n)| n).sum();
+        rows.push(MonthRow { month: month + 1, acquired, active, spend, cash_in: collections + new_cash, ending_cash: cash });
+        tracing::trace!(month = month + 1, acquired, spend, ending_cash = cash, "month simulated");
+    }
+    tracing::debug!(ending_cash = cash, "simulated");
+    rows
+}
+
+/// First month whose cash in covers that month's acquisition spend.
+pub fn self_funding_month(rows: &[MonthRow]) -> Option<usize> {
+    rows.iter().find(|r| r.spend > 0.0 && r.cash_in >= r.spend).map(|r| r.month)
+}
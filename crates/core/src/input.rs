@@ -0,0 +1,185 @@
+// Validated construction of evaluation inputs, for library consumers:
+//
+//   let inputs = EvaluationInput::builder().cac(500.0).ltgp(2500.0).cfa(200.0).build()?;
+//   let result = evaluation::evaluate(&inputs);
+//
+// CAC and LTGP are required; everything else starts at the command line's default. `build`
+// checks every field at once, so a mistyped amount fails there rather than turning
+// into a misleading verdict.
+//
+// Inputs from anywhere else (flags, scenario files, batch rows, API requests) go through
+// `validate` the same way. Nothing is quietly zeroed: a caller that would rather move a
+// negative CFA or early GP rate to 0 opts in with `clamp`, which says what it changed:
+//
+//   let adjusted = input::clamp(&mut inputs);   // ["CFA -50 adjusted to 0"]
+//   let inputs = input::validate(inputs)?;
+
+use crate::evaluation::{self, Inputs};
+use crate::period::Period;
+
+/// The inputs as the builder makes them; the same type `evaluation::evaluate` takes.
+pub type EvaluationInput = Inputs;
+
+impl Inputs {
+    pub fn builder() -> InputsBuilder {
+        InputsBuilder::default()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct InputsBuilder {
+    cac: Option<f64>,
+    ltgp: Option<f64>,
+    cfa: Option<f64>,
+    early_gp_rate: Option<f64>,
+    period: Option<Period>,
+    low_cac_fraction: Option<f64>,
+    high_cfa_fraction: Option<f64>,
+    low_cac_max: Option<f64>,
+    high_cfa_min: Option<f64>,
+}
+
+/// A number field's name, accessors, and valid range.
+struct Range {
+    name: &'static str,
+    get: fn(&Inputs) -> Option<f64>,
+    set: fn(&mut Inputs, f64),
+    max: f64,
+}
+
+/// Every number field is at least 0; only the low-CAC fraction has a ceiling.
+const RANGES: [Range; 8] = [
+    Range { name: "CAC", get: |i| Some(i.cac), set: |i, v| i.cac = v, max: f64::INFINITY },
+    Range { name: "CFA", get: |i| Some(i.cfa), set: |i, v| i.cfa = v, max: f64::INFINITY },
+    Range { name: "LTGP", get: |i| Some(i.ltgp), set: |i, v| i.ltgp = v, max: f64::INFINITY },
+    Range { name: "early GP rate", get: |i| Some(i.early_gp_rate), set: |i, v| i.early_gp_rate = v, max: f64::INFINITY },
+    Range { name: "low-CAC fraction", get: |i| Some(i.low_cac_fraction), set: |i, v| i.low_cac_fraction = v, max: 1.0 },
+    Range { name: "high-CFA fraction", get: |i| Some(i.high_cfa_fraction), set: |i, v| i.high_cfa_fraction = v, max: f64::INFINITY },
+    Range { name: "low-CAC cut", get: |i| i.low_cac_max, set: |i, v| i.low_cac_max = Some(v), max: f64::INFINITY },
+    Range { name: "high-CFA cut", get: |i| i.high_cfa_min, set: |i, v| i.high_cfa_min = Some(v), max: f64::INFINITY },
+];
+
+/// Every number in `inputs` that is out of range, one message each.
+pub fn problems(inputs: &Inputs) -> Vec<String> {
+    RANGES
+        .iter()
+        .filter_map(|r| {
+            let v = (r.get)(inputs)?;
+            if !v.is_finite() {
+                Some(format!("{} must be a finite number, got {}", r.name, v))
+            } else if r.max.is_finite() && !(0.0..=r.max).contains(&v) {
+                Some(format!("{} must be between 0 and {}, got {}", r.name, r.max, v))
+            } else if v < 0.0 {
+                Some(format!("{} can't be negative, got {}", r.name, v))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// `inputs` when every number is in range, otherwise every problem joined into one message.
+pub fn validate(inputs: Inputs) -> Result<Inputs, String> {
+    let problems = problems(&inputs);
+    if problems.is_empty() { Ok(inputs) } else { Err(problems.join("; ")) }
+}
+
+/// Moves each out-of-range number to the nearest valid one and describes every change.
+/// Non-finite numbers are left alone for `validate` to reject.
+pub fn clamp(inputs: &mut Inputs) -> Vec<String> {
+    let mut adjusted = Vec::new();
+    for r in &RANGES {
+        if let Some(v) = (r.get)(inputs)
+            && v.is_finite()
+            && !(0.0..=r.max).contains(&v)
+        {
+            let to = v.clamp(0.0, r.max);
+            (r.set)(inputs, to);
+            adjusted.push(format!("{} {} adjusted to {}", r.name, v, to));
+        }
+    }
+    adjusted
+}
+
+impl InputsBuilder {
+    /// Cost to acquire a customer, in dollars (required).
+    pub fn cac(mut self, cac: f64) -> Self {
+        self.cac = Some(cac);
+        self
+    }
+
+    /// Lifetime gross profit per customer, in dollars (required).
+    pub fn ltgp(mut self, ltgp: f64) -> Self {
+        self.ltgp = Some(ltgp);
+        self
+    }
+
+    /// Cash collected from a customer upfront, in dollars; 0 by default.
+    pub fn cfa(mut self, cfa: f64) -> Self {
+        self.cfa = Some(cfa);
+        self
+    }
+
+    /// Gross profit per customer per `period` at the start; 0 (payback unknown) by default.
+    pub fn early_gp_rate(mut self, rate: f64) -> Self {
+        self.early_gp_rate = Some(rate);
+        self
+    }
+
+    /// The unit of the early GP rate; days by default.
+    pub fn period(mut self, period: Period) -> Self {
+        self.period = Some(period);
+        self
+    }
+
+    /// CAC is low at or below this fraction of LTGP (0 to 1); 0.10 by default.
+    pub fn low_cac_fraction(mut self, fraction: f64) -> Self {
+        self.low_cac_fraction = Some(fraction);
+        self
+    }
+
+    /// CFA is high at or above this fraction of CAC; 0.5 by default.
+    pub fn high_cfa_fraction(mut self, fraction: f64) -> Self {
+        self.high_cfa_fraction = Some(fraction);
+        self
+    }
+
+    /// CAC is low at or below this many dollars, instead of a fraction of LTGP.
+    pub fn low_cac_max(mut self, dollars: f64) -> Self {
+        self.low_cac_max = Some(dollars);
+        self
+    }
+
+    /// CFA is high at or above this many dollars, instead of a fraction of CAC.
+    pub fn high_cfa_min(mut self, dollars: f64) -> Self {
+        self.high_cfa_min = Some(dollars);
+        self
+    }
+
+    /// The inputs, or every problem with them joined into one message.
+    pub fn build(self) -> Result<Inputs, String> {
+        let mut problems = Vec::new();
+        if self.cac.is_none() {
+            problems.push("CAC is required".to_string());
+        }
+        if self.ltgp.is_none() {
+            problems.push("LTGP is required".to_string());
+        }
+        let inputs = Inputs {
+            cac: self.cac.unwrap_or(0.0),
+            cfa: self.cfa.unwrap_or(0.0),
+            ltgp: self.ltgp.unwrap_or(0.0),
+            early_gp_rate: self.early_gp_rate.unwrap_or(0.0),
+            period: self.period.unwrap_or_default(),
+            low_cac_fraction: self.low_cac_fraction.unwrap_or(evaluation::DEFAULT_LOW_CAC_FRACTION),
+            high_cfa_fraction: self.high_cfa_fraction.unwrap_or(evaluation::DEFAULT_HIGH_CFA_FRACTION),
+            low_cac_max: self.low_cac_max,
+            high_cfa_min: self.high_cfa_min,
+        };
+        problems.extend(self::problems(&inputs));
+        if !problems.is_empty() {
+            return Err(problems.join("; "));
+        }
+        Ok(inputs)
+    }
+}
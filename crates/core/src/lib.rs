@@ -0,0 +1,34 @@
+// Crow's Nest core: the evaluation, verdict rules, decision trees, scenario files, simulator,
+// growth model, and solver the command-line tool is built on, as a library so other front ends
+// run exactly the same logic. It is types and math only, with no command-line, terminal, network,
+// or server dependencies, so it builds on its own, e.g. for WebAssembly:
+//
+//   cargo build -p ltgp-core --features wasm --target wasm32-unknown-unknown
+
+pub mod channel;
+pub mod evaluation;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod framework;
+pub mod growth;
+pub mod input;
+pub mod locale;
+pub mod metric;
+#[cfg(feature = "node")]
+pub mod node;
+pub mod period;
+pub mod plan;
+pub mod precision;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod recommend;
+pub mod report;
+pub mod rules;
+pub mod scenario;
+pub mod severity;
+pub mod simulation;
+pub mod theme;
+pub mod tree;
+pub mod units;
+#[cfg(feature = "wasm")]
+pub mod wasm;
@@ -0,0 +1,126 @@
+// Number formats for typed amounts. `--locale` (or `locale` in the config file) says how the
+// person at the keyboard writes numbers, and every amount typed into a flag or the interactive
+// form is read that way:
+//
+//   en (the default), ja, zh, …          2,500.75
+//   de, es, it, nl, pt, da, tr, …        2.500,75
+//   fr, ru, pl, sv, fi, nb, cs, …        2 500,75
+//   de-CH, fr-CH, it-CH                  2'500.75
+//
+// A grouping separator only counts between whole groups of three digits, so "1,5" under `en` is
+// rejected rather than read as 15. Text that isn't a number in the locale is still taken as a plain
+// number ("2500.75"), so scripts and defaults work in every locale. Files (batch CSVs, scaling
+// data) and comma-separated lists (`--retention 1,0.82,0.74`) are always read as `en`.
+
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Currency symbols ignored in typed amounts.
+const CURRENCY: [char; 3] = ['$', '€', '£'];
+
+/// How a locale writes numbers: the decimal mark and the thousands separator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    decimal: char,
+    group: char,
+}
+
+impl Default for Locale {
+    fn default() -> Locale {
+        Locale::EN
+    }
+}
+
+static LOCALE: OnceLock<Locale> = OnceLock::new();
+
+/// Sets the locale typed amounts are read in for the rest of the run.
+pub fn set(locale: Locale) {
+    let _ = LOCALE.set(locale);
+}
+
+/// The run's locale; `en` unless set.
+pub fn active() -> Locale {
+    LOCALE.get().copied().unwrap_or_default()
+}
+
+impl Locale {
+    /// 2,500.75
+    pub const EN: Locale = Locale { decimal: '.', group: ',' };
+    /// 2.500,75
+    const DOT_GROUPED: Locale = Locale { decimal: ',', group: '.' };
+    /// 2 500,75
+    const SPACE_GROUPED: Locale = Locale { decimal: ',', group: ' ' };
+    /// 2'500.75
+    const SWISS: Locale = Locale { decimal: '.', group: '\'' };
+
+    fn is_group(self, c: char) -> bool {
+        // Typists and copied figures use a no-break or narrow no-break space as often as a space.
+        c == self.group || (self.group == ' ' && matches!(c, '\u{a0}' | '\u{202f}'))
+    }
+
+    /// `s` as a number in this locale, or else as a plain number, ignoring a currency symbol; None
+    /// when it is neither.
+    pub fn parse(self, s: &str) -> Option<f64> {
+        let cleaned: String = s.chars().filter(|c| !CURRENCY.contains(c)).collect();
+        let cleaned = cleaned.trim();
+        self.localized(cleaned).or_else(|| cleaned.parse().ok())
+    }
+
+    fn localized(self, cleaned: &str) -> Option<f64> {
+        let (sign, digits) = match cleaned.strip_prefix('-') {
+            Some(rest) => ("-", rest.trim_start()),
+            None => ("", cleaned.strip_prefix('+').unwrap_or(cleaned)),
+        };
+        let (whole, fraction) = match digits.split_once(self.decimal) {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (digits, None),
+        };
+        let groups: Vec<&str> = whole.split(|c| self.is_group(c)).collect();
+        if let [first, rest @ ..] = groups.as_slice()
+            && !rest.is_empty()
+        {
+            let digits_only = |g: &str| !g.is_empty() && g.chars().all(|c| c.is_ascii_digit());
+            if !digits_only(first) || first.len() > 3 || rest.iter().any(|g| g.len() != 3 || !digits_only(g)) {
+                return None;
+            }
+        }
+        let whole = groups.concat();
+        let number = match fraction {
+            Some(fraction) => format!("{}{}.{}", sign, whole, fraction),
+            None => format!("{}{}", sign, whole),
+        };
+        number.parse().ok()
+    }
+
+    /// 2500.75 written the way this locale writes it, for hints.
+    pub fn example(self) -> String {
+        format!("2{}500{}75", self.group, self.decimal)
+    }
+}
+
+/// A number flag's value, read in the run's locale like an amount.
+pub fn number(s: &str) -> Result<f64, String> {
+    active().parse(s).ok_or_else(|| format!("`{}` is not a number (e.g. {})", s, active().example()))
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    /// A language tag (`de`, `de-DE`, `fr_CH.UTF-8`); the region only matters where it changes the
+    /// format, as in Switzerland and Mexico.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tag = s.split('.').next().unwrap_or_default().replace('_', "-").to_ascii_lowercase();
+        let (language, region) = tag.split_once('-').unwrap_or((&tag, ""));
+        let locale = match language {
+            "c" | "posix" | "en" | "ja" | "zh" | "ko" | "he" | "th" | "hi" | "ms" | "ga" => Locale::EN,
+            "de" | "es" | "it" | "nl" | "pt" | "da" | "tr" | "id" | "el" | "ro" | "hr" | "sl" | "sr" | "vi" => Locale::DOT_GROUPED,
+            "fr" | "ru" | "pl" | "sv" | "fi" | "nb" | "nn" | "no" | "cs" | "sk" | "uk" | "hu" | "bg" | "lt" | "lv" | "et" => Locale::SPACE_GROUPED,
+            _ => return Err(format!("unknown locale `{}` (use a language tag such as en-US, de-DE, fr-FR, or de-CH)", s)),
+        };
+        Ok(match region {
+            "ch" | "li" => Locale::SWISS,
+            "mx" | "us" if language == "es" => Locale::EN,
+            _ => locale,
+        })
+    }
+}
@@ -0,0 +1,233 @@
+// Metrics as a pipeline: each `Metric` computes one number from the evaluation (and, for
+// company-level metrics, the company's own figures) and interprets it as a severity and a note.
+// The capital efficiency dashboard (the `dashboard` subcommand) is `Pipeline::builtin()` run over
+// one context; a new dashboard metric is a `Metric` impl plus one `register` call, optionally
+// behind a cargo feature. The main report's sections don't run through the pipeline.
+
+use serde::Serialize;
+
+use crate::evaluation::{ratio_text, Evaluation};
+use crate::precision;
+use crate::severity::Severity;
+
+/// Company-level figures, per month unless noted.
+#[derive(Debug, Clone, Copy)]
+pub struct Company {
+    /// Net cash burned (0 or negative when cash-flow positive).
+    pub net_burn: f64,
+    /// ARR added, net of churn and contraction.
+    pub net_new_arr: f64,
+    /// Sales and marketing spend.
+    pub sm_spend: f64,
+    /// Cash on hand today.
+    pub cash: f64,
+}
+
+/// What metrics compute from.
+pub struct Context<'a> {
+    pub evaluation: &'a Evaluation,
+    pub company: Option<Company>,
+}
+
+/// A metric's value as shown, with its severity and the cut points or reason behind it.
+#[derive(Debug, Clone, Serialize)]
+pub struct Reading {
+    pub shown: String,
+    pub severity: Severity,
+    pub note: String,
+}
+
+impl Reading {
+    fn new(shown: String, severity: Severity, note: &str) -> Reading {
+        Reading { shown, severity, note: note.to_string() }
+    }
+}
+
+pub trait Metric {
+    fn name(&self) -> &'static str;
+
+    /// Whether the context has what the metric needs; the pipeline skips it otherwise.
+    fn applies(&self, cx: &Context) -> bool {
+        let _ = cx;
+        true
+    }
+
+    /// The value; None when it is undefined for these numbers (e.g. payback without gross profit).
+    fn compute(&self, cx: &Context) -> Option<f64>;
+
+    fn interpret(&self, value: Option<f64>, cx: &Context) -> Reading;
+}
+
+/// Healthy, watch, or at risk from two cut points; `higher_is_better` says which way they run.
+pub fn grade(value: f64, good: f64, watch: f64, higher_is_better: bool) -> Severity {
+    let at_least = |cut: f64| if higher_is_better { value >= cut } else { value <= cut };
+    if at_least(good) { Severity::Healthy } else if at_least(watch) { Severity::Watch } else { Severity::AtRisk }
+}
+
+/// Net burn ÷ net new ARR: dollars burned per dollar of ARR added. None when ARR isn't growing.
+pub fn burn_multiple(net_burn: f64, net_new_arr: f64) -> Option<f64> {
+    (net_new_arr > 0.0).then(|| net_burn.max(0.0) / net_new_arr)
+}
+
+/// Net new ARR ÷ sales and marketing spend: ARR bought per dollar of S&M.
+pub fn magic_number(net_new_arr: f64, sm_spend: f64) -> Option<f64> {
+    (sm_spend > 0.0).then(|| net_new_arr / sm_spend)
+}
+
+/// Months of cash at the current burn; None when not burning.
+pub fn runway_months(cash: f64, net_burn: f64) -> Option<f64> {
+    (net_burn > 0.0).then(|| cash.max(0.0) / net_burn)
+}
+
+pub struct LtgpCac;
+
+impl Metric for LtgpCac {
+    fn name(&self) -> &'static str {
+        "LTGP:CAC"
+    }
+
+    fn compute(&self, cx: &Context) -> Option<f64> {
+        Some(cx.evaluation.ratio)
+    }
+
+    fn interpret(&self, value: Option<f64>, _: &Context) -> Reading {
+        let ratio = value.unwrap_or(f64::INFINITY);
+        Reading::new(ratio_text(ratio), grade(ratio, 3.0, 1.0, true), "good above 3, underwater below 1")
+    }
+}
+
+pub struct CacPayback;
+
+impl Metric for CacPayback {
+    fn name(&self) -> &'static str {
+        "CAC payback"
+    }
+
+    fn compute(&self, cx: &Context) -> Option<f64> {
+        cx.evaluation.cac_payback_months
+    }
+
+    fn interpret(&self, value: Option<f64>, _: &Context) -> Reading {
+        match value {
+            Some(m) => Reading::new(format!("{} months", precision::fixed(m, 1)), grade(m, 12.0, 24.0, false), "good within 12 months, risky past 24"),
+            None => Reading::new("unknown".to_string(), Severity::Watch, "provide --early-gp-rate"),
+        }
+    }
+}
+
+pub struct BurnMultiple;
+
+impl Metric for BurnMultiple {
+    fn name(&self) -> &'static str {
+        "Burn multiple"
+    }
+
+    fn applies(&self, cx: &Context) -> bool {
+        cx.company.is_some()
+    }
+
+    fn compute(&self, cx: &Context) -> Option<f64> {
+        cx.company.and_then(|c| burn_multiple(c.net_burn, c.net_new_arr))
+    }
+
+    fn interpret(&self, value: Option<f64>, cx: &Context) -> Reading {
+        let cash_flow_positive = cx.company.is_some_and(|c| c.net_burn <= 0.0);
+        match value {
+            Some(b) if cash_flow_positive => Reading::new(precision::fixed(b, 2), Severity::Healthy, "cash-flow positive while growing"),
+            Some(b) => Reading::new(precision::fixed(b, 2), grade(b, 1.5, 2.0, false), "good at 1.5 or less, risky above 2"),
+            None => Reading::new("n/a".to_string(), Severity::AtRisk, "ARR is not growing"),
+        }
+    }
+}
+
+pub struct MagicNumber;
+
+impl Metric for MagicNumber {
+    fn name(&self) -> &'static str {
+        "Magic number"
+    }
+
+    fn applies(&self, cx: &Context) -> bool {
+        cx.company.is_some()
+    }
+
+    fn compute(&self, cx: &Context) -> Option<f64> {
+        cx.company.and_then(|c| magic_number(c.net_new_arr, c.sm_spend))
+    }
+
+    fn interpret(&self, value: Option<f64>, _: &Context) -> Reading {
+        match value {
+            Some(m) => Reading::new(precision::fixed(m, 2), grade(m, 0.75, 0.5, true), "good at 0.75 or more, weak below 0.5"),
+            None => Reading::new("n/a".to_string(), Severity::Watch, "no sales and marketing spend"),
+        }
+    }
+}
+
+pub struct Runway;
+
+impl Metric for Runway {
+    fn name(&self) -> &'static str {
+        "Runway"
+    }
+
+    fn applies(&self, cx: &Context) -> bool {
+        cx.company.is_some()
+    }
+
+    fn compute(&self, cx: &Context) -> Option<f64> {
+        cx.company.and_then(|c| runway_months(c.cash, c.net_burn))
+    }
+
+    fn interpret(&self, value: Option<f64>, _: &Context) -> Reading {
+        match value {
+            Some(r) => Reading::new(format!("{} months", precision::fixed(r, 1)), grade(r, 18.0, 12.0, true), "good at 18+ months, risky under 12"),
+            None => Reading::new("unlimited".to_string(), Severity::Healthy, "not burning cash"),
+        }
+    }
+}
+
+/// One metric's result from a pipeline run.
+#[derive(Debug, Clone, Serialize)]
+pub struct MetricValue {
+    pub name: &'static str,
+    pub value: Option<f64>,
+    pub reading: Reading,
+}
+
+pub struct Pipeline {
+    metrics: Vec<Box<dyn Metric>>,
+}
+
+impl Pipeline {
+    /// Unit economics first, then company-level efficiency.
+    pub fn builtin() -> Pipeline {
+        let mut pipeline = Pipeline { metrics: Vec::new() };
+        pipeline.register(Box::new(LtgpCac));
+        pipeline.register(Box::new(CacPayback));
+        pipeline.register(Box::new(BurnMultiple));
+        pipeline.register(Box::new(MagicNumber));
+        pipeline.register(Box::new(Runway));
+        pipeline
+    }
+
+    /// Adds a metric at the end; a later registration with the same name replaces the earlier one
+    /// in place.
+    pub fn register(&mut self, metric: Box<dyn Metric>) {
+        match self.metrics.iter().position(|m| m.name() == metric.name()) {
+            Some(at) => self.metrics[at] = metric,
+            None => self.metrics.push(metric),
+        }
+    }
+
+    /// Every metric that applies to `cx`, in registration order.
+    pub fn run(&self, cx: &Context) -> Vec<MetricValue> {
+        self.metrics
+            .iter()
+            .filter(|m| m.applies(cx))
+            .map(|m| {
+                let value = m.compute(cx);
+                MetricValue { name: m.name(), value, reading: m.interpret(value, cx) }
+            })
+            .collect()
+    }
+}
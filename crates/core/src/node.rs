@@ -0,0 +1,205 @@
+// Node.js bindings: a native module with `evaluate`, `simulate`, and `solve`, so TypeScript tools
+// call the calculator directly instead of shelling out and parsing its output. Built with the
+// napi-rs CLI from package.json, which also writes the TypeScript declarations:
+//
+//   npm install && npm run build
+//
+//   const { evaluate, simulate, solve } = require("ltgp-cac");
+//   const inputs = { cac: 500, ltgp: 2500, cfa: 200, earlyGpRate: 50 };
+//   evaluate(inputs).verdict;
+//   simulate(inputs, { cacSd: 80, ltgpSd: 400 }).ratio.p50;
+//   solve(inputs).map((lever) => lever.action);
+//
+// Field names are the scenario file's in camelCase, with the same defaults.
+
+use napi::{Error, Result};
+use napi_derive::napi;
+
+use crate::evaluation;
+use crate::recommend;
+use crate::report::EvaluationResult;
+use crate::scenario::Scenario;
+use crate::simulation::{self, Uncertainty, MAX_RUNS};
+
+/// The inputs to one evaluation; anything left out takes the scenario file's default.
+#[napi(object)]
+pub struct Inputs {
+    pub name: Option<String>,
+    pub cac: f64,
+    pub cfa: Option<f64>,
+    pub ltgp: f64,
+    pub early_gp_rate: Option<f64>,
+    /// days, weeks, months, or years.
+    pub period: Option<String>,
+    pub low_cac_fraction: Option<f64>,
+    pub high_cfa_fraction: Option<f64>,
+    pub low_cac_max: Option<f64>,
+    pub high_cfa_min: Option<f64>,
+}
+
+impl Inputs {
+    fn scenario(&self) -> Result<Scenario> {
+        let mut s = Scenario::new(self.cac, self.ltgp);
+        s.name = self.name.clone();
+        s.cfa = self.cfa.unwrap_or(s.cfa);
+        s.early_gp_rate = self.early_gp_rate.unwrap_or(s.early_gp_rate);
+        if let Some(period) = &self.period {
+            s.period = period.parse().map_err(Error::from_reason)?;
+        }
+        s.low_cac_fraction = self.low_cac_fraction.unwrap_or(s.low_cac_fraction);
+        s.high_cfa_fraction = self.high_cfa_fraction.unwrap_or(s.high_cfa_fraction);
+        s.low_cac_max = self.low_cac_max;
+        s.high_cfa_min = self.high_cfa_min;
+        Ok(s)
+    }
+}
+
+/// One evaluation: the fields `--format json` prints.
+#[napi(object)]
+pub struct Evaluation {
+    pub name: Option<String>,
+    pub net_outlay: f64,
+    /// LTGP:CAC; Infinity for a free customer.
+    pub ratio: f64,
+    pub low_cac: bool,
+    pub high_cfa: bool,
+    /// low or high.
+    pub cac_class: String,
+    pub cfa_class: String,
+    pub low_cac_threshold: f64,
+    pub high_cfa_threshold: f64,
+    pub quadrant: String,
+    pub quadrant_description: String,
+    pub verdict: String,
+    /// healthy, watch, at-risk, or critical.
+    pub severity: String,
+    /// Payback in the inputs' period; absent without early gross profit.
+    pub payback: Option<f64>,
+    pub payback_days: Option<f64>,
+    pub monthly_gp: f64,
+    pub cac_payback_months: Option<f64>,
+    /// no-economics, free-acquisition, no-profit, or self-funded; absent for ordinary inputs.
+    pub degenerate: Option<String>,
+    pub degenerate_explanation: Option<String>,
+    /// Caveats about the inputs.
+    pub warnings: Vec<String>,
+    pub evaluated_at: Option<String>,
+    pub timezone: Option<String>,
+}
+
+/// The scenario's inputs, or an error naming each one out of range.
+fn checked(inputs: &Inputs) -> Result<evaluation::Inputs> {
+    inputs.scenario()?.inputs().map_err(Error::from_reason)
+}
+
+/// The evaluation as the typed result every other output renders from, so JavaScript sees the
+/// same fields as `--format json`.
+fn evaluated(inputs: &Inputs) -> Result<Evaluation> {
+    let s = inputs.scenario()?;
+    let r = EvaluationResult::from(&evaluation::evaluate(&checked(inputs)?));
+    let ratio = r.ratio_value();
+    Ok(Evaluation {
+        name: s.name,
+        net_outlay: r.net_outlay,
+        ratio,
+        low_cac: r.low_cac,
+        high_cfa: r.high_cfa,
+        cac_class: r.cac_class.name().to_lowercase(),
+        cfa_class: r.cfa_class.name().to_lowercase(),
+        low_cac_threshold: r.low_cac_threshold,
+        high_cfa_threshold: r.high_cfa_threshold,
+        quadrant: r.quadrant,
+        quadrant_description: r.quadrant_description,
+        verdict: r.verdict,
+        severity: r.severity.level.name().to_string(),
+        payback: r.payback,
+        payback_days: r.payback_days,
+        monthly_gp: r.monthly_gp,
+        cac_payback_months: r.cac_payback_months,
+        degenerate: r.degenerate.as_ref().map(|d| d.kind.name().to_string()),
+        degenerate_explanation: r.degenerate.map(|d| d.explanation),
+        warnings: r.warnings,
+        evaluated_at: r.evaluated_at,
+        timezone: r.timezone,
+    })
+}
+
+/// Standard deviations on the inputs, and how many draws to take.
+#[napi(object)]
+pub struct SimulateOptions {
+    pub cac_sd: Option<f64>,
+    pub cfa_sd: Option<f64>,
+    pub ltgp_sd: Option<f64>,
+    /// Default 10000.
+    pub runs: Option<u32>,
+    /// Default 42, so repeated calls agree.
+    pub seed: Option<u32>,
+}
+
+/// The 5th, 50th, and 95th percentiles.
+#[napi(object)]
+pub struct Interval {
+    pub p5: f64,
+    pub p50: f64,
+    pub p95: f64,
+}
+
+impl From<simulation::Interval> for Interval {
+    fn from(i: simulation::Interval) -> Interval {
+        Interval { p5: i.p5, p50: i.p50, p95: i.p95 }
+    }
+}
+
+#[napi(object)]
+pub struct Simulation {
+    /// The evaluation at the means.
+    pub base: Evaluation,
+    pub ratio: Interval,
+    pub chance_ratio_at_most_3: f64,
+    /// Absent without early gross profit.
+    pub payback_days: Option<Interval>,
+    /// Share of draws with the base verdict.
+    pub verdict_holds: f64,
+}
+
+/// A change that improves the economics, and its re-evaluated effect.
+#[napi(object)]
+pub struct Lever {
+    pub action: String,
+    pub effect: String,
+}
+
+/// Evaluates one set of inputs.
+#[napi]
+pub fn evaluate(inputs: Inputs) -> Result<Evaluation> {
+    evaluated(&inputs)
+}
+
+/// Propagates standard deviations on CAC, CFA, and LTGP through the evaluation.
+#[napi]
+pub fn simulate(inputs: Inputs, options: Option<SimulateOptions>) -> Result<Simulation> {
+    let o = options.unwrap_or(SimulateOptions { cac_sd: None, cfa_sd: None, ltgp_sd: None, runs: None, seed: None });
+    let runs = o.runs.unwrap_or(10_000);
+    if runs == 0 || runs > MAX_RUNS {
+        return Err(Error::from_reason(format!("runs must be from 1 to {}", MAX_RUNS)));
+    }
+    let sd = |name: &str, sd: Option<f64>| simulation::check_sd(name, sd.unwrap_or(0.0)).map_err(Error::from_reason);
+    let unc = Uncertainty { cac_sd: sd("cacSd", o.cac_sd)?, cfa_sd: sd("cfaSd", o.cfa_sd)?, ltgp_sd: sd("ltgpSd", o.ltgp_sd)? };
+    let base = evaluated(&inputs)?;
+    let samples = simulation::simulate(&checked(&inputs)?, &unc, runs as usize, o.seed.unwrap_or(42) as u64);
+    let summary = simulation::summarize(&samples, &base.verdict);
+    Ok(Simulation {
+        ratio: summary.ratio.into(),
+        chance_ratio_at_most_3: summary.chance_ratio_at_most_3,
+        payback_days: summary.payback_days.map(Interval::from),
+        verdict_holds: summary.verdict_holds,
+        base,
+    })
+}
+
+/// The changes to CAC, LTGP, or CFA that fix the economics, most important first.
+#[napi]
+pub fn solve(inputs: Inputs) -> Result<Vec<Lever>> {
+    let e = evaluation::evaluate(&checked(&inputs)?);
+    Ok(recommend::levers(&e).into_iter().map(|l| Lever { action: l.action, effect: l.effect }).collect())
+}
@@ -0,0 +1,126 @@
+// The unit the early gross profit rate (and so payback) is expressed in. Days and weeks convert
+// exactly; a month or a year has no fixed length, so both convert at the Gregorian calendar's
+// average (365.2425 days a year, a twelfth of that a month) and every figure derived from them is
+// labeled an approximation. Payback, monthly gross profit, and the importers all convert through
+// here, so no two outputs use different month lengths.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Average days in a Gregorian year.
+pub const DAYS_PER_YEAR: f64 = 365.2425;
+
+/// Average days in a Gregorian month.
+pub const DAYS_PER_MONTH: f64 = DAYS_PER_YEAR / 12.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase", try_from = "String")]
+pub enum Period {
+    #[default]
+    Days,
+    Weeks,
+    /// Average calendar months (≈ 30.44 days)
+    Months,
+    /// Average calendar years (365.2425 days)
+    Years,
+}
+
+impl Period {
+    pub const ALL: [Period; 4] = [Period::Days, Period::Weeks, Period::Months, Period::Years];
+
+    /// days, weeks, months, or years.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Period::Days => "days",
+            Period::Weeks => "weeks",
+            Period::Months => "months",
+            Period::Years => "years",
+        }
+    }
+
+    /// The singular, for "per week" and the like.
+    pub fn unit(&self) -> &'static str {
+        self.name().trim_end_matches('s')
+    }
+
+    /// Days in one period; an average for months and years.
+    pub fn days(&self) -> f64 {
+        match self {
+            Period::Days => 1.0,
+            Period::Weeks => 7.0,
+            Period::Months => DAYS_PER_MONTH,
+            Period::Years => DAYS_PER_YEAR,
+        }
+    }
+
+    /// Whether converting to days averages over calendar months or years.
+    pub fn is_approximate(&self) -> bool {
+        matches!(self, Period::Months | Period::Years)
+    }
+
+    /// `value` periods in days.
+    pub fn to_days(&self, value: f64) -> f64 {
+        value * self.days()
+    }
+
+    /// The period `s` is most likely a slip for: a prefix ("w", "mo", "month") or a typo
+    /// within two edits ("monhts"). Nothing for a different unit altogether, like "fortnights".
+    fn closest(s: &str) -> Option<Period> {
+        if s.is_empty() {
+            return None;
+        }
+        if let Some(p) = Period::ALL.iter().find(|p| p.name().starts_with(s)) {
+            return Some(*p);
+        }
+        Period::ALL.iter().map(|p| (edit_distance(s, p.name()), *p)).filter(|(d, _)| *d <= 2).min_by_key(|(d, _)| *d).map(|(_, p)| p)
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Period {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let wanted = s.trim().to_lowercase();
+        Period::ALL.iter().copied().find(|p| p.name() == wanted).ok_or_else(|| {
+            let names = Period::ALL.iter().map(|p| p.name()).collect::<Vec<_>>().join(", ");
+            match Period::closest(&wanted) {
+                Some(p) => format!("unknown period `{}`; did you mean `{}`? (available: {})", s, p, names),
+                None => format!("unknown period `{}` (available: {})", s, names),
+            }
+        })
+    }
+}
+
+impl TryFrom<String> for Period {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Levenshtein distance: single-character insertions, deletions, and substitutions.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitute.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
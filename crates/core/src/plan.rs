@@ -0,0 +1,121 @@
+// Month-by-month cash flows for one customer and for a program of monthly cohorts.
+//
+// Timing convention: a customer is acquired at the start of month 0, CAC is paid and CFA is
+// collected immediately, and gross profit arrives at the early GP rate (converted to months)
+// from month 0 onward until the customer's lifetime gross profit (LTGP) has been earned.
+
+use crate::evaluation::{self, Inputs};
+
+#[derive(Debug, Clone, Copy)]
+pub struct UnitCashFlow {
+    pub cac: f64,
+    pub cfa: f64,
+    pub monthly_gp: f64,
+    pub ltgp: f64,
+}
+
+impl UnitCashFlow {
+    pub fn from_inputs(i: &Inputs) -> UnitCashFlow {
+        UnitCashFlow { cac: i.cac, cfa: i.cfa, monthly_gp: evaluation::monthly_gp(i), ltgp: i.ltgp }
+    }
+
+    /// Gross profit one customer produces in month `m` after acquisition.
+    pub fn gp_in_month(&self, m: usize) -> f64 {
+        let earned_before = self.monthly_gp * m as f64;
+        self.monthly_gp.min((self.ltgp - earned_before).max(0.0))
+    }
+
+    /// Net cash one customer produces in month `m` (month 0 includes −CAC and +CFA).
+    pub fn cash_in_month(&self, m: usize) -> f64 {
+        let upfront = if m == 0 { self.cfa - self.cac } else { 0.0 };
+        upfront + self.gp_in_month(m)
+    }
+
+    /// First month at whose end the customer's cumulative cash is non-negative.
+    pub fn breakeven_month(&self, horizon: usize) -> Option<usize> {
+        let mut cumulative = 0.0;
+        (0..horizon).find(|m| {
+            cumulative += self.cash_in_month(*m);
+            cumulative >= 0.0
+        })
+    }
+}
+
+/// Net program cash per month when `cohorts[t]` customers are acquired in month t.
+pub fn program_cash(unit: &UnitCashFlow, cohorts: &[f64]) -> Vec<f64> {
+    (0..cohorts.len()).map(|t| (0..=t).map(|start| cohorts[start] * unit.cash_in_month(t - start)).sum()).collect()
+}
+
+pub fn cumulative(flows: &[f64]) -> Vec<f64> {
+    flows.iter().scan(0.0, |acc, f| { *acc += f; Some(*acc) }).collect()
+}
+
+/// Lowest cumulative cash within each month: the previous month's closing position after paying
+/// for this month's cohort, before any of this month's gross profit arrives.
+pub fn program_low_points(unit: &UnitCashFlow, cohorts: &[f64]) -> Vec<f64> {
+    let closing = cumulative(&program_cash(unit, cohorts));
+    (0..cohorts.len()).map(|t| {
+        let opening = if t == 0 { 0.0 } else { closing[t - 1] };
+        opening + cohorts[t] * (unit.cfa - unit.cac)
+    }).collect()
+}
+
+/// Lowest point of a running cash series and the month it occurs; (0, 0.0) if it never goes negative.
+pub fn trough(running: &[f64]) -> (usize, f64) {
+    running.iter().copied().enumerate().fold((0, 0.0), |acc, (m, c)| if c < acc.1 { (m, c) } else { acc })
+}
+
+/// Fastest constant monthly acquisition rate whose cumulative cash never dips below −`starting_cash`
+/// within `horizon` months, with the month the cash trough occurs. None means cash never limits growth.
+pub fn max_sustainable_rate(unit: &UnitCashFlow, starting_cash: f64, horizon: usize) -> Option<(f64, usize)> {
+    // Program cash scales linearly with the acquisition rate, so one customer/month gives the shape.
+    let (month, low) = trough(&program_low_points(unit, &vec![1.0; horizon]));
+    if low >= 0.0 { return None; }
+    Some((starting_cash / -low, month))
+}
+
+/// Lowest cash balance in each month: starting cash, minus fixed burn, plus acquisition cash flows.
+pub fn cash_balance(unit: &UnitCashFlow, starting_cash: f64, fixed_burn: f64, rate: f64, horizon: usize) -> Vec<f64> {
+    program_low_points(unit, &vec![rate; horizon]).iter().enumerate().map(|(m, c)| starting_cash - fixed_burn * (m + 1) as f64 + c).collect()
+}
+
+/// Range of steady acquisition rates that keep the balance at or above `floor` for the whole horizon.
+/// Rates below the low end can't cover the burn; above the high end, working capital sinks the balance.
+pub fn feasible_rates(unit: &UnitCashFlow, starting_cash: f64, fixed_burn: f64, floor: f64, horizon: usize) -> Option<(f64, f64)> {
+    let per_customer = program_low_points(unit, &vec![1.0; horizon]);
+    let (mut lo, mut hi) = (0.0_f64, f64::INFINITY);
+    for (m, c) in per_customer.iter().enumerate() {
+        let headroom = starting_cash - fixed_burn * (m + 1) as f64 - floor;
+        if *c < 0.0 {
+            hi = hi.min(headroom / -c);
+        } else if *c > 0.0 {
+            lo = lo.max(-headroom / c);
+        } else if headroom < 0.0 {
+            return None;
+        }
+    }
+    (hi >= lo && hi >= 0.0).then_some((lo, hi))
+}
+
+/// Peak cash gap for one customer, one monthly cohort, and a program acquiring a cohort every month.
+#[derive(Debug, Clone, Copy)]
+pub struct WorkingCapital {
+    /// Cash tied up in one customer at acquisition (CAC − CFA, never negative).
+    pub per_customer: f64,
+    /// Months until one customer's gross profit closes the gap; None if it doesn't within the horizon.
+    pub months_to_close: Option<usize>,
+    /// Peak gap for a program acquiring one customer every month.
+    pub program_per_monthly_customer: f64,
+    /// Month (0-based) in which the program's gap peaks.
+    pub program_peak_month: usize,
+}
+
+pub fn working_capital(unit: &UnitCashFlow, horizon: usize) -> WorkingCapital {
+    let (program_peak_month, low) = trough(&program_low_points(unit, &vec![1.0; horizon]));
+    WorkingCapital {
+        per_customer: (unit.cac - unit.cfa).max(0.0),
+        months_to_close: unit.breakeven_month(horizon),
+        program_per_monthly_customer: -low,
+        program_peak_month,
+    }
+}
@@ -0,0 +1,87 @@
+// Display precision and rounding for every figure the reports print. Without a setting each
+// figure keeps its usual places (two for money and ratios, one for days and months); with
+// `--precision N` every figure uses N. Either way ties round by one policy, so a number that lands
+// in a financial document reads the same in the text report, JSON, spreadsheet rows, and webhooks:
+//
+//   half-up   2.345 → 2.35, 2.355 → 2.36   (away from zero; the default)
+//   bankers   2.345 → 2.34, 2.355 → 2.36   (to the even digit, so ties don't drift upward in sums)
+//
+// Rounding works on the decimal the number was typed as, not its binary approximation, so 2.675
+// rounds like 2.675 rather than 2.67499999….
+
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[serde(rename_all = "kebab-case")]
+pub enum Rounding {
+    /// Ties round away from zero
+    #[default]
+    HalfUp,
+    /// Ties round to the even digit
+    Bankers,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Settings {
+    places: Option<usize>,
+    rounding: Rounding,
+}
+
+static SETTINGS: OnceLock<Settings> = OnceLock::new();
+
+/// The most places `--precision` accepts; past this f64 has no digits left to show.
+pub const MAX_PLACES: usize = 10;
+
+/// Sets the precision (None keeps each figure's usual places) and rounding policy for the run.
+pub fn set(places: Option<usize>, rounding: Rounding) {
+    let _ = SETTINGS.set(Settings { places: places.map(|p| p.min(MAX_PLACES)), rounding });
+}
+
+fn settings() -> Settings {
+    SETTINGS.get().copied().unwrap_or_default()
+}
+
+/// The places set with `--precision`, if any.
+pub fn explicit() -> Option<usize> {
+    settings().places
+}
+
+/// The run's tie rounding.
+pub fn rounding() -> Rounding {
+    settings().rounding
+}
+
+/// The places a figure that usually shows `default` places prints with.
+pub fn places(default: usize) -> usize {
+    explicit().unwrap_or(default)
+}
+
+/// `v` rounded to `places` decimals by the run's rounding policy; non-finite values pass through.
+pub fn round(v: f64, places: usize) -> f64 {
+    if !v.is_finite() {
+        return v;
+    }
+    let scale = 10f64.powi(places as i32);
+    // Snap away the binary representation error first, so 2.675 (stored as 2.67499…) is a tie.
+    let scaled = (v * scale * 1e6).round() / 1e6;
+    let rounded = match settings().rounding {
+        Rounding::HalfUp => scaled.round(),
+        Rounding::Bankers => scaled.round_ties_even(),
+    };
+    rounded / scale
+}
+
+/// `v` as text at the run's precision, falling back to `default` places.
+pub fn fixed(v: f64, default: usize) -> String {
+    let places = places(default);
+    format!("{:.*}", places, round(v, places))
+}
+
+/// `fixed`, with a `+` on figures that aren't negative, for changes.
+pub fn signed(v: f64, default: usize) -> String {
+    let text = fixed(v, default);
+    if text.starts_with('-') { text } else { format!("+{}", text) }
+}
@@ -0,0 +1,268 @@
+// Python bindings: the `ltgp_cac` module, so notebooks and pandas pipelines evaluate, simulate,
+// and solve with the same logic as the CLI. Build and install it into the active environment
+// with maturin (see pyproject.toml):
+//
+//   maturin develop --release
+//
+//   import ltgp_cac
+//   inputs = ltgp_cac.Inputs(cac=500, ltgp=2500, cfa=200, early_gp_rate=50)
+//   result = ltgp_cac.evaluate(inputs)          # .ratio, .quadrant, .verdict, .payback_days, …
+//   ltgp_cac.simulate(inputs, cac_sd=80, ltgp_sd=400).ratio.p50
+//   [lever.action for lever in ltgp_cac.solve(inputs)]
+//
+// `Evaluation.to_dict()` flattens inputs and results into one row, e.g. for
+// `pd.DataFrame([ltgp_cac.evaluate(ltgp_cac.Inputs(**row)).to_dict() for row in rows])`.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::evaluation::{self, DEFAULT_HIGH_CFA_FRACTION};
+use crate::recommend;
+use crate::report::EvaluationResult;
+use crate::scenario::Scenario;
+use crate::simulation::{self, Uncertainty, MAX_RUNS};
+
+/// The inputs to one evaluation, with the scenario file's defaults.
+#[pyclass(name = "Inputs", get_all, set_all, from_py_object)]
+#[derive(Clone)]
+struct PyInputs {
+    name: Option<String>,
+    cac: f64,
+    cfa: f64,
+    ltgp: f64,
+    early_gp_rate: f64,
+    period: String,
+    low_cac_fraction: f64,
+    high_cfa_fraction: f64,
+    low_cac_max: Option<f64>,
+    high_cfa_min: Option<f64>,
+}
+
+#[pymethods]
+impl PyInputs {
+    #[new]
+    #[pyo3(signature = (cac, ltgp, cfa = 0.0, early_gp_rate = 0.0, period = "days".to_string(), low_cac_fraction = 0.10, high_cfa_fraction = DEFAULT_HIGH_CFA_FRACTION, low_cac_max = None, high_cfa_min = None, name = None))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(cac: f64, ltgp: f64, cfa: f64, early_gp_rate: f64, period: String, low_cac_fraction: f64, high_cfa_fraction: f64, low_cac_max: Option<f64>, high_cfa_min: Option<f64>, name: Option<String>) -> PyInputs {
+        PyInputs { name, cac, cfa, ltgp, early_gp_rate, period, low_cac_fraction, high_cfa_fraction, low_cac_max, high_cfa_min }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Inputs(cac={}, ltgp={}, cfa={}, early_gp_rate={}, period={:?})", self.cac, self.ltgp, self.cfa, self.early_gp_rate, self.period)
+    }
+}
+
+impl PyInputs {
+    fn scenario(&self) -> PyResult<Scenario> {
+        Ok(Scenario {
+            name: self.name.clone(),
+            cac: self.cac,
+            cfa: self.cfa,
+            ltgp: self.ltgp,
+            early_gp_rate: self.early_gp_rate,
+            period: self.period.parse().map_err(PyValueError::new_err)?,
+            low_cac_fraction: self.low_cac_fraction,
+            high_cfa_fraction: self.high_cfa_fraction,
+            low_cac_max: self.low_cac_max,
+            high_cfa_min: self.high_cfa_min,
+        })
+    }
+}
+
+/// One evaluation: the fields `--format json` prints.
+#[pyclass(name = "Evaluation", get_all, frozen, skip_from_py_object)]
+#[derive(Clone)]
+struct PyEvaluation {
+    inputs: PyInputs,
+    net_outlay: f64,
+    /// LTGP:CAC; infinite for a free customer.
+    ratio: f64,
+    low_cac: bool,
+    high_cfa: bool,
+    /// low or high.
+    cac_class: String,
+    cfa_class: String,
+    low_cac_threshold: f64,
+    high_cfa_threshold: f64,
+    quadrant: String,
+    quadrant_description: String,
+    verdict: String,
+    /// healthy, watch, at-risk, or critical.
+    severity: String,
+    payback: Option<f64>,
+    payback_days: Option<f64>,
+    monthly_gp: f64,
+    cac_payback_months: Option<f64>,
+    degenerate: Option<String>,
+    degenerate_explanation: Option<String>,
+    warnings: Vec<String>,
+    evaluated_at: Option<String>,
+    timezone: Option<String>,
+}
+
+#[pymethods]
+impl PyEvaluation {
+    /// Inputs and results as one flat dict, for a DataFrame row.
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let d = PyDict::new(py);
+        let i = &self.inputs;
+        d.set_item("name", &i.name)?;
+        d.set_item("cac", i.cac)?;
+        d.set_item("cfa", i.cfa)?;
+        d.set_item("ltgp", i.ltgp)?;
+        d.set_item("early_gp_rate", i.early_gp_rate)?;
+        d.set_item("period", &i.period)?;
+        d.set_item("net_outlay", self.net_outlay)?;
+        d.set_item("ratio", self.ratio)?;
+        d.set_item("low_cac", self.low_cac)?;
+        d.set_item("high_cfa", self.high_cfa)?;
+        d.set_item("cac_class", &self.cac_class)?;
+        d.set_item("cfa_class", &self.cfa_class)?;
+        d.set_item("low_cac_threshold", self.low_cac_threshold)?;
+        d.set_item("high_cfa_threshold", self.high_cfa_threshold)?;
+        d.set_item("quadrant", &self.quadrant)?;
+        d.set_item("quadrant_description", &self.quadrant_description)?;
+        d.set_item("verdict", &self.verdict)?;
+        d.set_item("severity", &self.severity)?;
+        d.set_item("payback", self.payback)?;
+        d.set_item("payback_days", self.payback_days)?;
+        d.set_item("monthly_gp", self.monthly_gp)?;
+        d.set_item("cac_payback_months", self.cac_payback_months)?;
+        d.set_item("degenerate", &self.degenerate)?;
+        d.set_item("degenerate_explanation", &self.degenerate_explanation)?;
+        d.set_item("warnings", &self.warnings)?;
+        d.set_item("evaluated_at", &self.evaluated_at)?;
+        d.set_item("timezone", &self.timezone)?;
+        Ok(d)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Evaluation(ratio={}, quadrant={:?}, verdict={:?})", evaluation::ratio_text(self.ratio), self.quadrant, evaluation::verdict_label(&self.verdict))
+    }
+}
+
+/// The scenario's inputs, or a ValueError naming each one out of range.
+fn checked(inputs: &PyInputs) -> PyResult<evaluation::Inputs> {
+    inputs.scenario()?.inputs().map_err(PyValueError::new_err)
+}
+
+/// The evaluation as the typed result every other output renders from, so Python sees the same
+/// fields as `--format json`.
+fn evaluated(inputs: &PyInputs) -> PyResult<PyEvaluation> {
+    let r = EvaluationResult::from(&evaluation::evaluate(&checked(inputs)?));
+    let ratio = r.ratio_value();
+    Ok(PyEvaluation {
+        inputs: inputs.clone(),
+        net_outlay: r.net_outlay,
+        ratio,
+        low_cac: r.low_cac,
+        high_cfa: r.high_cfa,
+        cac_class: r.cac_class.name().to_lowercase(),
+        cfa_class: r.cfa_class.name().to_lowercase(),
+        low_cac_threshold: r.low_cac_threshold,
+        high_cfa_threshold: r.high_cfa_threshold,
+        quadrant: r.quadrant,
+        quadrant_description: r.quadrant_description,
+        verdict: r.verdict,
+        severity: r.severity.level.name().to_string(),
+        payback: r.payback,
+        payback_days: r.payback_days,
+        monthly_gp: r.monthly_gp,
+        cac_payback_months: r.cac_payback_months,
+        degenerate: r.degenerate.as_ref().map(|d| d.kind.name().to_string()),
+        degenerate_explanation: r.degenerate.map(|d| d.explanation),
+        warnings: r.warnings,
+        evaluated_at: r.evaluated_at,
+        timezone: r.timezone,
+    })
+}
+
+/// The 5th, 50th, and 95th percentiles.
+#[pyclass(name = "Interval", get_all, frozen, skip_from_py_object)]
+#[derive(Clone)]
+struct PyInterval {
+    p5: f64,
+    p50: f64,
+    p95: f64,
+}
+
+impl From<simulation::Interval> for PyInterval {
+    fn from(i: simulation::Interval) -> PyInterval {
+        PyInterval { p5: i.p5, p50: i.p50, p95: i.p95 }
+    }
+}
+
+/// What a Monte Carlo simulation says about an evaluation.
+#[pyclass(name = "Simulation", get_all, frozen, skip_from_py_object)]
+struct PySimulation {
+    /// The evaluation at the means.
+    base: PyEvaluation,
+    ratio: PyInterval,
+    chance_ratio_at_most_3: f64,
+    /// None without early gross profit.
+    payback_days: Option<PyInterval>,
+    /// Share of draws with the base verdict.
+    verdict_holds: f64,
+}
+
+/// A change that improves the economics, and its re-evaluated effect.
+#[pyclass(name = "Lever", get_all, frozen, skip_from_py_object)]
+struct PyLever {
+    action: String,
+    effect: String,
+}
+
+#[pymethods]
+impl PyLever {
+    fn __repr__(&self) -> String {
+        format!("Lever({:?})", self.action)
+    }
+}
+
+/// Evaluates one set of inputs.
+#[pyfunction]
+fn evaluate(inputs: PyInputs) -> PyResult<PyEvaluation> {
+    evaluated(&inputs)
+}
+
+/// Propagates standard deviations on CAC, CFA, and LTGP through the evaluation.
+#[pyfunction]
+#[pyo3(signature = (inputs, cac_sd = 0.0, cfa_sd = 0.0, ltgp_sd = 0.0, runs = 10_000, seed = 42))]
+fn simulate(inputs: PyInputs, cac_sd: f64, cfa_sd: f64, ltgp_sd: f64, runs: usize, seed: u64) -> PyResult<PySimulation> {
+    if runs == 0 || runs > MAX_RUNS as usize {
+        return Err(PyValueError::new_err(format!("runs must be from 1 to {}", MAX_RUNS)));
+    }
+    let sd = |name: &str, sd: f64| simulation::check_sd(name, sd).map_err(PyValueError::new_err);
+    let unc = Uncertainty { cac_sd: sd("cac_sd", cac_sd)?, cfa_sd: sd("cfa_sd", cfa_sd)?, ltgp_sd: sd("ltgp_sd", ltgp_sd)? };
+    let base = evaluated(&inputs)?;
+    let samples = simulation::simulate(&checked(&inputs)?, &unc, runs, seed);
+    let summary = simulation::summarize(&samples, &base.verdict);
+    Ok(PySimulation {
+        ratio: summary.ratio.into(),
+        chance_ratio_at_most_3: summary.chance_ratio_at_most_3,
+        payback_days: summary.payback_days.map(PyInterval::from),
+        verdict_holds: summary.verdict_holds,
+        base,
+    })
+}
+
+/// The changes to CAC, LTGP, or CFA that fix the economics, most important first.
+#[pyfunction]
+fn solve(inputs: PyInputs) -> PyResult<Vec<PyLever>> {
+    let e = evaluation::evaluate(&checked(&inputs)?);
+    Ok(recommend::levers(&e).into_iter().map(|l| PyLever { action: l.action, effect: l.effect }).collect())
+}
+
+#[pymodule]
+fn ltgp_cac(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyInputs>()?;
+    m.add_class::<PyEvaluation>()?;
+    m.add_class::<PyInterval>()?;
+    m.add_class::<PySimulation>()?;
+    m.add_class::<PyLever>()?;
+    m.add_function(wrap_pyfunction!(evaluate, m)?)?;
+    m.add_function(wrap_pyfunction!(simulate, m)?)?;
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    Ok(())
+}
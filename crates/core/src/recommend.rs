@@ -0,0 +1,94 @@
+// Recommendations: concrete levers computed from the user's own numbers, each re-evaluated so the
+// effect shown is what the calculator would actually report after the change.
+
+use serde::Serialize;
+
+use crate::evaluation::{self, money, ratio_text, verdict_label, Evaluation, Inputs};
+use crate::precision;
+
+/// How much a lever matters; lower sorts first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Priority {
+    /// Lifetime economics don't clear 3:1.
+    Viability,
+    /// Cash tied up per customer.
+    Cash,
+    /// Speed of recovering that cash.
+    Speed,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Lever {
+    #[serde(skip)]
+    priority: Priority,
+    /// Size of the change relative to today's value (0.2 = 20%), for ordering within a priority.
+    #[serde(skip)]
+    size: f64,
+    pub action: String,
+    pub effect: String,
+}
+
+fn payback_text(e: &Evaluation) -> String {
+    e.payback_days().map(|d| format!("{} days", precision::fixed(d, 1))).unwrap_or_else(|| "unknown".to_string())
+}
+
+/// What changes between `before` and `after`, limited to what moved.
+fn effect(before: &Evaluation, after: &Evaluation) -> String {
+    let mut parts = Vec::new();
+    if after.net_outlay != before.net_outlay {
+        parts.push(format!("net outlay {} → {}", money(before.net_outlay), money(after.net_outlay)));
+    }
+    if after.ratio != before.ratio {
+        parts.push(format!("LTGP:CAC {} → {}", ratio_text(before.ratio), ratio_text(after.ratio)));
+    }
+    if after.payback_days() != before.payback_days() {
+        parts.push(format!("payback {} → {}", payback_text(before), payback_text(after)));
+    }
+    if after.quadrant != before.quadrant {
+        parts.push(format!("quadrant becomes {}", after.quadrant.name()));
+    }
+    if after.verdict != before.verdict {
+        parts.push(format!("verdict becomes {}", verdict_label(after.verdict)));
+    }
+    parts.join(", ")
+}
+
+fn lever(base: &Evaluation, priority: Priority, size: f64, action: String, changed: Inputs) -> Lever {
+    Lever { priority, size, action, effect: effect(base, &evaluation::evaluate(&changed)) }
+}
+
+/// Levers worth pulling, most important first.
+pub fn levers(base: &Evaluation) -> Vec<Lever> {
+    let i = &base.inputs;
+    let mut out = Vec::new();
+
+    if base.ratio <= 3.0 && i.cac > 0.0 {
+        // Just past 3:1, since the verdict needs the ratio strictly above it.
+        let target_cac = i.ltgp / 3.0 * 0.99;
+        out.push(lever(base, Priority::Viability, 1.0 - target_cac / i.cac, format!("Cut CAC by {}% to {}", precision::fixed((1.0 - target_cac / i.cac) * 100.0, 0), money(target_cac)), Inputs { cac: target_cac, ..i.clone() }));
+        let target_ltgp = i.cac * 3.0 * 1.01;
+        let size = if i.ltgp > 0.0 { target_ltgp / i.ltgp - 1.0 } else { f64::INFINITY };
+        out.push(lever(base, Priority::Viability, size, format!("Raise lifetime gross profit to {} (retention, pricing, or upsells)", money(target_ltgp)), Inputs { ltgp: target_ltgp, ..i.clone() }));
+    }
+
+    if base.net_outlay > 0.0 {
+        let fee = i.cac - i.cfa;
+        out.push(lever(base, Priority::Cash, fee / i.cac, format!("Add a {} setup fee or deposit (CFA → {})", money(fee), money(i.cac)), Inputs { cfa: i.cac, ..i.clone() }));
+        if !base.high_cfa && base.high_cfa_thresh < i.cac {
+            let extra = base.high_cfa_thresh - i.cfa;
+            out.push(lever(base, Priority::Cash, extra / i.cac, format!("Collect {} more upfront (CFA → {})", money(extra), money(base.high_cfa_thresh)), Inputs { cfa: base.high_cfa_thresh, ..i.clone() }));
+        }
+        if !base.low_cac && base.low_cac_thresh > 0.0 {
+            let cut = 1.0 - base.low_cac_thresh / i.cac;
+            out.push(lever(base, Priority::Cash, cut, format!("Cut CAC by {}% to {}", precision::fixed(cut * 100.0, 0), money(base.low_cac_thresh)), Inputs { cac: base.low_cac_thresh, ..i.clone() }));
+        }
+    }
+
+    if base.net_outlay > 0.0 && i.early_gp_rate > 0.0 {
+        out.push(lever(base, Priority::Speed, 0.2, "Increase early gross profit by 20% (faster onboarding, earlier upsell)".to_string(), Inputs { early_gp_rate: i.early_gp_rate * 1.2, ..i.clone() }));
+    }
+
+    out.retain(|l| !l.effect.is_empty());
+    out.sort_by(|a, b| a.priority.cmp(&b.priority).then(a.size.total_cmp(&b.size)));
+    out
+}
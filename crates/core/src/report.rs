@@ -0,0 +1,269 @@
+// The evaluation as one typed, serializable result. Every output renders from it — the text
+// report, `--format json`, the webhook, spreadsheet rows, and the servers — so they can't drift
+// apart.
+
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::evaluation::{money, monthly_gp, ratio_text, Class, Degenerate, Evaluation, Inputs};
+use crate::precision;
+use crate::severity::Severity;
+
+/// The inputs and computed metrics: what `--format json` prints, the webhook sends, and the API
+/// returns.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct EvaluationResult {
+    pub inputs: Inputs,
+    /// CAC minus CFA: the cash laid out per customer before gross profit comes in.
+    pub net_outlay: f64,
+    /// LTGP:CAC; JSON has no infinity, so a free customer (CAC of 0) comes out as null.
+    pub ratio: Option<f64>,
+    pub low_cac: bool,
+    pub high_cfa: bool,
+    pub cac_class: Class,
+    pub cfa_class: Class,
+    pub low_cac_threshold: f64,
+    pub high_cfa_threshold: f64,
+    pub quadrant: String,
+    pub quadrant_description: String,
+    pub verdict: String,
+    pub severity: ResultSeverity,
+    /// Payback in `inputs.period` units; null without early gross profit.
+    pub payback: Option<f64>,
+    pub payback_days: Option<f64>,
+    /// Early gross profit per average calendar month (365.2425 / 12 ≈ 30.44 days).
+    pub monthly_gp: f64,
+    /// Full CAC ÷ monthly gross profit, ignoring CFA.
+    pub cac_payback_months: Option<f64>,
+    /// Set when the inputs are at an edge (CAC or LTGP of $0, CFA above CAC) that the ratio alone
+    /// doesn't explain.
+    pub degenerate: Option<DegenerateCase>,
+    /// Caveats about the inputs.
+    pub warnings: Vec<String>,
+    /// When the evaluation ran, as RFC 3339 in `timezone`; null when the front end keeps no clock.
+    pub evaluated_at: Option<String>,
+    /// The timezone `evaluated_at` is written in, e.g. `Europe/Berlin` or `UTC`.
+    pub timezone: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DegenerateCase {
+    pub kind: Degenerate,
+    /// What the case means for these inputs, and what to check.
+    pub explanation: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ResultSeverity {
+    /// healthy, watch, at-risk, or critical.
+    pub level: Severity,
+    /// The level's color in the active theme, as hex.
+    pub color: String,
+}
+
+static CLOCK: OnceLock<fn() -> (String, String)> = OnceLock::new();
+
+/// Sets what stamps each result for the rest of the run: the time as RFC 3339 and the timezone it
+/// is written in. The library has no clock of its own, since it also runs in WebAssembly.
+pub fn set_clock(clock: fn() -> (String, String)) {
+    let _ = CLOCK.set(clock);
+}
+
+impl EvaluationResult {
+    /// `verdict` is the rules' verdict or a script's replacement.
+    pub fn new(e: &Evaluation, verdict: &str) -> EvaluationResult {
+        let severity = Severity::of_verdict(verdict);
+        let (evaluated_at, timezone) = CLOCK.get().map(|clock| clock()).unzip();
+        EvaluationResult {
+            inputs: e.inputs.clone(),
+            net_outlay: e.net_outlay,
+            ratio: e.ratio.is_finite().then_some(e.ratio),
+            low_cac: e.low_cac,
+            high_cfa: e.high_cfa,
+            cac_class: e.cac_class(),
+            cfa_class: e.cfa_class(),
+            low_cac_threshold: e.low_cac_thresh,
+            high_cfa_threshold: e.high_cfa_thresh,
+            quadrant: e.quadrant.name().to_string(),
+            quadrant_description: e.quadrant.description().to_string(),
+            verdict: verdict.to_string(),
+            severity: ResultSeverity { level: severity, color: severity.hex().to_string() },
+            payback: e.payback,
+            payback_days: e.payback_days(),
+            monthly_gp: monthly_gp(&e.inputs),
+            cac_payback_months: e.cac_payback_months,
+            degenerate: e.degenerate().map(|kind| DegenerateCase { kind, explanation: kind.explanation(&e.inputs) }),
+            warnings: e.warnings(),
+            evaluated_at,
+            timezone,
+        }
+        .rounded()
+    }
+
+    /// With `--precision`, the computed figures rounded the way the text report shows them, so
+    /// JSON, spreadsheet rows, and webhooks carry the same numbers. The inputs stay as given.
+    fn rounded(mut self) -> EvaluationResult {
+        if let Some(places) = precision::explicit() {
+            let round = |v: f64| precision::round(v, places);
+            for v in [&mut self.net_outlay, &mut self.low_cac_threshold, &mut self.high_cfa_threshold, &mut self.monthly_gp] {
+                *v = round(*v);
+            }
+            for v in [&mut self.ratio, &mut self.payback, &mut self.payback_days, &mut self.cac_payback_months] {
+                *v = v.map(round);
+            }
+        }
+        self
+    }
+
+    /// LTGP:CAC with a free customer as infinity, for display and comparisons.
+    pub fn ratio_value(&self) -> f64 {
+        self.ratio.unwrap_or(f64::INFINITY)
+    }
+}
+
+/// The result with the active rules' verdict.
+impl From<&Evaluation> for EvaluationResult {
+    fn from(e: &Evaluation) -> EvaluationResult {
+        EvaluationResult::new(e, e.verdict)
+    }
+}
+
+/// `EvaluationResult` as a JSON value, for embedding in larger payloads.
+pub fn json_value(r: &EvaluationResult) -> serde_json::Value {
+    serde_json::to_value(r).expect("evaluation serializes to JSON")
+}
+
+fn low_cac_basis(i: &Inputs) -> String {
+    match i.low_cac_max {
+        Some(_) => "fixed dollar cut".to_string(),
+        None => format!("{} of LTGP", i.amounts().low_cac_fraction),
+    }
+}
+
+fn high_cfa_basis(i: &Inputs) -> String {
+    match i.high_cfa_min {
+        Some(_) => "fixed dollar cut".to_string(),
+        None => format!("{} of CAC", i.amounts().high_cfa_fraction),
+    }
+}
+
+/// The gauge runs from 0 to this ratio, with the 3:1 target at its middle.
+const GAUGE_MAX: f64 = 6.0;
+
+/// How a front end marks severities in the text report. The defaults are plain text with icons, as
+/// the bindings return it; the command line adds terminal colors and `--no-icons`.
+pub trait Style {
+    /// The icon for `severity` followed by a space, or nothing.
+    fn icon(&self, severity: Severity) -> &'static str {
+        severity.icon()
+    }
+
+    /// `text` marked with `severity`.
+    fn paint(&self, _severity: Severity, text: &str) -> String {
+        text.to_string()
+    }
+}
+
+/// Plain text with icons.
+pub struct Plain;
+
+impl Style for Plain {}
+
+/// LTGP:CAC as a bar from 0 to 6:1 with the 3:1 target marked (`│`), filled in the ratio's severity
+/// color; a ratio past the end shows as `▶`.
+pub fn ratio_gauge(ratio: f64, width: usize, style: &dyn Style) -> String {
+    let fill = ((ratio.clamp(0.0, GAUGE_MAX) / GAUGE_MAX) * width as f64).round() as usize;
+    let bar: String = (0..width).map(|n| if n == width / 2 { '│' } else if n < fill { '█' } else { '░' }).collect();
+    let over = if ratio > GAUGE_MAX { "▶" } else { " " };
+    let value = if ratio.is_finite() { precision::fixed(ratio, 2) } else { "∞".to_string() };
+    format!("LTGP:CAC 0 [{}] 6{} {}:1 vs 3:1 target", style.paint(Severity::of_ratio(ratio), &bar), over, value)
+}
+
+/// The severity in upper case, in its color on a terminal.
+fn severity_label(severity: Severity, style: &dyn Style) -> String {
+    style.paint(severity, &severity.name().to_uppercase())
+}
+
+/// The core report as text, with severities marked by `style`.
+pub fn render_report(r: &EvaluationResult, style: &dyn Style) -> String {
+    let i = &r.inputs;
+    let ratio = r.ratio_value();
+    let mut lines = vec![
+        String::new(),
+        "=== Growth Model Evaluation ===".to_string(),
+        String::new(),
+        ratio_gauge(ratio, 30, style),
+        String::new(),
+        format!("You spend about {} to acquire a customer.", money(i.cac)),
+        format!("The customer gives you about {} upfront.", money(i.cfa)),
+        format!("Over their lifetime, you expect to make {} in gross profit.", money(i.ltgp)),
+        String::new(),
+        "That means:".to_string(),
+        format!(" - Net cash you actually lay out upfront: {}.", money(r.net_outlay)),
+        format!(" - {}Lifetime return ratio (LTGP divided by CAC): {}.", style.icon(Severity::of_ratio(ratio)), ratio_text(ratio)),
+        format!(" - CAC classification: {}", r.cac_class.cac_label()),
+        format!(" - CFA classification: {}", r.cfa_class.cfa_label()),
+        format!(" - Quadrant: {}", r.quadrant_description),
+        format!(" - Quadrant boundaries: low CAC at or below {} ({}); high CFA at or above {} ({}).", money(r.low_cac_threshold), low_cac_basis(i), money(r.high_cfa_threshold), high_cfa_basis(i)),
+        String::new(),
+    ];
+    if let Some(d) = &r.degenerate {
+        lines.push(format!("{}Special case ({}): {}", style.icon(Severity::Watch), d.kind.headline(), d.explanation));
+        lines.push(String::new());
+    }
+    lines.extend([
+        format!("{}Verdict: {}", style.icon(r.severity.level), r.verdict),
+        format!("Severity: {}", severity_label(r.severity.level, style)),
+        String::new(),
+    ]);
+
+    lines.push(match (r.payback, r.payback_days) {
+        (Some(value), Some(days)) if i.period.is_approximate() => format!("Estimated payback period: {} {} (≈ {} days at an average {} days per {}).", precision::fixed(value, 2), i.period, precision::fixed(days, 1), precision::fixed(i.period.days(), 2), i.period.unit()),
+        (Some(value), Some(days)) => format!("Estimated payback period: {} {} (≈ {} days).", precision::fixed(value, 2), i.period, precision::fixed(days, 1)),
+        _ => "Payback period could not be estimated. Provide --early-gp-rate to calculate it.".to_string(),
+    });
+    if let Some(months) = r.cac_payback_months {
+        lines.push(format!("CAC payback (industry standard, before CFA): {} months at {} gross profit per month.", precision::fixed(months, 1), money(r.monthly_gp)));
+    }
+    lines.extend(r.warnings.iter().map(|w| format!("{}Warning: {}", style.icon(Severity::Watch), w)));
+    lines.join("\n")
+}
+
+/// The core report for narrow terminals: the same facts as `render_report`, as short labeled lines.
+pub fn render_compact_report(r: &EvaluationResult, style: &dyn Style) -> String {
+    let i = &r.inputs;
+    let ratio = r.ratio_value();
+    let mut lines = vec![
+        String::new(),
+        "=== Growth Model Evaluation ===".to_string(),
+        String::new(),
+        ratio_gauge(ratio, 20, style),
+        format!("CAC {} · CFA {} · LTGP {}", money(i.cac), money(i.cfa), money(i.ltgp)),
+        format!("{}Net outlay {} · LTGP:CAC {}", style.icon(Severity::of_ratio(ratio)), money(r.net_outlay), ratio_text(ratio)),
+        format!("{} CAC · {} CFA", r.cac_class.name(), r.cfa_class.name()),
+        format!("Quadrant: {}", r.quadrant_description),
+        String::new(),
+    ];
+    if let Some(d) = &r.degenerate {
+        lines.push(format!("{}Special case: {}", style.icon(Severity::Watch), d.kind.headline()));
+        lines.push(String::new());
+    }
+    lines.extend([
+        format!("{}Verdict: {}", style.icon(r.severity.level), r.verdict),
+        format!("Severity: {}", severity_label(r.severity.level, style)),
+        String::new(),
+    ]);
+    lines.push(match r.payback {
+        Some(value) => format!("Payback: {} {}", precision::fixed(value, 2), i.period),
+        None => "Payback: unknown (needs --early-gp-rate)".to_string(),
+    });
+    if let Some(months) = r.cac_payback_months {
+        lines.push(format!("CAC payback: {} months", precision::fixed(months, 1)));
+    }
+    lines.extend(r.warnings.iter().map(|w| format!("{}Warning: {}", style.icon(Severity::Watch), w)));
+    lines.join("\n")
+}
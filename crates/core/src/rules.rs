@@ -0,0 +1,274 @@
+// Verdict rules: an ordered list of conditions over computed metrics, each mapping to a label
+// and guidance text. The bundled verdict_rules.yaml reproduces the built-in verdicts; `--rules`
+// swaps in a company's own decision policy for every evaluation in the run.
+
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use crate::evaluation::Evaluation;
+use crate::severity::Severity;
+
+const BUNDLED: &str = include_str!("verdict_rules.yaml");
+
+static ACTIVE: OnceLock<Ruleset> = OnceLock::new();
+
+/// Colors a rule may give its verdict zone in heatmaps.
+pub const COLORS: [&str; 6] = ["green", "cyan", "blue", "yellow", "magenta", "red"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Metric {
+    Cac,
+    Cfa,
+    Ltgp,
+    Ratio,
+    NetOutlay,
+    CfaCoverage,
+    PaybackDays,
+    CacPaybackMonths,
+    LowCac,
+    HighCfa,
+}
+
+impl Metric {
+    const ALL: [(&'static str, Metric); 10] = [
+        ("cac", Metric::Cac),
+        ("cfa", Metric::Cfa),
+        ("ltgp", Metric::Ltgp),
+        ("ratio", Metric::Ratio),
+        ("net_outlay", Metric::NetOutlay),
+        ("cfa_coverage", Metric::CfaCoverage),
+        ("payback_days", Metric::PaybackDays),
+        ("cac_payback_months", Metric::CacPaybackMonths),
+        ("low_cac", Metric::LowCac),
+        ("high_cfa", Metric::HighCfa),
+    ];
+
+    fn name(&self) -> &'static str {
+        Metric::ALL.iter().find(|(_, m)| m == self).map(|(n, _)| *n).expect("every metric is listed in ALL")
+    }
+
+    fn parse(name: &str) -> Result<Metric, String> {
+        Metric::ALL.iter().find(|(n, _)| *n == name).map(|(_, m)| *m).ok_or_else(|| {
+            let names = Metric::ALL.iter().map(|(n, _)| *n).collect::<Vec<_>>().join(", ");
+            format!("unknown metric `{}` (available: {})", name, names)
+        })
+    }
+
+    /// The metric's value; yes/no metrics are 1 or 0, and an unknown payback is NaN so it never matches.
+    fn value(&self, e: &Evaluation) -> f64 {
+        let i = &e.inputs;
+        let flag = |b: bool| if b { 1.0 } else { 0.0 };
+        match self {
+            Metric::Cac => i.cac,
+            Metric::Cfa => i.cfa,
+            Metric::Ltgp => i.ltgp,
+            Metric::Ratio => e.ratio,
+            Metric::NetOutlay => e.net_outlay,
+            Metric::CfaCoverage => if i.cac > 0.0 { i.cfa / i.cac } else { f64::INFINITY },
+            Metric::PaybackDays => e.payback_days().unwrap_or(f64::NAN),
+            Metric::CacPaybackMonths => e.cac_payback_months.unwrap_or(f64::NAN),
+            Metric::LowCac => flag(e.low_cac),
+            Metric::HighCfa => flag(e.high_cfa),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl Op {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Op::Lt => "<",
+            Op::Le => "<=",
+            Op::Gt => ">",
+            Op::Ge => ">=",
+            Op::Eq => "==",
+            Op::Ne => "!=",
+        }
+    }
+
+    fn holds(&self, a: f64, b: f64) -> bool {
+        match self {
+            Op::Lt => a < b,
+            Op::Le => a <= b,
+            Op::Gt => a > b,
+            Op::Ge => a >= b,
+            Op::Eq => a == b,
+            Op::Ne => a != b,
+        }
+    }
+}
+
+/// One `<metric> <op> <value>` test.
+#[derive(Debug, Clone, Copy)]
+pub struct Condition {
+    metric: Metric,
+    op: Op,
+    value: f64,
+}
+
+impl Condition {
+    pub fn holds(&self, e: &Evaluation) -> bool {
+        self.op.holds(self.metric.value(e), self.value)
+    }
+
+    /// `{"metric", "op", "value"}`, for re-evaluating in the HTML report's script.
+    pub fn to_json(self) -> serde_json::Value {
+        serde_json::json!({ "metric": self.metric.name(), "op": self.op.symbol(), "value": self.value })
+    }
+}
+
+impl FromStr for Condition {
+    type Err = String;
+
+    /// Parses `ratio <= 3`, `low_cac == false`, or a bare `high_cfa` (meaning it is true).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split_whitespace().collect();
+        let (metric, op, value) = match parts.as_slice() {
+            [metric] => (metric, Op::Eq, 1.0),
+            [metric, op, value] => {
+                let op = match *op {
+                    "<" => Op::Lt,
+                    "<=" => Op::Le,
+                    ">" => Op::Gt,
+                    ">=" => Op::Ge,
+                    "==" => Op::Eq,
+                    "!=" => Op::Ne,
+                    _ => return Err(format!("unknown operator `{}` in `{}`", op, s)),
+                };
+                let value = match *value {
+                    "true" => 1.0,
+                    "false" => 0.0,
+                    v => v.parse::<f64>().map_err(|_| format!("invalid value `{}` in `{}`", v, s))?,
+                };
+                (metric, op, value)
+            }
+            _ => return Err(format!("expected `<metric> <op> <value>`, got `{}`", s)),
+        };
+        Ok(Condition { metric: Metric::parse(metric)?, op, value })
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct RawRule {
+    #[serde(default)]
+    when: Vec<String>,
+    label: String,
+    guidance: String,
+    #[serde(default)]
+    color: Option<String>,
+    #[serde(default)]
+    severity: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct RawRuleset {
+    rules: Vec<RawRule>,
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    conditions: Vec<Condition>,
+    /// The conditions as written, for diagrams.
+    when: Vec<String>,
+    /// "Label: guidance", the form every report prints and `sweep` shortens to the label.
+    verdict: String,
+    /// Zone color in heatmaps; one of `COLORS`.
+    color: Option<String>,
+    severity: Severity,
+}
+
+#[derive(Debug, Clone)]
+pub struct Ruleset {
+    rules: Vec<Rule>,
+}
+
+impl Ruleset {
+    pub fn parse(text: &str) -> Result<Ruleset, String> {
+        let raw: RawRuleset = serde_yaml::from_str(text).map_err(|e| e.to_string())?;
+        let rules = raw.rules.into_iter().enumerate().map(|(n, r)| {
+            let conditions = r.when.iter().map(|c| c.parse()).collect::<Result<Vec<Condition>, String>>().map_err(|e| format!("rule {}: {}", n + 1, e))?;
+            if let Some(color) = &r.color && !COLORS.contains(&color.as_str()) {
+                return Err(format!("rule {}: unknown color `{}` (available: {})", n + 1, color, COLORS.join(", ")));
+            }
+            let severity = match &r.severity {
+                Some(s) => s.parse().map_err(|e| format!("rule {}: {}", n + 1, e))?,
+                None => Severity::from_color(r.color.as_deref()),
+            };
+            Ok(Rule { conditions, when: r.when.iter().map(|c| c.trim().to_string()).collect(), verdict: format!("{}: {}", r.label.trim(), r.guidance.trim()), color: r.color, severity })
+        }).collect::<Result<Vec<Rule>, String>>()?;
+        if rules.is_empty() {
+            return Err("no rules defined".to_string());
+        }
+        Ok(Ruleset { rules })
+    }
+
+    pub fn load(path: &str) -> Result<Ruleset, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path, e))?;
+        Ruleset::parse(&text).map_err(|e| format!("{}: {}", path, e))
+    }
+
+    /// Verdict from the first rule whose conditions all hold.
+    pub fn verdict(&self, e: &Evaluation) -> Option<&str> {
+        self.rules.iter().find(|r| r.conditions.iter().all(|c| c.holds(e))).map(|r| r.verdict.as_str())
+    }
+
+    /// Heatmap color of the rule that gives `verdict`, if it names one.
+    pub fn color(&self, verdict: &str) -> Option<&str> {
+        self.rules.iter().find(|r| r.verdict == verdict).and_then(|r| r.color.as_deref())
+    }
+
+    /// Severity of the rule that gives `verdict`; watch for a verdict no rule gives.
+    pub fn severity(&self, verdict: &str) -> Severity {
+        self.rules.iter().find(|r| r.verdict == verdict).map(|r| r.severity).unwrap_or(Severity::Watch)
+    }
+
+    /// The rules in order as `{"when", "verdict", "severity"}` objects, for the HTML report's script.
+    pub fn to_json(&self) -> serde_json::Value {
+        self.rules.iter().map(|r| serde_json::json!({ "when": r.conditions.iter().map(|c| c.to_json()).collect::<Vec<_>>(), "verdict": r.verdict, "severity": r.severity.name() })).collect()
+    }
+
+    /// DOT subgraph of the rules as a first-match chain: each test leads to its verdict on yes
+    /// and to the next rule on no.
+    pub fn dot_cluster(&self) -> String {
+        let mut lines = vec!["  subgraph cluster_verdict {".to_string(), "    label=\"Verdict rules (first match wins)\";".to_string()];
+        for (n, rule) in self.rules.iter().enumerate() {
+            let test = if rule.when.is_empty() { "always".to_string() } else { rule.when.join(" and ") };
+            lines.push(format!("    r{} [shape=diamond, label=\"{}\"];", n, dot_text(&test)));
+            lines.push(format!("    v{} [shape=box, style=rounded, label=\"{}\"];", n, dot_text(&rule.verdict)));
+            lines.push(format!("    r{} -> v{} [label=\"yes\"];", n, n));
+            if n + 1 < self.rules.len() {
+                lines.push(format!("    r{} -> r{} [label=\"no\"];", n, n + 1));
+            }
+        }
+        lines.push("  }".to_string());
+        lines.join("\n")
+    }
+}
+
+/// Escapes a label for a quoted DOT string.
+pub fn dot_text(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Makes `rules` the ruleset for the rest of the run; must be called before the first evaluation.
+pub fn install(rules: Ruleset) {
+    ACTIVE.set(rules).expect("verdict rules installed before the first evaluation");
+}
+
+/// The installed ruleset, or the bundled default.
+pub fn active() -> &'static Ruleset {
+    ACTIVE.get_or_init(|| Ruleset::parse(BUNDLED).expect("bundled verdict_rules.yaml is valid"))
+}
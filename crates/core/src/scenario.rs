@@ -0,0 +1,108 @@
+// Scenario files: a full input set saved as TOML so it can be re-run or compared.
+//
+//   name = "Enterprise offer"
+//   cac = 500.0
+//   cfa = 200.0
+//   ltgp = 2500.0
+//   early_gp_rate = 50.0
+//   period = "days"
+//   low_cac_fraction = 0.10
+//   high_cfa_fraction = 0.50    # optional
+//   low_cac_max = 300.0         # optional dollar cut, overrides low_cac_fraction
+//   high_cfa_min = 250.0        # optional dollar cut, overrides high_cfa_fraction
+
+use serde::Deserialize;
+
+use crate::evaluation::{self, Inputs};
+use crate::input;
+use crate::period::Period;
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    pub name: Option<String>,
+    /// Cost to acquire a customer, in dollars.
+    pub cac: f64,
+    /// Cash collected from a customer upfront, in dollars.
+    #[serde(default)]
+    pub cfa: f64,
+    /// Lifetime gross profit per customer, in dollars.
+    pub ltgp: f64,
+    /// Gross profit per customer per period at the start, in dollars.
+    #[serde(default)]
+    pub early_gp_rate: f64,
+    /// days, weeks, months, or years.
+    #[serde(default)]
+    pub period: Period,
+    /// CAC is low below this fraction of LTGP.
+    #[serde(default = "default_low_cac_fraction")]
+    pub low_cac_fraction: f64,
+    /// CFA is high at or above this fraction of CAC.
+    #[serde(default = "default_high_cfa_fraction")]
+    pub high_cfa_fraction: f64,
+    /// CAC is low at or below this many dollars (overrides low_cac_fraction).
+    pub low_cac_max: Option<f64>,
+    /// CFA is high at or above this many dollars (overrides high_cfa_fraction).
+    pub high_cfa_min: Option<f64>,
+}
+
+fn default_low_cac_fraction() -> f64 {
+    evaluation::DEFAULT_LOW_CAC_FRACTION
+}
+
+fn default_high_cfa_fraction() -> f64 {
+    evaluation::DEFAULT_HIGH_CFA_FRACTION
+}
+
+impl Scenario {
+    /// CAC and LTGP with every other field at its default.
+    pub fn new(cac: f64, ltgp: f64) -> Scenario {
+        Scenario {
+            name: None,
+            cac,
+            cfa: 0.0,
+            ltgp,
+            early_gp_rate: 0.0,
+            period: Period::default(),
+            low_cac_fraction: default_low_cac_fraction(),
+            high_cfa_fraction: default_high_cfa_fraction(),
+            low_cac_max: None,
+            high_cfa_min: None,
+        }
+    }
+
+    /// Loads a scenario file; the file stem stands in for a missing `name`.
+    pub fn load(path: &str) -> Result<Scenario, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path, e))?;
+        let mut scenario: Scenario = toml::from_str(&text).map_err(|e| format!("{}: {}", path, e))?;
+        if scenario.name.is_none() {
+            scenario.name = std::path::Path::new(path).file_stem().map(|s| s.to_string_lossy().into_owned());
+        }
+        Ok(scenario)
+    }
+
+    pub fn label(&self) -> &str {
+        self.name.as_deref().unwrap_or("scenario")
+    }
+
+    /// The scenario's inputs exactly as written, out-of-range values and all.
+    pub fn raw_inputs(&self) -> Inputs {
+        Inputs {
+            cac: self.cac,
+            cfa: self.cfa,
+            ltgp: self.ltgp,
+            early_gp_rate: self.early_gp_rate,
+            period: self.period,
+            low_cac_fraction: self.low_cac_fraction,
+            high_cfa_fraction: self.high_cfa_fraction,
+            low_cac_max: self.low_cac_max,
+            high_cfa_min: self.high_cfa_min,
+        }
+    }
+
+    /// The scenario's inputs, or every out-of-range value in one message.
+    pub fn inputs(&self) -> Result<Inputs, String> {
+        input::validate(self.raw_inputs())
+    }
+}
@@ -0,0 +1,92 @@
+// Severity levels every verdict carries: healthy, watch, at-risk, and critical. Each level has one
+// color, used by the terminal, summary, JSON, and HTML outputs alike, so people and downstream
+// tools read the same taxonomy whichever format they get.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::rules;
+use crate::theme;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum Severity {
+    Healthy,
+    Watch,
+    AtRisk,
+    Critical,
+}
+
+impl Severity {
+    pub const ALL: [Severity; 4] = [Severity::Healthy, Severity::Watch, Severity::AtRisk, Severity::Critical];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Severity::Healthy => "healthy",
+            Severity::Watch => "watch",
+            Severity::AtRisk => "at-risk",
+            Severity::Critical => "critical",
+        }
+    }
+
+    /// The level's color, as a rule color name.
+    pub fn color(&self) -> &'static str {
+        match self {
+            Severity::Healthy => "green",
+            Severity::Watch => "yellow",
+            Severity::AtRisk => "magenta",
+            Severity::Critical => "red",
+        }
+    }
+
+    pub fn hex(&self) -> &'static str {
+        theme::active().hex(self.color())
+    }
+
+    /// The level's icon followed by a space.
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Severity::Healthy => "✅ ",
+            Severity::Watch => "⚠️ ",
+            Severity::AtRisk => "🔶 ",
+            Severity::Critical => "🛑 ",
+        }
+    }
+
+    /// For rules that don't name a severity: read it off their heatmap color, else watch.
+    pub fn from_color(color: Option<&str>) -> Severity {
+        match color {
+            Some("green" | "cyan") => Severity::Healthy,
+            Some("magenta") => Severity::AtRisk,
+            Some("red") => Severity::Critical,
+            _ => Severity::Watch,
+        }
+    }
+
+    /// The active rules' severity for `verdict`; a verdict no rule gives (e.g. a script's) is watch.
+    pub fn of_verdict(verdict: &str) -> Severity {
+        rules::active().severity(verdict)
+    }
+
+    /// LTGP:CAC above 3 is healthy, above 1 at risk, otherwise customers cost more than they return.
+    pub fn of_ratio(ratio: f64) -> Severity {
+        if ratio > 3.0 { Severity::Healthy } else if ratio > 1.0 { Severity::AtRisk } else { Severity::Critical }
+    }
+
+    pub fn of_pass(passes: bool) -> Severity {
+        if passes { Severity::Healthy } else { Severity::Critical }
+    }
+}
+
+impl FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Severity::ALL.iter().copied().find(|v| v.name() == s.trim()).ok_or_else(|| {
+            let names = Severity::ALL.iter().map(|v| v.name()).collect::<Vec<_>>().join(", ");
+            format!("unknown severity `{}` (available: {})", s, names)
+        })
+    }
+}
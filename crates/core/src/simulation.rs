@@ -0,0 +1,151 @@
+// Inputs entered as "mean ± standard deviation" and Monte Carlo propagation of that uncertainty
+// through the evaluation. The CLI's histograms of the results are in its uncertainty module.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand_distr::{Distribution, Normal};
+use serde::Serialize;
+use std::str::FromStr;
+
+use crate::evaluation::{self, parse_money_like, Inputs};
+
+/// A dollar figure with an optional standard deviation, e.g. `500`, `500 ± 80`, `500+-80`, or `500 ± 15%`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Estimate {
+    pub mean: f64,
+    pub sd: f64,
+}
+
+impl Estimate {
+    pub fn exact(mean: f64) -> Estimate {
+        Estimate { mean, sd: 0.0 }
+    }
+}
+
+impl FromStr for Estimate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mean, sd) = match ["±", "+/-", "+-"].iter().find_map(|sep| s.split_once(sep)) {
+            Some((mean, sd)) => (mean, Some(sd.trim())),
+            None => (s, None),
+        };
+        let mean = parse_money_like(mean).filter(|v| v.is_finite()).ok_or_else(|| format!("`{}` is not a number", mean.trim()))?;
+        let sd = match sd {
+            Some(pct) if pct.ends_with('%') => parse_money_like(pct.trim_end_matches('%')).map(|p| mean.abs() * p / 100.0),
+            Some(abs) => parse_money_like(abs),
+            None => Some(0.0),
+        };
+        let sd = sd.filter(|v| v.is_finite()).ok_or_else(|| format!("invalid standard deviation in `{}`", s))?;
+        Ok(Estimate { mean, sd: sd.abs() })
+    }
+}
+
+/// Most draws one simulation takes in the servers and bindings, so one call can't tie them up.
+pub const MAX_RUNS: u32 = 1_000_000;
+
+/// Standard deviations for the inputs that accept "±".
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Uncertainty {
+    pub cac_sd: f64,
+    pub cfa_sd: f64,
+    pub ltgp_sd: f64,
+}
+
+impl Uncertainty {
+    pub fn is_empty(&self) -> bool {
+        self.cac_sd == 0.0 && self.cfa_sd == 0.0 && self.ltgp_sd == 0.0
+    }
+}
+
+/// `sd` when it can be a standard deviation (finite, not negative); otherwise an error naming the
+/// front end's `name` for it.
+pub fn check_sd(name: &str, sd: f64) -> Result<f64, String> {
+    if sd.is_finite() && sd >= 0.0 { Ok(sd) } else { Err(format!("{} must be a finite number, 0 or more, got {}", name, sd)) }
+}
+
+/// Outcome of one simulated draw.
+#[derive(Debug, Clone)]
+pub struct Sample {
+    pub ratio: f64,
+    pub payback_days: Option<f64>,
+    pub verdict: &'static str,
+}
+
+/// Normal draw truncated at zero (dollar inputs can't go negative); `min` excludes zero itself for CAC.
+fn draw(rng: &mut StdRng, mean: f64, sd: f64, min: f64) -> f64 {
+    if sd <= 0.0 { return mean; }
+    let normal = Normal::new(mean, sd).expect("standard deviation is finite and positive");
+    for _ in 0..1000 {
+        let v = normal.sample(rng);
+        if v >= min { return v; }
+    }
+    mean.max(min)
+}
+
+#[tracing::instrument(level = "debug", skip(inputs, unc))]
+pub fn simulate(inputs: &Inputs, unc: &Uncertainty, runs: usize, seed: u64) -> Vec<Sample> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let samples: Vec<Sample> = (0..runs).map(|_| {
+        let drawn = Inputs {
+            cac: draw(&mut rng, inputs.cac, unc.cac_sd, f64::MIN_POSITIVE),
+            cfa: draw(&mut rng, inputs.cfa, unc.cfa_sd, 0.0),
+            ltgp: draw(&mut rng, inputs.ltgp, unc.ltgp_sd, 0.0),
+            ..inputs.clone()
+        };
+        let e = evaluation::evaluate(&drawn);
+        Sample { ratio: e.ratio, payback_days: e.payback_days(), verdict: e.verdict }
+    }).collect();
+    tracing::debug!(samples = samples.len(), "simulated");
+    samples
+}
+
+/// Value at quantile `q` (0..=1) of an already sorted slice.
+pub fn percentile(sorted: &[f64], q: f64) -> f64 {
+    if sorted.is_empty() { return f64::NAN; }
+    let idx = (q * (sorted.len() - 1) as f64).round() as usize;
+    sorted[idx.min(sorted.len() - 1)]
+}
+
+/// The 5th, 50th, and 95th percentiles.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Interval {
+    pub p5: f64,
+    pub p50: f64,
+    pub p95: f64,
+}
+
+impl Interval {
+    /// The interval of an already sorted slice.
+    pub fn of(sorted: &[f64]) -> Interval {
+        Interval { p5: percentile(sorted, 0.05), p50: percentile(sorted, 0.5), p95: percentile(sorted, 0.95) }
+    }
+}
+
+/// What a simulation says about an evaluation, for the service interfaces.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Summary {
+    pub ratio: Interval,
+    /// Share of draws with LTGP:CAC at or below 3.
+    pub chance_ratio_at_most_3: f64,
+    /// None without early gross profit.
+    pub payback_days: Option<Interval>,
+    /// Share of draws with `verdict`, the verdict at the means.
+    pub verdict_holds: f64,
+}
+
+pub fn summarize(samples: &[Sample], verdict: &str) -> Summary {
+    let mut ratios: Vec<f64> = samples.iter().map(|s| s.ratio).collect();
+    ratios.sort_by(f64::total_cmp);
+    let mut paybacks: Vec<f64> = samples.iter().filter_map(|s| s.payback_days).collect();
+    paybacks.sort_by(f64::total_cmp);
+    let share = |n: usize| n as f64 / samples.len().max(1) as f64;
+    Summary {
+        ratio: Interval::of(&ratios),
+        chance_ratio_at_most_3: share(ratios.iter().filter(|r| **r <= 3.0).count()),
+        payback_days: (!paybacks.is_empty()).then(|| Interval::of(&paybacks)),
+        verdict_holds: share(samples.iter().filter(|s| s.verdict == verdict).count()),
+    }
+}
@@ -0,0 +1,180 @@
+// Color themes: one palette drives the terminal colors, the HTML report, and every SVG export, so
+// output reads well on whatever background it ends up on. Built in are `light` (the default),
+// `dark`, and `high-contrast`; the config file can define more under `[themes.<name>]`, each
+// starting from a built-in `base` and overriding any of its colors.
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use crate::rules::COLORS;
+
+pub const BUILTIN: [&str; 3] = ["light", "dark", "high-contrast"];
+
+static ACTIVE: OnceLock<Theme> = OnceLock::new();
+
+/// How a theme's colors reach the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Terminal {
+    /// The terminal's own eight colors, so its color scheme still applies.
+    Named,
+    /// The terminal's bright variants, for dark backgrounds.
+    Bright,
+    /// The theme's exact hex colors, on terminals with 24-bit color.
+    Truecolor,
+}
+
+#[derive(Debug, Clone)]
+pub struct Theme {
+    pub name: String,
+    /// Hex per rule color, in `rules::COLORS` order.
+    colors: [String; 6],
+    pub background: String,
+    pub text: String,
+    /// Axes and outlines.
+    pub axis: String,
+    /// Dashed reference lines: thresholds, targets, medians.
+    pub guide: String,
+    /// Secondary text: captions and annotations.
+    pub muted: String,
+    /// Faint gridlines and panel borders.
+    pub grid: String,
+    terminal: Terminal,
+}
+
+/// A `[themes.<name>]` table from the config file; anything left out comes from `base`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Palette {
+    base: Option<String>,
+    green: Option<String>,
+    cyan: Option<String>,
+    blue: Option<String>,
+    yellow: Option<String>,
+    magenta: Option<String>,
+    red: Option<String>,
+    background: Option<String>,
+    text: Option<String>,
+    axis: Option<String>,
+    guide: Option<String>,
+    muted: Option<String>,
+    grid: Option<String>,
+    terminal: Option<Terminal>,
+}
+
+fn theme(name: &str, colors: [&str; 6], [background, text, axis, guide, muted, grid]: [&str; 6], terminal: Terminal) -> Theme {
+    Theme {
+        name: name.to_string(),
+        colors: colors.map(str::to_string),
+        background: background.to_string(),
+        text: text.to_string(),
+        axis: axis.to_string(),
+        guide: guide.to_string(),
+        muted: muted.to_string(),
+        grid: grid.to_string(),
+        terminal,
+    }
+}
+
+/// A built-in theme by name.
+pub fn builtin(name: &str) -> Option<Theme> {
+    match name {
+        "light" => Some(theme("light", ["#4a9d5b", "#3aa6b9", "#3b6fb6", "#e0b53f", "#a0569c", "#c8553d"], ["#ffffff", "#222222", "#444444", "#888888", "#555555", "#cccccc"], Terminal::Named)),
+        "dark" => Some(theme("dark", ["#6cc380", "#5cc6d6", "#6f9be0", "#f0c75e", "#c58bc1", "#e8765f"], ["#1e1f22", "#e6e6e6", "#b0b0b0", "#8a8a8a", "#a8a8a8", "#444444"], Terminal::Bright)),
+        "high-contrast" => Some(theme("high-contrast", ["#006d2c", "#00687a", "#0033a0", "#8a6100", "#8e0099", "#c00000"], ["#ffffff", "#000000", "#000000", "#000000", "#000000", "#666666"], Terminal::Named)),
+        _ => None,
+    }
+}
+
+/// Accepts `#rgb` and `#rrggbb`.
+fn check_hex(key: &str, value: &str) -> Result<(), String> {
+    let digits = value.strip_prefix('#').unwrap_or("");
+    if (digits.len() == 3 || digits.len() == 6) && digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        Ok(())
+    } else {
+        Err(format!("`{}` must be a hex color like #3b6fb6, not `{}`", key, value))
+    }
+}
+
+/// A hex color as red, green, and blue.
+pub fn rgb(hex: &str) -> (u8, u8, u8) {
+    let digits = hex.trim_start_matches('#');
+    let digits = if digits.len() == 3 { digits.chars().flat_map(|c| [c, c]).collect() } else { digits.to_string() };
+    let channel = |n: usize| u8::from_str_radix(digits.get(n..n + 2).unwrap_or("0"), 16).unwrap_or(0);
+    (channel(0), channel(2), channel(4))
+}
+
+impl Theme {
+    /// `palette` laid over its base theme (light when it names none).
+    fn from_palette(name: &str, palette: &Palette) -> Result<Theme, String> {
+        let base = palette.base.as_deref().unwrap_or("light");
+        let mut t = builtin(base).ok_or_else(|| format!("theme `{}`: unknown base `{}` (available: {})", name, base, BUILTIN.join(", ")))?;
+        t.name = name.to_string();
+        let set = |slot: &mut String, key: &str, value: &Option<String>| -> Result<(), String> {
+            if let Some(v) = value {
+                check_hex(key, v).map_err(|e| format!("theme `{}`: {}", name, e))?;
+                *slot = v.clone();
+            }
+            Ok(())
+        };
+        let colors = [&palette.green, &palette.cyan, &palette.blue, &palette.yellow, &palette.magenta, &palette.red];
+        for ((slot, key), value) in t.colors.iter_mut().zip(COLORS).zip(colors) {
+            set(slot, key, value)?;
+        }
+        set(&mut t.background, "background", &palette.background)?;
+        set(&mut t.text, "text", &palette.text)?;
+        set(&mut t.axis, "axis", &palette.axis)?;
+        set(&mut t.guide, "guide", &palette.guide)?;
+        set(&mut t.muted, "muted", &palette.muted)?;
+        set(&mut t.grid, "grid", &palette.grid)?;
+        if let Some(terminal) = palette.terminal {
+            t.terminal = terminal;
+        }
+        Ok(t)
+    }
+
+    /// The theme named `name`: a built-in one, or one of the config file's `themes`.
+    pub fn resolve(name: &str, themes: &BTreeMap<String, Palette>) -> Result<Theme, String> {
+        if let Some(t) = builtin(name) {
+            if themes.contains_key(name) {
+                return Err(format!("theme `{}` is built in; give the config file's palette another name", name));
+            }
+            return Ok(t);
+        }
+        match themes.get(name) {
+            Some(palette) => Theme::from_palette(name, palette),
+            None => {
+                let names: Vec<&str> = BUILTIN.iter().copied().chain(themes.keys().map(String::as_str)).collect();
+                Err(format!("unknown theme `{}` (available: {})", name, names.join(", ")))
+            }
+        }
+    }
+
+    /// SVG/HTML color for a rule color name; anything unrecognized gets red.
+    pub fn hex(&self, color: &str) -> &str {
+        let n = COLORS.iter().position(|c| *c == color).unwrap_or(COLORS.len() - 1);
+        &self.colors[n]
+    }
+
+    /// How the theme's colors reach the terminal.
+    pub fn terminal(&self) -> Terminal {
+        self.terminal
+    }
+
+    /// Opening `<svg>` tag plus a full-size background, with the theme's text color inherited.
+    pub fn svg_open(&self, width: f64, height: f64, font_size: u32) -> String {
+        format!(r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" font-family="sans-serif" font-size="{font_size}" fill="{text}"><rect width="100%" height="100%" fill="{bg}"/>"#, text = self.text, bg = self.background)
+    }
+}
+
+/// Makes `theme` the theme for the rest of the run.
+pub fn install(theme: Theme) {
+    ACTIVE.set(theme).expect("theme installed once, at startup");
+}
+
+/// The installed theme, or light.
+pub fn active() -> &'static Theme {
+    ACTIVE.get_or_init(|| builtin("light").expect("light is built in"))
+}
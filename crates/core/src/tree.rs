@@ -0,0 +1,205 @@
+// Decision tree: the structure that places an evaluation in a quadrant. The bundled
+// quadrant_tree.yaml reproduces the built-in CAC/CFA quadrants; `--tree` swaps in a
+// user-defined tree with its own axes, cut points, labels, and recommendations.
+
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use crate::evaluation::Evaluation;
+use crate::rules::{self, Condition};
+
+const BUNDLED: &str = include_str!("quadrant_tree.yaml");
+
+static ACTIVE: OnceLock<Tree> = OnceLock::new();
+
+/// Diagram formats for `--export-tree`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Export {
+    /// Mermaid flowchart, for embedding in Markdown
+    Mermaid,
+    /// Graphviz DOT of the whole policy: the quadrant tree and the verdict rules
+    Dot,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum RawNode {
+    Split { test: String, yes: Box<RawNode>, no: Box<RawNode> },
+    Leaf { label: String, recommendation: String },
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct RawTree {
+    root: RawNode,
+}
+
+/// Where an evaluation ends up: a label and what it means for the business.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Leaf {
+    pub label: String,
+    /// "Label: recommendation", as shown in reports.
+    pub description: String,
+}
+
+#[derive(Debug)]
+enum Node {
+    /// `text` is the test as written, for diagrams.
+    Split { test: Condition, text: String, yes: Box<Node>, no: Box<Node> },
+    Leaf(Leaf),
+}
+
+impl Node {
+    fn compile(raw: RawNode) -> Result<Node, String> {
+        Ok(match raw {
+            RawNode::Split { test, yes, no } => Node::Split { test: test.parse()?, text: test.trim().to_string(), yes: Box::new(Node::compile(*yes)?), no: Box::new(Node::compile(*no)?) },
+            RawNode::Leaf { label, recommendation } => {
+                let label = label.trim().to_string();
+                Node::Leaf(Leaf { description: format!("{}: {}", label, recommendation.trim()), label })
+            }
+        })
+    }
+}
+
+/// Nodes in depth-first order, with the edges between them, for the diagram exports.
+struct Flat<'a> {
+    nodes: Vec<&'a Node>,
+    /// (from, to, "yes" | "no")
+    edges: Vec<(usize, usize, &'static str)>,
+}
+
+fn flatten(root: &Node) -> Flat<'_> {
+    fn walk<'a>(node: &'a Node, flat: &mut Flat<'a>) -> usize {
+        let id = flat.nodes.len();
+        flat.nodes.push(node);
+        if let Node::Split { yes, no, .. } = node {
+            let y = walk(yes, flat);
+            flat.edges.push((id, y, "yes"));
+            let n = walk(no, flat);
+            flat.edges.push((id, n, "no"));
+        }
+        id
+    }
+    let mut flat = Flat { nodes: Vec::new(), edges: Vec::new() };
+    walk(root, &mut flat);
+    flat
+}
+
+/// Mermaid can't take raw quotes inside a quoted label.
+fn mermaid_text(s: &str) -> String {
+    s.replace('"', "#quot;")
+}
+
+#[derive(Debug)]
+pub struct Tree {
+    root: Node,
+}
+
+impl Tree {
+    pub fn parse(text: &str) -> Result<Tree, String> {
+        let raw: RawTree = serde_yaml::from_str(text).map_err(|e| e.to_string())?;
+        Ok(Tree { root: Node::compile(raw.root)? })
+    }
+
+    pub fn load(path: &str) -> Result<Tree, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path, e))?;
+        Tree::parse(&text).map_err(|e| format!("{}: {}", path, e))
+    }
+
+    /// Walks from the root, following each test, to the leaf the evaluation lands in.
+    pub fn classify(&self, e: &Evaluation) -> &Leaf {
+        let mut node = &self.root;
+        loop {
+            match node {
+                Node::Split { test, yes, no, .. } => node = if test.holds(e) { yes } else { no },
+                Node::Leaf(leaf) => return leaf,
+            }
+        }
+    }
+
+    /// Ids of the nodes an evaluation passes through, root first.
+    fn path(&self, e: &Evaluation) -> Vec<usize> {
+        let flat = flatten(&self.root);
+        let mut at = 0;
+        let mut path = vec![0];
+        while let Node::Split { test, .. } = flat.nodes[at] {
+            let branch = if test.holds(e) { "yes" } else { "no" };
+            at = flat.edges.iter().find(|(from, _, b)| *from == at && *b == branch).map(|(_, to, _)| *to).expect("split nodes have both branches");
+            path.push(at);
+        }
+        path
+    }
+
+    /// Mermaid flowchart of the tree with the path `e` took highlighted.
+    fn mermaid(&self, e: &Evaluation) -> String {
+        let flat = flatten(&self.root);
+        let path = self.path(e);
+        let mut lines = vec!["flowchart TD".to_string()];
+        for (id, node) in flat.nodes.iter().enumerate() {
+            lines.push(match node {
+                Node::Split { text, .. } => format!("    n{}{{\"{}\"}}", id, mermaid_text(text)),
+                Node::Leaf(leaf) => format!("    n{}[\"{}\"]", id, mermaid_text(&leaf.description)),
+            });
+        }
+        for (from, to, branch) in &flat.edges {
+            lines.push(format!("    n{} -->|{}| n{}", from, branch, to));
+        }
+        lines.push("    classDef taken fill:#ffe08a,stroke:#b8860b,stroke-width:2px".to_string());
+        lines.push(format!("    class {} taken", path.iter().map(|id| format!("n{}", id)).collect::<Vec<_>>().join(",")));
+        let taken_edges: Vec<String> = flat.edges.iter().enumerate().filter(|(_, (from, to, _))| path.windows(2).any(|w| w[0] == *from && w[1] == *to)).map(|(n, _)| n.to_string()).collect();
+        lines.push(format!("    linkStyle {} stroke:#b8860b,stroke-width:3px", taken_edges.join(",")));
+        lines.join("\n")
+    }
+
+    /// DOT graph of the tree and the active verdict rules, for documenting the policy in use.
+    fn dot(&self) -> String {
+        let flat = flatten(&self.root);
+        let mut lines = vec!["digraph decision_policy {".to_string(), "  node [fontname=\"sans-serif\"];".to_string(), "  edge [fontname=\"sans-serif\"];".to_string()];
+        lines.push("  subgraph cluster_quadrant {".to_string());
+        lines.push("    label=\"Quadrant tree\";".to_string());
+        for (id, node) in flat.nodes.iter().enumerate() {
+            lines.push(match node {
+                Node::Split { text, .. } => format!("    n{} [shape=diamond, label=\"{}\"];", id, rules::dot_text(text)),
+                Node::Leaf(leaf) => format!("    n{} [shape=box, style=rounded, label=\"{}\"];", id, rules::dot_text(&leaf.description)),
+            });
+        }
+        for (from, to, branch) in &flat.edges {
+            lines.push(format!("    n{} -> n{} [label=\"{}\"];", from, to, branch));
+        }
+        lines.push("  }".to_string());
+        lines.push(rules::active().dot_cluster());
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+
+    /// The tree as nested `{"test", "yes", "no"}` and `{"label", "description"}` objects, for the
+    /// HTML report's script.
+    pub fn to_json(&self) -> serde_json::Value {
+        fn node(n: &Node) -> serde_json::Value {
+            match n {
+                Node::Split { test, yes, no, .. } => serde_json::json!({ "test": test.to_json(), "yes": node(yes), "no": node(no) }),
+                Node::Leaf(leaf) => serde_json::json!({ "label": leaf.label, "description": leaf.description }),
+            }
+        }
+        node(&self.root)
+    }
+
+    pub fn export(&self, format: Export, e: &Evaluation) -> String {
+        match format {
+            Export::Mermaid => self.mermaid(e),
+            Export::Dot => self.dot(),
+        }
+    }
+}
+
+/// Makes `tree` the decision tree for the rest of the run; must be called before the first evaluation.
+pub fn install(tree: Tree) {
+    ACTIVE.set(tree).expect("decision tree installed before the first evaluation");
+}
+
+/// The installed tree, or the bundled quadrants.
+pub fn active() -> &'static Tree {
+    ACTIVE.get_or_init(|| Tree::parse(BUNDLED).expect("bundled quadrant_tree.yaml is valid"))
+}
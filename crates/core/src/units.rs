@@ -0,0 +1,132 @@
+// Unit-checked quantities for the evaluation math. Inputs arrive as plain numbers (flags, files,
+// JSON), but a lump sum, a per-period rate, and a fraction each mean something different, and
+// mixing them up (a weekly rate used as monthly, 10 passed where 0.10 was meant) gives a plausible
+// but wrong verdict. `Inputs::amounts` wraps the numbers once, and only the combinations that make
+// sense compile:
+//
+//   Money + Money, Money - Money       → Money
+//   fraction.of(money)                 → Money     (a share of an amount)
+//   money.ratio_to(money)              → f64       (LTGP:CAC and the like)
+//   rate.per(Period::Months)           → Money     (gross profit per month, converted)
+//   rate.periods_to_earn(money)        → periods   (payback, in the rate's own period)
+//
+// There is no Money × Money, Money + Rate, or Fraction × Fraction, and a percentage only becomes
+// a `Fraction` through `Fraction::from_percent`.
+
+use std::fmt;
+use std::ops::{Add, Sub};
+
+use crate::period::Period;
+use crate::precision;
+
+/// An amount of money, in dollars.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Money(f64);
+
+impl Money {
+    pub const ZERO: Money = Money(0.0);
+
+    pub fn dollars(dollars: f64) -> Money {
+        Money(dollars)
+    }
+
+    /// The amount as a plain number of dollars, for output.
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    pub fn is_positive(self) -> bool {
+        self.0 > 0.0
+    }
+
+    /// Zero when negative.
+    pub fn or_zero(self) -> Money {
+        Money(self.0.max(0.0))
+    }
+
+    /// How many times `other` fits into this amount; None when `other` is zero or less.
+    pub fn ratio_to(self, other: Money) -> Option<f64> {
+        other.is_positive().then(|| self.0 / other.0)
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        Money(self.0 + other.0)
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, other: Money) -> Money {
+        Money(self.0 - other.0)
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&crate::evaluation::money(self.0))
+    }
+}
+
+/// Money earned per period, e.g. early gross profit of $50 per week.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rate {
+    amount: Money,
+    period: Period,
+}
+
+impl Rate {
+    pub fn new(amount: Money, period: Period) -> Rate {
+        Rate { amount, period }
+    }
+
+    /// The period the rate is quoted in.
+    pub fn period(self) -> Period {
+        self.period
+    }
+
+    /// The amount earned per `period`, converted through days.
+    pub fn per(self, period: Period) -> Money {
+        Money(self.amount.0 * period.days() / self.period.days())
+    }
+
+    /// Periods (in the rate's own period) until `amount` is earned; None when nothing is earned.
+    pub fn periods_to_earn(self, amount: Money) -> Option<f64> {
+        amount.ratio_to(self.amount)
+    }
+}
+
+/// A share of something, where 0.10 is 10%. Not capped at 1: a high-CFA cut can sit above CAC.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Fraction(f64);
+
+impl Fraction {
+    /// `share` as given, 0.10 for 10%.
+    pub fn new(share: f64) -> Fraction {
+        Fraction(share)
+    }
+
+    /// A percentage, 10 for 10%.
+    pub fn from_percent(percent: f64) -> Fraction {
+        Fraction(percent / 100.0)
+    }
+
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    /// This share of `amount`.
+    pub fn of(self, amount: Money) -> Money {
+        Money(self.0 * amount.0)
+    }
+}
+
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}%", precision::fixed(self.0 * 100.0, 0))
+    }
+}
@@ -0,0 +1,37 @@
+// JavaScript bindings for the WebAssembly build, so a browser calculator runs the same
+// evaluation as the CLI. Inputs are the scenario file's fields as an object, with the same
+// defaults; results are the object `--format json` prints.
+//
+//   wasm-pack build --target web -- --features wasm
+//
+//   import init, { evaluate, report } from "./pkg/ltgp_core.js";
+//   await init();
+//   evaluate({ cac: 500, cfa: 200, ltgp: 2500, early_gp_rate: 50 }).verdict;
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::evaluation::{self, Evaluation};
+use crate::report::{self, EvaluationResult};
+use crate::scenario::Scenario;
+
+fn evaluation(inputs: JsValue) -> Result<Evaluation, JsError> {
+    let scenario: Scenario = serde_wasm_bindgen::from_value(inputs).map_err(|e| JsError::new(&format!("invalid inputs: {}", e)))?;
+    let inputs = scenario.inputs().map_err(|e| JsError::new(&e))?;
+    Ok(evaluation::evaluate(&inputs))
+}
+
+/// Evaluates one set of inputs.
+#[wasm_bindgen]
+pub fn evaluate(inputs: JsValue) -> Result<JsValue, JsError> {
+    let e = evaluation(inputs)?;
+    // JSON-compatible, so a missing payback is null as in `--format json`, not undefined.
+    EvaluationResult::from(&e).serialize(&serde_wasm_bindgen::Serializer::json_compatible()).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// The CLI's plain-text report for one set of inputs.
+#[wasm_bindgen]
+pub fn report(inputs: JsValue) -> Result<String, JsError> {
+    let e = evaluation(inputs)?;
+    Ok(report::render_report(&EvaluationResult::from(&e), &report::Plain))
+}
@@ -0,0 +1,8 @@
+// Generates the gRPC service and messages from proto/ltgp.proto, with a vendored protoc so the
+// build doesn't need one installed.
+
+fn main() {
+    let mut config = tonic_prost_build::Config::new();
+    config.protoc_executable(protoc_bin_vendored::protoc_bin_path().expect("vendored protoc for this platform"));
+    tonic_prost_build::configure().build_client(false).compile_with_config(config, &["proto/ltgp.proto"], &["proto"]).expect("proto/ltgp.proto compiles");
+}
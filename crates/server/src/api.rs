@@ -0,0 +1,129 @@
+// HTTP API: the calculator over REST for internal tools and spreadsheets, evaluating with the
+// same framework, rules, and decision tree as the CLI. Request bodies take the scenario file's
+// fields as JSON:
+//
+//   POST /evaluate      {"name": "acme", "cac": 500, "cfa": 200, "ltgp": 2500, "early_gp_rate": 50}
+//                       → {"name": "acme", "result": {…}}
+//   POST /batch         [{…}, {…}]  → {"results": [{"name": …, "result": {…}}, …]}
+//   GET  /health        → ok
+//   GET  /openapi.json  → the OpenAPI 3 document for all of the above
+//
+// `result` is the object `--format json` prints. Out-of-range inputs, such as a negative CAC,
+// are rejected with 422 and a message naming each one, never adjusted. Nothing is saved or sent
+// anywhere. The OpenAPI document is generated from the handlers and the request and response
+// types below, so client SDKs can be generated from it; `serve --openapi` prints it. Every
+// request is logged at info (method, path, status, and time taken), and rejected ones at warn.
+
+use std::time::Instant;
+
+use axum::extract::Request;
+use axum::http::StatusCode;
+use axum::middleware::{self, Next};
+use axum::response::Response;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use tracing::Instrument;
+use serde::Serialize;
+use utoipa::{OpenApi, ToSchema};
+
+use ltgp_core::evaluation;
+use ltgp_core::report::EvaluationResult;
+use ltgp_core::scenario::Scenario;
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Crow's Nest LTGP:CAC API", description = "Evaluate growth economics with the same logic as the CLI."),
+    paths(evaluate, batch, health)
+)]
+struct ApiDoc;
+
+/// One evaluation, under the name it was sent with.
+#[derive(Serialize, ToSchema)]
+pub struct Evaluated {
+    pub name: Option<String>,
+    pub result: EvaluationResult,
+}
+
+/// Evaluations in the order the rows were sent.
+#[derive(Serialize, ToSchema)]
+pub struct BatchResults {
+    pub results: Vec<Evaluated>,
+}
+
+/// The OpenAPI 3 document, as pretty-printed JSON.
+pub fn openapi() -> String {
+    ApiDoc::openapi().to_pretty_json().expect("OpenAPI document serializes to JSON")
+}
+
+pub fn router() -> Router {
+    Router::new()
+        .route("/evaluate", post(evaluate))
+        .route("/batch", post(batch))
+        .route("/health", get(health))
+        .route("/openapi.json", get(|| async { ([("content-type", "application/json")], openapi()) }))
+        .layer(middleware::from_fn(log_request))
+}
+
+/// Runs the request in a span naming its method and path, then logs how it went.
+async fn log_request(request: Request, next: Next) -> Response {
+    let span = tracing::info_span!("request", method = %request.method(), path = %request.uri().path());
+    let started = Instant::now();
+    let response = next.run(request).instrument(span.clone()).await;
+    let (status, elapsed_ms) = (response.status().as_u16(), started.elapsed().as_millis() as u64);
+    span.in_scope(|| {
+        if response.status().is_success() { tracing::info!(status, elapsed_ms, "handled") } else { tracing::warn!(status, elapsed_ms, "rejected") }
+    });
+    response
+}
+
+/// The evaluation of `s`, or why its inputs are out of range.
+pub fn evaluated(s: &Scenario) -> Result<Evaluated, String> {
+    let eval = evaluation::evaluate(&s.inputs()?);
+    Ok(Evaluated { name: s.name.clone(), result: EvaluationResult::from(&eval) })
+}
+
+/// Evaluate one set of inputs.
+#[utoipa::path(post, path = "/evaluate", request_body = Scenario, responses(
+    (status = 200, description = "The evaluation", body = Evaluated),
+    (status = 422, description = "An input is out of range", body = String, example = "CFA can't be negative, got -50"),
+))]
+async fn evaluate(Json(scenario): Json<Scenario>) -> Result<Json<Evaluated>, (StatusCode, String)> {
+    evaluated(&scenario).map(Json).map_err(|e| {
+        tracing::debug!(error = %e, "inputs out of range");
+        (StatusCode::UNPROCESSABLE_ENTITY, e)
+    })
+}
+
+/// Evaluate every row.
+#[utoipa::path(post, path = "/batch", request_body = Vec<Scenario>, responses(
+    (status = 200, description = "One evaluation per row", body = BatchResults),
+    (status = 422, description = "A row's input is out of range", body = String, example = "row 2: CAC can't be negative, got -500"),
+))]
+async fn batch(Json(rows): Json<Vec<Scenario>>) -> Result<Json<BatchResults>, (StatusCode, String)> {
+    let results = rows
+        .iter()
+        .enumerate()
+        .map(|(n, s)| evaluated(s).map_err(|e| format!("row {}: {}", n + 1, e)))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| {
+            tracing::debug!(error = %e, "inputs out of range");
+            (StatusCode::UNPROCESSABLE_ENTITY, e)
+        })?;
+    tracing::debug!(rows = results.len(), "batch evaluated");
+    Ok(Json(BatchResults { results }))
+}
+
+/// Check the server is up.
+#[utoipa::path(get, path = "/health", responses((status = 200, description = "The server is up", body = String, example = "ok")))]
+async fn health() -> &'static str {
+    "ok"
+}
+
+/// Serves the API on `addr` until the process is stopped.
+pub async fn serve(addr: String) -> Result<(), String> {
+    let listener = tokio::net::TcpListener::bind(&addr).await.map_err(|e| format!("could not listen on {}: {}", addr, e))?;
+    let local = listener.local_addr().map(|a| a.to_string()).unwrap_or_else(|_| addr.clone());
+    println!("Serving the API on http://{}.", local);
+    tracing::info!(addr = %local, "REST API listening");
+    axum::serve(listener, router()).await.map_err(|e| format!("server stopped: {}", e))
+}
@@ -0,0 +1,173 @@
+// gRPC: the evaluation and simulation endpoints as the `ltgp.v1.Calculator` service defined in
+// proto/ltgp.proto, for platforms that standardize on gRPC rather than REST. Served by
+// `serve --grpc ADDR`, next to the REST API when `--listen` is given too; the messages mirror
+// the API's JSON, anything left unset takes the command line's default, and out-of-range inputs
+// fail with `INVALID_ARGUMENT`. Each call is logged with its method and time taken.
+
+use std::time::Instant;
+
+use tonic::{Request, Response, Status};
+
+use ltgp_core::evaluation::{self, Inputs};
+use ltgp_core::growth::{self, GrowthPolicy};
+use ltgp_core::report::EvaluationResult;
+use ltgp_core::scenario::Scenario;
+use ltgp_core::simulation::{self, Interval, Uncertainty};
+
+mod pb {
+    tonic::include_proto!("ltgp.v1");
+}
+
+use pb::calculator_server::{Calculator, CalculatorServer};
+
+pub const DEFAULT_RUNS: u32 = 10_000;
+pub const DEFAULT_SEED: u64 = 42;
+pub const DEFAULT_MONTHS: u32 = 24;
+/// Upper limits, so one request can't tie up the server.
+pub const MAX_RUNS: u32 = simulation::MAX_RUNS;
+pub const MAX_MONTHS: u32 = 1_200;
+
+fn scenario(i: pb::Inputs) -> Result<Scenario, Status> {
+    let mut s = Scenario::new(i.cac, i.ltgp);
+    s.name = i.name;
+    s.cfa = i.cfa;
+    s.early_gp_rate = i.early_gp_rate;
+    if !i.period.is_empty() {
+        s.period = i.period.parse().map_err(Status::invalid_argument)?;
+    }
+    if let Some(v) = i.low_cac_fraction {
+        s.low_cac_fraction = v;
+    }
+    if let Some(v) = i.high_cfa_fraction {
+        s.high_cfa_fraction = v;
+    }
+    s.low_cac_max = i.low_cac_max;
+    s.high_cfa_min = i.high_cfa_min;
+    Ok(s)
+}
+
+/// The inputs of `s`, or `InvalidArgument` naming each one out of range.
+fn inputs(s: &Scenario) -> Result<Inputs, Status> {
+    s.inputs().map_err(Status::invalid_argument)
+}
+
+fn evaluation(s: &Scenario) -> Result<pb::Evaluation, Status> {
+    let e = evaluation::evaluate(&inputs(s)?);
+    let r = EvaluationResult::from(&e);
+    let i = r.inputs;
+    Ok(pb::Evaluation {
+        name: s.name.clone(),
+        inputs: Some(pb::Inputs {
+            name: s.name.clone(),
+            cac: i.cac,
+            cfa: i.cfa,
+            ltgp: i.ltgp,
+            early_gp_rate: i.early_gp_rate,
+            period: i.period.to_string(),
+            low_cac_fraction: Some(i.low_cac_fraction),
+            high_cfa_fraction: Some(i.high_cfa_fraction),
+            low_cac_max: i.low_cac_max,
+            high_cfa_min: i.high_cfa_min,
+        }),
+        net_outlay: r.net_outlay,
+        ratio: r.ratio,
+        low_cac: r.low_cac,
+        high_cfa: r.high_cfa,
+        low_cac_threshold: r.low_cac_threshold,
+        high_cfa_threshold: r.high_cfa_threshold,
+        quadrant: r.quadrant,
+        verdict: r.verdict,
+        severity: Some(pb::Severity { level: r.severity.level.name().to_string(), color: r.severity.color }),
+        payback: r.payback,
+        payback_days: r.payback_days,
+        cac_payback_months: r.cac_payback_months,
+        warnings: r.warnings,
+        degenerate: r.degenerate.map(|d| pb::DegenerateCase { kind: d.kind.name().to_string(), explanation: d.explanation }),
+        evaluated_at: r.evaluated_at,
+        timezone: r.timezone,
+    })
+}
+
+fn interval(i: Interval) -> pb::Interval {
+    pb::Interval { p5: i.p5, p50: i.p50, p95: i.p95 }
+}
+
+struct Service;
+
+/// Runs one call and logs how it went: info when handled, warn with the status when not.
+fn logged<T>(method: &str, call: impl FnOnce() -> Result<T, Status>) -> Result<Response<T>, Status> {
+    let started = Instant::now();
+    let outcome = call();
+    let elapsed_ms = started.elapsed().as_millis() as u64;
+    match &outcome {
+        Ok(_) => tracing::info!(method, elapsed_ms, "handled"),
+        Err(status) => tracing::warn!(method, elapsed_ms, code = ?status.code(), error = status.message(), "rejected"),
+    }
+    outcome.map(Response::new)
+}
+
+#[tonic::async_trait]
+impl Calculator for Service {
+    async fn evaluate(&self, request: Request<pb::Inputs>) -> Result<Response<pb::Evaluation>, Status> {
+        logged("Evaluate", || evaluation(&scenario(request.into_inner())?))
+    }
+
+    async fn evaluate_batch(&self, request: Request<pb::Batch>) -> Result<Response<pb::BatchEvaluation>, Status> {
+        logged("EvaluateBatch", || {
+            let results = request.into_inner().rows.into_iter().map(|i| evaluation(&scenario(i)?)).collect::<Result<_, _>>()?;
+            Ok(pb::BatchEvaluation { results })
+        })
+    }
+
+    async fn simulate(&self, request: Request<pb::SimulateRequest>) -> Result<Response<pb::Simulation>, Status> {
+        logged("Simulate", || {
+            let r = request.into_inner();
+            let runs = r.runs.unwrap_or(DEFAULT_RUNS);
+            if runs == 0 || runs > MAX_RUNS {
+                return Err(Status::invalid_argument(format!("runs must be from 1 to {}", MAX_RUNS)));
+            }
+            let s = scenario(r.inputs.unwrap_or_default())?;
+            let inputs = inputs(&s)?;
+            let sd = |name: &str, sd: f64| simulation::check_sd(name, sd).map_err(Status::invalid_argument);
+            let unc = Uncertainty { cac_sd: sd("cac_sd", r.cac_sd)?, cfa_sd: sd("cfa_sd", r.cfa_sd)?, ltgp_sd: sd("ltgp_sd", r.ltgp_sd)? };
+            let verdict = evaluation::evaluate(&inputs).verdict;
+            let samples = simulation::simulate(&inputs, &unc, runs as usize, r.seed.unwrap_or(DEFAULT_SEED));
+            let summary = simulation::summarize(&samples, verdict);
+            Ok(pb::Simulation {
+                base: Some(evaluation(&s)?),
+                ratio: Some(interval(summary.ratio)),
+                chance_ratio_at_most_3: summary.chance_ratio_at_most_3,
+                payback_days: summary.payback_days.map(interval),
+                verdict_holds: summary.verdict_holds,
+            })
+        })
+    }
+
+    async fn grow(&self, request: Request<pb::GrowRequest>) -> Result<Response<pb::Growth>, Status> {
+        logged("Grow", || {
+            let r = request.into_inner();
+            let months = r.months.unwrap_or(DEFAULT_MONTHS);
+            if months == 0 || months > MAX_MONTHS {
+                return Err(Status::invalid_argument(format!("months must be from 1 to {}", MAX_MONTHS)));
+            }
+            let reinvest = r.reinvest.unwrap_or(1.0);
+            if !(0.0..=1.0).contains(&reinvest) {
+                return Err(Status::invalid_argument("reinvest must be from 0 to 1"));
+            }
+            let policy = GrowthPolicy { months: months as usize, starting_cash: r.starting_cash, reinvest, max_monthly_spend: r.max_monthly_spend, channels: Vec::new() };
+            let rows = growth::simulate(&inputs(&scenario(r.inputs.unwrap_or_default())?)?, &policy);
+            Ok(pb::Growth {
+                self_funding_month: growth::self_funding_month(&rows).map(|m| m as u32),
+                months: rows.iter().map(|m| pb::Month { month: m.month as u32, acquired: m.acquired, active: m.active, spend: m.spend, cash_in: m.cash_in, ending_cash: m.ending_cash }).collect(),
+            })
+        })
+    }
+}
+
+/// Serves the service on `addr` until the process is stopped.
+pub async fn serve(addr: String) -> Result<(), String> {
+    let socket = addr.parse().map_err(|e| format!("--grpc {}: {}", addr, e))?;
+    println!("Serving gRPC on {}.", addr);
+    tracing::info!(addr = %addr, "gRPC service listening");
+    tonic::transport::Server::builder().add_service(CalculatorServer::new(Service)).serve(socket).await.map_err(|e| format!("gRPC server on {} stopped: {}", addr, e))
+}
@@ -0,0 +1,9 @@
+// Crow's Nest servers: the core evaluation, simulation, and growth model behind a REST API with
+// an OpenAPI document (`api`), a gRPC service (`grpc`), JSON-RPC over stdio (`rpc`), and a Model
+// Context Protocol server (`mcp`). The command-line tool runs them through `serve`, `--rpc`, and
+// `--mcp`; its `server` feature pulls this crate in.
+
+pub mod api;
+pub mod grpc;
+pub mod mcp;
+pub mod rpc;
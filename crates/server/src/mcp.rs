@@ -0,0 +1,92 @@
+// MCP: `--mcp` serves the calculator, solver, and simulators as Model Context Protocol tools over
+// stdio, so AI assistants call them with structured arguments and get structured results back
+// rather than scraping the report. Register it with an assistant as a stdio server:
+//
+//   {"command": "ltgp_cac_calculator", "args": ["--mcp"]}
+//
+//   evaluate   ratio, quadrant, verdict, severity, and payback, as `--format json` prints them
+//   solve      the changes to CAC, LTGP, or CFA that fix what's wrong, each re-evaluated
+//   simulate   the spread of ratio, payback, and verdict given uncertainty in CAC, CFA, and LTGP
+//   grow       month-by-month reinvestment of collections into acquisition
+//
+// Arguments are the JSON-RPC methods' params (see rpc.rs), with schemas generated from the same
+// types. Nothing is saved or sent anywhere.
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use utoipa::PartialSchema;
+
+use ltgp_core::evaluation;
+use ltgp_core::recommend::{self, Lever};
+use ltgp_core::scenario::Scenario;
+
+use crate::api;
+use crate::rpc::{self, Failure, GrowParams, SimulateParams, INVALID_PARAMS, METHOD_NOT_FOUND};
+
+/// Protocol versions this server speaks, newest first.
+const VERSIONS: [&str; 3] = ["2025-06-18", "2025-03-26", "2024-11-05"];
+
+/// The solver's answer: where the inputs stand, and the levers that improve them.
+#[derive(Serialize)]
+struct Solution {
+    base: api::Evaluated,
+    /// Most important first; empty when nothing needs fixing.
+    levers: Vec<Lever>,
+}
+
+fn tools() -> Value {
+    let tool = |name: &str, description: &str, schema: utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>| json!({ "name": name, "description": description, "inputSchema": schema });
+    json!({ "tools": [
+        tool("evaluate", "Evaluate customer acquisition economics: LTGP:CAC ratio, CAC/CFA quadrant, verdict, severity, and payback period. Money is in dollars; early_gp_rate is gross profit per `period` (days, weeks, months, or years) at the start.", Scenario::schema()),
+        tool("solve", "Find the changes to CAC, LTGP, or cash collected upfront (CFA) that fix the economics, most important first, each with its re-evaluated effect.", Scenario::schema()),
+        tool("simulate", "Monte Carlo simulation: the 5th/50th/95th percentile LTGP:CAC ratio and payback, the chance the ratio is at or below 3, and how often the verdict holds, given standard deviations on CAC, CFA, and LTGP.", SimulateParams::schema()),
+        tool("grow", "Project month by month what reinvesting collected cash into acquisition does: customers acquired and active, spend, cash in, ending cash, and the first month it funds itself.", GrowParams::schema()),
+    ]})
+}
+
+fn solve(s: Scenario) -> Result<Value, Failure> {
+    let base = rpc::evaluated(&s)?;
+    let eval = evaluation::evaluate(&base.result.inputs);
+    rpc::result(Solution { levers: recommend::levers(&eval), base })
+}
+
+/// A tool's result; argument problems are reported to the model as tool errors it can correct.
+fn call_tool(p: Value) -> Result<Value, Failure> {
+    let name = p.get("name").and_then(Value::as_str).ok_or((INVALID_PARAMS, "tools/call needs a tool name".to_string()))?;
+    let args = p.get("arguments").cloned().unwrap_or_else(|| json!({}));
+    let outcome = match name {
+        "evaluate" => rpc::params(args).and_then(|s| rpc::result(rpc::evaluated(&s)?)),
+        "solve" => rpc::params(args).and_then(solve),
+        "simulate" => rpc::params(args).and_then(rpc::simulate),
+        "grow" => rpc::params(args).and_then(rpc::grow),
+        _ => return Err((INVALID_PARAMS, format!("no tool `{}`", name))),
+    };
+    Ok(match outcome {
+        Ok(value) => json!({ "content": [{ "type": "text", "text": value.to_string() }], "structuredContent": value, "isError": false }),
+        Err((_, message)) => json!({ "content": [{ "type": "text", "text": message }], "isError": true }),
+    })
+}
+
+fn call(method: &str, p: Value) -> Result<Value, Failure> {
+    match method {
+        "initialize" => {
+            let asked = p.get("protocolVersion").and_then(Value::as_str).unwrap_or_default();
+            let version = VERSIONS.iter().find(|v| **v == asked).unwrap_or(&VERSIONS[0]);
+            Ok(json!({
+                "protocolVersion": version,
+                "capabilities": { "tools": {} },
+                "serverInfo": { "name": env!("CARGO_PKG_NAME"), "version": env!("CARGO_PKG_VERSION") },
+            }))
+        }
+        "ping" => Ok(json!({})),
+        "tools/list" => Ok(tools()),
+        "tools/call" => call_tool(p),
+        m if m.starts_with("notifications/") => Ok(Value::Null),
+        _ => Err((METHOD_NOT_FOUND, format!("no method `{}`", method))),
+    }
+}
+
+/// Serves the tools until stdin closes.
+pub fn run() {
+    rpc::serve(call)
+}
@@ -0,0 +1,215 @@
+// JSON-RPC over stdio: `--rpc` answers JSON-RPC 2.0 requests on stdin, one per line, with one
+// response line each on stdout, so editors, bots, and other long-lived processes can keep one
+// calculator warm and evaluate many times without starting a process for each. Params are the
+// API's JSON, and anything left unset takes the same defaults as the gRPC service:
+//
+//   evaluate   {"name": "acme", "cac": 500, "cfa": 200, "ltgp": 2500}  → {"name", "result"}
+//   batch      [{…}, {…}]                                               → {"results": […]}
+//   simulate   {"inputs": {…}, "cac_sd": 80, "ltgp_sd": 400, "runs": 10000, "seed": 42}
+//              → {"base", "ratio", "chance_ratio_at_most_3", "payback_days", "verdict_holds"}
+//   grow       {"inputs": {…}, "months": 24, "starting_cash": 0, "reinvest": 1, "max_monthly_spend": null}
+//              → {"months": […], "self_funding_month"}
+//
+//   → {"jsonrpc": "2.0", "id": 1, "method": "evaluate", "params": {"cac": 500, "ltgp": 2500}}
+//   ← {"jsonrpc": "2.0", "id": 1, "result": {"name": null, "result": {…}}}
+//
+// Out-of-range inputs, such as a negative CAC, fail with invalid params rather than being
+// adjusted. Notifications (no id) get no response, and a batch (an array of requests) gets an array back.
+// Nothing is saved or sent anywhere. Logs go to stderr: each request at debug, failed ones at warn.
+
+use std::io::{self, BufRead, Write};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use utoipa::ToSchema;
+
+use ltgp_core::evaluation;
+use ltgp_core::growth::{self, GrowthPolicy, MonthRow};
+use ltgp_core::scenario::Scenario;
+use ltgp_core::simulation::{self, Summary, Uncertainty};
+
+use crate::api::{self, BatchResults, Evaluated};
+use crate::grpc::{DEFAULT_MONTHS, DEFAULT_RUNS, DEFAULT_SEED, MAX_MONTHS, MAX_RUNS};
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+
+#[derive(Deserialize)]
+struct Request {
+    jsonrpc: String,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SimulateParams {
+    #[schema(inline)]
+    inputs: Scenario,
+    /// Standard deviation of CAC, in dollars; 0 or more.
+    #[serde(default)]
+    cac_sd: f64,
+    /// Standard deviation of CFA, in dollars; 0 or more.
+    #[serde(default)]
+    cfa_sd: f64,
+    /// Standard deviation of LTGP, in dollars; 0 or more.
+    #[serde(default)]
+    ltgp_sd: f64,
+    /// Draws to simulate (default 10000).
+    runs: Option<u32>,
+    /// Random seed, for repeatable results (default 42).
+    seed: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct Simulation {
+    base: Evaluated,
+    #[serde(flatten)]
+    summary: Summary,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GrowParams {
+    #[schema(inline)]
+    inputs: Scenario,
+    /// Months to simulate (default 24).
+    months: Option<u32>,
+    /// Cash available for acquisition at the start.
+    #[serde(default)]
+    starting_cash: f64,
+    /// Fraction of available cash spent each month (default 1).
+    reinvest: Option<f64>,
+    /// Upper limit on acquisition spend per month.
+    max_monthly_spend: Option<f64>,
+}
+
+#[derive(Serialize)]
+struct Growth {
+    months: Vec<MonthRow>,
+    self_funding_month: Option<usize>,
+}
+
+/// A method's failure, as a JSON-RPC error code and message.
+pub type Failure = (i64, String);
+
+/// What a method returns for its params.
+pub type Methods = fn(&str, Value) -> Result<Value, Failure>;
+
+pub fn params<T: serde::de::DeserializeOwned>(params: Value) -> Result<T, Failure> {
+    serde_json::from_value(params).map_err(|e| (INVALID_PARAMS, format!("invalid params: {}", e)))
+}
+
+pub fn result<T: Serialize>(value: T) -> Result<Value, Failure> {
+    Ok(serde_json::to_value(value).expect("results serialize to JSON"))
+}
+
+/// The evaluation of `s`, or invalid params naming each input out of range.
+pub fn evaluated(s: &Scenario) -> Result<Evaluated, Failure> {
+    api::evaluated(s).map_err(|e| (INVALID_PARAMS, e))
+}
+
+pub fn simulate(p: SimulateParams) -> Result<Value, Failure> {
+    let runs = p.runs.unwrap_or(DEFAULT_RUNS);
+    if runs == 0 || runs > MAX_RUNS {
+        return Err((INVALID_PARAMS, format!("runs must be from 1 to {}", MAX_RUNS)));
+    }
+    let inputs = p.inputs.inputs().map_err(|e| (INVALID_PARAMS, e))?;
+    let sd = |name: &str, sd: f64| simulation::check_sd(name, sd).map_err(|e| (INVALID_PARAMS, e));
+    let unc = Uncertainty { cac_sd: sd("cac_sd", p.cac_sd)?, cfa_sd: sd("cfa_sd", p.cfa_sd)?, ltgp_sd: sd("ltgp_sd", p.ltgp_sd)? };
+    let verdict = evaluation::evaluate(&inputs).verdict;
+    let samples = simulation::simulate(&inputs, &unc, runs as usize, p.seed.unwrap_or(DEFAULT_SEED));
+    result(Simulation { base: evaluated(&p.inputs)?, summary: simulation::summarize(&samples, verdict) })
+}
+
+pub fn grow(p: GrowParams) -> Result<Value, Failure> {
+    let months = p.months.unwrap_or(DEFAULT_MONTHS);
+    if months == 0 || months > MAX_MONTHS {
+        return Err((INVALID_PARAMS, format!("months must be from 1 to {}", MAX_MONTHS)));
+    }
+    let reinvest = p.reinvest.unwrap_or(1.0);
+    if !(0.0..=1.0).contains(&reinvest) {
+        return Err((INVALID_PARAMS, "reinvest must be from 0 to 1".to_string()));
+    }
+    let policy = GrowthPolicy { months: months as usize, starting_cash: p.starting_cash, reinvest, max_monthly_spend: p.max_monthly_spend, channels: Vec::new() };
+    let rows = growth::simulate(&p.inputs.inputs().map_err(|e| (INVALID_PARAMS, e))?, &policy);
+    result(Growth { self_funding_month: growth::self_funding_month(&rows), months: rows })
+}
+
+fn call(method: &str, p: Value) -> Result<Value, Failure> {
+    match method {
+        "evaluate" => result(evaluated(&params(p)?)?),
+        "batch" => result(BatchResults { results: params::<Vec<Scenario>>(p)?.iter().enumerate().map(|(n, s)| evaluated(s).map_err(|(code, e)| (code, format!("row {}: {}", n + 1, e)))).collect::<Result<_, _>>()? }),
+        "simulate" => simulate(params(p)?),
+        "grow" => grow(params(p)?),
+        _ => Err((METHOD_NOT_FOUND, format!("no method `{}`; there are evaluate, batch, simulate, and grow", method))),
+    }
+}
+
+fn error(id: Value, (code, message): Failure) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+/// The response to one request, or None for a notification.
+fn respond(call: Methods, message: Value) -> Option<Value> {
+    let id = message.get("id").cloned();
+    let request = match serde_json::from_value::<Request>(message) {
+        Ok(r) if r.jsonrpc == "2.0" => r,
+        Ok(_) => return Some(error(id.unwrap_or(Value::Null), (INVALID_REQUEST, "jsonrpc must be \"2.0\"".to_string()))),
+        Err(e) => return Some(error(id.unwrap_or(Value::Null), (INVALID_REQUEST, format!("invalid request: {}", e)))),
+    };
+    let span = tracing::debug_span!("rpc", method = %request.method);
+    let _entered = span.enter();
+    let outcome = call(&request.method, request.params);
+    match &outcome {
+        Ok(_) => tracing::debug!("answered"),
+        Err((code, message)) => tracing::warn!(code, error = %message, "failed"),
+    }
+    let id = id?;
+    Some(match outcome {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(failure) => error(id, failure),
+    })
+}
+
+/// The reply to one line: a request or a batch of them.
+fn reply(call: Methods, line: &str) -> Option<Value> {
+    match serde_json::from_str::<Value>(line) {
+        Err(e) => Some(error(Value::Null, (PARSE_ERROR, format!("parse error: {}", e)))),
+        Ok(Value::Array(batch)) if batch.is_empty() => Some(error(Value::Null, (INVALID_REQUEST, "empty batch".to_string()))),
+        Ok(Value::Array(batch)) => {
+            let responses: Vec<Value> = batch.into_iter().filter_map(|m| respond(call, m)).collect();
+            (!responses.is_empty()).then_some(Value::Array(responses))
+        }
+        Ok(message) => respond(call, message),
+    }
+}
+
+/// Answers requests until stdin closes.
+pub fn run() {
+    serve(call)
+}
+
+/// Answers requests on stdin with `call` until it closes.
+pub fn serve(call: Methods) {
+    let stdin = io::stdin();
+    let mut stdout = io::stdout().lock();
+    for line in stdin.lock().lines() {
+        let line = line.unwrap_or_else(|e| {
+            eprintln!("Error: could not read stdin: {}", e);
+            std::process::exit(1);
+        });
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = reply(call, &line)
+            && writeln!(stdout, "{}", response).and_then(|()| stdout.flush()).is_err()
+        {
+            // The other end has gone away.
+            return;
+        }
+    }
+}